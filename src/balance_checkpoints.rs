@@ -0,0 +1,139 @@
+use scrypto::prelude::*;
+use crate::{BalanceCheckpoint, MAX_BALANCE_CHECKPOINTS};
+
+#[blueprint]
+mod balance_checkpoints {
+    use super::*;
+
+    enable_method_auth! {
+        methods {
+            checkpoint => PUBLIC;
+            get_checkpoints => PUBLIC;
+            get_average_balance => PUBLIC;
+        }
+    }
+
+    /// Reference balance history for `VotingPowerMode::AverageBalance`: anyone can record an
+    /// account's balance of a resource, and averages are computed from the recorded balances
+    struct BalanceCheckpoints {
+        /// Key: account and resource
+        /// Value: recorded balances, oldest first (bounded by MAX_BALANCE_CHECKPOINTS)
+        pub checkpoints: KeyValueStore<(Global<Account>, ResourceAddress), Vec<BalanceCheckpoint>>,
+    }
+
+    impl BalanceCheckpoints {
+        /// Instantiates a balance checkpoint component without an owner
+        pub fn instantiate() -> Global<BalanceCheckpoints> {
+            Self {
+                checkpoints: KeyValueStore::new(),
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .globalize()
+        }
+
+        /// Records the account's current balance of a resource
+        /// Callable by anyone, e.g. keepers or the account itself after each transfer. Within
+        /// the same second the lowest recorded balance is kept. Beyond MAX_BALANCE_CHECKPOINTS
+        /// checkpoints the oldest one is folded into the next, so recording many checkpoints
+        /// lowers the resolution of the oldest history but does not erase it.
+        pub fn checkpoint(&mut self, account: Global<Account>, resource: ResourceAddress) {
+            let balance = account.balance(resource);
+            let now = Clock::current_time_rounded_to_seconds();
+            let key = (account, resource);
+
+            let mut checkpoints = self
+                .checkpoints
+                .get(&key)
+                .map(|c| c.clone())
+                .unwrap_or_default();
+            let balance = match checkpoints.last() {
+                Some(last) if last.recorded_at == now => {
+                    let lowest = last.balance.min(balance);
+                    checkpoints.pop();
+                    lowest
+                }
+                _ => balance,
+            };
+            checkpoints.push(BalanceCheckpoint {
+                balance,
+                recorded_at: now,
+            });
+            if checkpoints.len() > MAX_BALANCE_CHECKPOINTS {
+                // The merged checkpoint starts at the oldest one and keeps the lower balance
+                let oldest = checkpoints.remove(0);
+                let next = &mut checkpoints[0];
+                next.balance = next.balance.min(oldest.balance);
+                next.recorded_at = oldest.recorded_at;
+            }
+
+            self.checkpoints.insert(key, checkpoints);
+        }
+
+        /// Returns the recorded balances of an account for a resource, oldest first
+        pub fn get_checkpoints(
+            &self,
+            account: Global<Account>,
+            resource: ResourceAddress,
+        ) -> Vec<BalanceCheckpoint> {
+            self.checkpoints
+                .get(&(account, resource))
+                .map(|c| c.clone())
+                .unwrap_or_default()
+        }
+
+        /// Returns the time-weighted average balance of an account between `from` and `to`
+        /// Between two checkpoints the lower of their balances counts, so a balance borrowed
+        /// for a single checkpoint stops counting once the next one is recorded. The stretch
+        /// after the last checkpoint up to `to` is closed by the lowest balance recorded after
+        /// `to`, or by the current balance if `to` is now; if `to` is in the past and nothing was
+        /// recorded since, it does not count. Before the first checkpoint nothing counts either,
+        /// so keepers must checkpoint accounts regularly, including once a window has ended.
+        pub fn get_average_balance(
+            &self,
+            account: Global<Account>,
+            resource: ResourceAddress,
+            from: Instant,
+            to: Instant,
+        ) -> Decimal {
+            let (from, to) = (from.seconds_since_unix_epoch, to.seconds_since_unix_epoch);
+            if to <= from {
+                return Decimal::ZERO;
+            }
+
+            let checkpoints = self.get_checkpoints(account, resource);
+            let (within, later): (Vec<BalanceCheckpoint>, Vec<BalanceCheckpoint>) = checkpoints
+                .into_iter()
+                .partition(|c| c.recorded_at.seconds_since_unix_epoch <= to);
+            // A balance observed at or after `to`, closing the stretch after the last checkpoint
+            let now = Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch;
+            let closing = match later.iter().map(|c| c.balance).min() {
+                Some(lowest) => Some(lowest),
+                None if to >= now => Some(account.balance(resource)),
+                None => None,
+            };
+
+            let mut weighted = Decimal::ZERO;
+            for (index, checkpoint) in within.iter().enumerate() {
+                let next = within.get(index + 1);
+                let start = checkpoint.recorded_at.seconds_since_unix_epoch.max(from);
+                let end = next
+                    .map_or(to, |n| n.recorded_at.seconds_since_unix_epoch)
+                    .min(to);
+                if end <= start {
+                    continue;
+                }
+                let next_balance = match next {
+                    Some(next) => next.balance,
+                    None => match closing {
+                        Some(balance) => balance,
+                        None => continue,
+                    },
+                };
+                weighted += checkpoint.balance.min(next_balance) * Decimal::from(end - start);
+            }
+
+            weighted / Decimal::from(to - from)
+        }
+    }
+}
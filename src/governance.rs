@@ -1,19 +1,93 @@
 use scrypto::prelude::*;
 use crate::{
-    GovernanceParameters, Proposal, ProposalVoteOption, ProposalVoteOptionId,
-    TemperatureCheck, TemperatureCheckDraft, TemperatureCheckVote, VoteOptionColor,
-    TemperatureCheckCreatedEvent, TemperatureCheckVotedEvent,
-    ProposalCreatedEvent, ProposalVotedEvent, GovernanceParametersUpdatedEvent,
-    MAX_LINKS, MAX_VOTE_OPTIONS, MAX_SELECTIONS,
+    AccountAgeConfig, ArchivedProposal, AuthorizedSignerBadge, StorageReceipt, BallotQuestion, CarriedOverVote, DelegatedVote, Delegation, DelegationMode, DescriptionFile, Attachment, EarlyVoteBonus, ExecutionApprovalConfig, ExecutionRollback, File, IntendedVote, ProposalAction, ProposalKind, FeeSubsidyConfig, FeeSubsidyUsage, GovernanceBranding, GovernanceLimits, GovernanceParameters,
+    GovernanceActivity, GovernanceStats, GroupTally, Membership, ParameterChange, OptionTally, Proposal, SignalPhase, ProposalView, TemperatureCheckView,
+    ProposalStatus, ProposalTally, RankedTemperatureCheck, ResultAttestation, SurveyResponse, SurveyResponseRecord, ResultChallenge, ProposalTurnout, ProposalVoteOption, ProposalVoteOptionId, ProposalVoteOptionInput, TemperatureCheck, TemperatureCheckDraft, TemperatureCheckStatus,
+    TemperatureCheckResult, TemperatureCheckTally, TemperatureCheckVote, TemperatureCheckVoteRecord, ProposalVoteRecord,
+    VoteOptionColor, VotePreview, VoteRationale, VoteRecord, VoteChangePolicy, VotingGroup, VotingGroupMembership, VotingMode, VotingPowerConfig, VotingPowerMode, WeightCap,
+    TemperatureCheckCreatedEvent, TemperatureCheckVotedEvent, TemperatureCheckFinalizedEvent,
+    TemperatureCheckSecondedEvent, TemperatureCheckSponsoredEvent, TemperatureCheckDelegatedVotesCastEvent,
+    TemperatureCheckElevatedEvent, ProposalForceElevatedEvent,
+    ProposalCreatedEvent, ProposalVotedEvent, LargeVoteEvent, ProposalVoteChangedEvent, ProposalSignalVotedEvent, QuorumReachedEvent, ProposalFinalizedEvent, ProposalReopenedEvent,
+    ProposalExecutedEvent, ExecutionRolledBackEvent, ReconfirmationSpawnedEvent, ProposalLapsedEvent, ProposalArchivedEvent,
+    ResultChallengedEvent, ProposalDisputedEvent, ChallengeBondReclaimedEvent, DisputeRewardClaimedEvent, DisputeRewardsSweptEvent,
+    ExecutionApprovedEvent, ExecutionApprovalConfigUpdatedEvent,
+    ExecutorRegisteredEvent, ExecutorRemovedEvent, ExecutorCalledEvent, FundingApprovedEvent, VoteBondReclaimedEvent,
+    GovernanceParametersUpdatedEvent,
+    DelegatedVotesCastEvent, DelegatedVoteOverriddenEvent, IntendedVoteAnnouncedEvent, SurveyResponseSubmittedEvent, ProposalReactionEvent, GovernanceBrandingUpdatedEvent,
+    StorageReimbursementClaimedEvent, VotingPowerConfigUpdatedEvent, FeeSubsidyConfigUpdatedEvent,
+    GovernanceLimitsUpdatedEvent,
+    AccountDenylistedEvent, AccountRemovedFromDenylistEvent, IdentityVerifierUpdatedEvent, AccountAgeConfigUpdatedEvent,
+    MemberAddedEvent, MemberRemovedEvent, TrustedStorageAddedEvent, TrustedStorageRemovedEvent,
+    VotingGroupUpdatedEvent, VotingGroupMemberAddedEvent, VotingGroupMemberRemovedEvent, GroupVoteCastEvent,
+    SignerBadgeIssuedEvent, SignerBadgeRevokedEvent,
+    GovernanceError, ensure, eligibility_leaf, verify_merkle_proof,
+    ACCOUNT_AGE_ORACLE_METHOD, BALANCE_HISTORY_METHOD, RESULT_ATTESTATION_VERSION, EXECUTOR_METHOD, IDENTITY_VERIFIER_METHOD, MAX_BALANCE_AVERAGING_DAYS, MAX_CO_AUTHORS, MAX_EXTRA_QUESTIONS, MAX_SPONSORS, MAX_PARAMETER_HISTORY, MAX_OPTION_LABEL_LENGTH, MAX_SHORT_DESCRIPTION_LENGTH,
+    MAX_TITLE_LENGTH, MAX_EXTERNAL_REF_LENGTH, MAX_ATTACHMENTS, MAX_ATTACHMENT_LABEL_LENGTH, MAX_MIME_TYPE_LENGTH, MAX_ATTACHMENTS_TOTAL_SIZE, MAX_RATIONALE_LENGTH, MAX_SURVEY_RESPONSE_LENGTH, REACTION_KINDS, REQUIRED_LINK_SCHEME, MAX_VOTING_POWER_RESOURCES, POINTS_PER_VOTER,
+    MIN_TEMPERATURE_CHECK_DAYS, MAX_TEMPERATURE_CHECK_DAYS, MIN_PROPOSAL_LENGTH_DAYS, MAX_PROPOSAL_LENGTH_DAYS, MIN_SIGNAL_PHASE_DAYS, MAX_SIGNAL_PHASE_DAYS, DISPUTE_REWARD_CLAIM_DAYS,
 };
+use crate::vote_delegation::vote_delegation::VoteDelegation;
 
 #[blueprint]
 #[events(
     TemperatureCheckCreatedEvent,
     TemperatureCheckVotedEvent,
+    TemperatureCheckFinalizedEvent,
+    TemperatureCheckSecondedEvent,
+    TemperatureCheckSponsoredEvent,
+    TemperatureCheckDelegatedVotesCastEvent,
+    TemperatureCheckElevatedEvent,
+    ProposalForceElevatedEvent,
     ProposalCreatedEvent,
     ProposalVotedEvent,
-    GovernanceParametersUpdatedEvent
+    LargeVoteEvent,
+    ProposalVoteChangedEvent,
+    ProposalSignalVotedEvent,
+    QuorumReachedEvent,
+    ProposalFinalizedEvent,
+    ProposalReopenedEvent,
+    ProposalExecutedEvent,
+    ExecutionRolledBackEvent,
+    ExecutionApprovedEvent,
+    ExecutionApprovalConfigUpdatedEvent,
+    ExecutorRegisteredEvent,
+    ExecutorRemovedEvent,
+    ExecutorCalledEvent,
+    FundingApprovedEvent,
+    VoteBondReclaimedEvent,
+    ReconfirmationSpawnedEvent,
+    ProposalLapsedEvent,
+    ProposalArchivedEvent,
+    ResultChallengedEvent,
+    ProposalDisputedEvent,
+    ChallengeBondReclaimedEvent,
+    DisputeRewardClaimedEvent,
+    DisputeRewardsSweptEvent,
+    GovernanceParametersUpdatedEvent,
+    DelegatedVotesCastEvent,
+    DelegatedVoteOverriddenEvent,
+    IntendedVoteAnnouncedEvent,
+    SurveyResponseSubmittedEvent,
+    ProposalReactionEvent,
+    GovernanceBrandingUpdatedEvent,
+    StorageReimbursementClaimedEvent,
+    VotingPowerConfigUpdatedEvent,
+    FeeSubsidyConfigUpdatedEvent,
+    GovernanceLimitsUpdatedEvent,
+    AccountDenylistedEvent,
+    AccountRemovedFromDenylistEvent,
+    IdentityVerifierUpdatedEvent,
+    AccountAgeConfigUpdatedEvent,
+    MemberAddedEvent,
+    MemberRemovedEvent,
+    TrustedStorageAddedEvent,
+    TrustedStorageRemovedEvent,
+    VotingGroupUpdatedEvent,
+    VotingGroupMemberAddedEvent,
+    VotingGroupMemberRemovedEvent,
+    GroupVoteCastEvent,
+    SignerBadgeIssuedEvent,
+    SignerBadgeRevokedEvent
 )]
 mod governance {
     use super::*;
@@ -21,47 +95,476 @@ mod governance {
     enable_method_auth! {
         roles {
             owner => updatable_by: [];
+            moderator => updatable_by: [owner];
+            auditor => updatable_by: [owner];
+            security => updatable_by: [owner];
         },
         methods {
             // Public methods
             make_temperature_check => PUBLIC;
             vote_on_temperature_check => PUBLIC;
+            vote_on_temperature_check_as_signer => PUBLIC;
+            vote_on_proposal_as_signer => PUBLIC;
+            issue_signer_badge => PUBLIC;
+            revoke_signer_badge => PUBLIC;
+            is_authorized_signer => PUBLIC;
+            get_signer_badge_resource => PUBLIC;
+            finalize_temperature_check => PUBLIC;
+            auto_elevate => PUBLIC;
+            second_temperature_check => PUBLIC;
+            co_sponsor => PUBLIC;
+            elevate_seconded => PUBLIC;
+            vote_on_temperature_check_as_delegatee => PUBLIC;
             vote_on_proposal => PUBLIC;
+            change_vote => PUBLIC;
+            vote_on_proposal_with_rationale => PUBLIC;
+            vote_on_ballot => PUBLIC;
+            vote_on_proposal_with_points => PUBLIC;
+            respond_to_survey => PUBLIC;
+            vote_on_ranking => PUBLIC;
+            vote_in_group => PUBLIC;
+            react => PUBLIC;
+            vote_on_signal => PUBLIC;
+            vote_on_proposal_with_bond => PUBLIC;
+            vote_on_proposal_with_proof => PUBLIC;
+            reclaim_vote_bond => PUBLIC;
+            announce_intended_vote => PUBLIC;
+            vote_on_proposal_as_delegatee => PUBLIC;
+            finalize_proposal => PUBLIC;
+            challenge_result => PUBLIC;
+            reclaim_challenge_bond => PUBLIC;
+            claim_dispute_reward => PUBLIC;
+            spawn_reconfirmation => PUBLIC;
+            approve_execution => PUBLIC;
+            get_execution_approval_config => PUBLIC;
+            get_executor => PUBLIC;
+            get_voting_group => PUBLIC;
+            get_voting_group_of => PUBLIC;
             get_governance_parameters => PUBLIC;
+            get_governance_stats => PUBLIC;
+            get_parameter_history => PUBLIC;
             get_temperature_check_count => PUBLIC;
+            get_temperature_check => PUBLIC;
+            get_temperature_check_result => PUBLIC;
+            get_temperature_check_tally => PUBLIC;
+            get_sponsors => PUBLIC;
+            get_proposal_for_temperature_check => PUBLIC;
+            get_temperature_check_by_external_ref => PUBLIC;
+            get_proposal_by_external_ref => PUBLIC;
+            get_temperature_check_for_proposal => PUBLIC;
+            get_proposal => PUBLIC;
+            get_archived_proposal => PUBLIC;
+            get_description_file => PUBLIC;
+            verify_description_hash => PUBLIC;
+            verify_discussion_hash => PUBLIC;
             get_proposal_count => PUBLIC;
+            get_turnout => PUBLIC;
+            get_temperature_check_time_remaining => PUBLIC;
+            get_proposal_time_remaining => PUBLIC;
+            get_proposal_tally => PUBLIC;
+            get_result_attestation => PUBLIC;
+            get_signal_tally => PUBLIC;
+            get_proposal_status => PUBLIC;
+            get_temperature_check_vote => PUBLIC;
+            get_proposal_vote => PUBLIC;
+            get_vote_rationale => PUBLIC;
+            get_intended_vote => PUBLIC;
+            get_survey_responses => PUBLIC;
+            get_ranking => PUBLIC;
+            get_group_tally => PUBLIC;
+            get_reaction => PUBLIC;
+            get_reaction_counts => PUBLIC;
+            get_delegated_votes => PUBLIC;
+            get_delegated_breakdown => PUBLIC;
+            export_voters => PUBLIC;
+            preview_vote => PUBLIC;
+            get_branding => PUBLIC;
+            get_dao_name => PUBLIC;
+            get_logo_url => PUBLIC;
+            get_forum_url => PUBLIC;
+            get_docs_url => PUBLIC;
+            fund_storage_reimbursements => PUBLIC;
+            claim_storage_reimbursement => PUBLIC;
+            get_storage_reimbursement_balance => PUBLIC;
+            get_voting_power_config => PUBLIC;
+            get_voting_power => PUBLIC;
+            is_denylisted => PUBLIC;
+            is_member => PUBLIC;
+            get_identity_verifier => PUBLIC;
+            get_account_age_config => PUBLIC;
+            is_trusted_storage => PUBLIC;
+            fund_fee_subsidy => PUBLIC;
+            get_fee_subsidy_balance => PUBLIC;
+            get_fee_subsidy_config => PUBLIC;
+            get_limits => PUBLIC;
             // Owner-only methods
             make_proposal => restrict_to: [owner];
+            force_elevate => restrict_to: [owner];
+            make_two_phase_proposal => restrict_to: [owner];
+            reopen_proposal => restrict_to: [owner];
+            execute_proposal => restrict_to: [owner];
+            set_execution_approval_config => restrict_to: [owner];
+            register_executor => restrict_to: [owner];
+            remove_executor => restrict_to: [owner];
+            register_voting_group => restrict_to: [owner];
+            update_voting_group_weight => restrict_to: [owner];
+            add_voting_group_member => restrict_to: [owner];
+            remove_voting_group_member => restrict_to: [owner];
             update_governance_parameters => restrict_to: [owner];
+            set_vote_delegation => restrict_to: [owner];
+            update_branding => restrict_to: [owner];
+            set_storage_receipt_resource => restrict_to: [owner];
+            update_voting_power_config => restrict_to: [owner];
+            set_identity_verifier => restrict_to: [owner];
+            set_account_age_config => restrict_to: [owner];
+            add_trusted_storage => restrict_to: [owner];
+            remove_trusted_storage => restrict_to: [owner];
+            update_fee_subsidy_config => restrict_to: [owner];
+            update_limits => restrict_to: [owner];
+            set_temperature_check_royalty => restrict_to: [owner];
+            claim_royalties => restrict_to: [owner];
+            sweep_dispute_rewards => restrict_to: [owner];
+            // Moderator methods
+            add_to_denylist => restrict_to: [moderator, owner];
+            remove_from_denylist => restrict_to: [moderator, owner];
+            archive_proposal => restrict_to: [moderator, owner];
+            add_member => restrict_to: [moderator, owner];
+            remove_member => restrict_to: [moderator, owner];
+            // Auditor methods
+            dump_votes => restrict_to: [auditor];
+            // Security methods
+            rollback_execution => restrict_to: [security, owner];
         }
     }
 
     struct Governance {
         pub governance_parameters: GovernanceParameters,
+        /// Log of the last `MAX_PARAMETER_HISTORY` parameter changes, oldest first
+        pub parameter_history: Vec<ParameterChange>,
         pub temperature_checks: KeyValueStore<u64, TemperatureCheck>,
         pub temperature_check_count: u64,
+        /// Key: external reference given in a draft
+        /// Value: ID of the temperature check created from it
+        pub external_refs: KeyValueStore<String, u64>,
         pub proposals: KeyValueStore<u64, Proposal>,
         pub proposal_count: u64,
+        /// Condensed records of finalized proposals moved out of `proposals`
+        pub archived: KeyValueStore<u64, ArchivedProposal>,
+        /// Delegation component whose delegations can be cast by delegatees
+        pub vote_delegation: Option<Global<VoteDelegation>>,
+        /// DAO branding, mirrored into the component metadata
+        pub branding: GovernanceBranding,
+        /// XRD set aside by the DAO to reimburse attachment storage fees of passed proposals
+        pub storage_reimbursements: Vault,
+        /// Resource of the receipts issued for paid attachment storage, required to claim a reimbursement
+        pub storage_receipt_resource: Option<ResourceAddress>,
+        /// Storage receipts already used for a reimbursement, each usable once
+        pub claimed_storage_receipts: KeyValueStore<NonFungibleGlobalId, ()>,
+        /// Resources and multipliers voting power is computed from (XRD at 1x by default)
+        pub voting_power_config: VotingPowerConfig,
+        /// Accounts barred from voting and creating temperature checks, with the reason
+        pub denylist: KeyValueStore<Global<Account>, String>,
+        /// Allowlist of accounts that can vote on private (members-only) consultations
+        pub members: KeyValueStore<Global<Account>, Membership>,
+        /// Optional proof-of-personhood registry; when set, voters and authors must be verified by it
+        pub identity_verifier: Option<ComponentAddress>,
+        /// Optional account age oracle; when enforced, accounts created after a proposal's
+        /// snapshot cannot vote on it
+        pub account_age_config: Option<AccountAgeConfig>,
+        /// XRD vote bonds locked on proposals that take them
+        pub vote_bonds: Vault,
+        /// XRD bonds locked by challengers of passed proposals
+        pub challenge_bonds: Vault,
+        /// XRD slashed from failed challenges, claimable by the winning voters
+        pub dispute_rewards: Vault,
+        /// Trusted components that carry out proposal actions, by executor ID
+        pub executors: KeyValueStore<u64, ComponentAddress>,
+        pub executor_count: u64,
+        /// Voting groups (e.g. regional chapters) by group ID, for `VotingMode::Groups` proposals
+        pub voting_groups: KeyValueStore<u64, VotingGroup>,
+        pub voting_group_count: u64,
+        /// Voting group of each member account; an account is in at most one group
+        pub voting_group_members: KeyValueStore<Global<Account>, VotingGroupMembership>,
+        /// Sum of the voting groups' bloc weights, the eligible weight of `Groups` proposals
+        pub total_bloc_weight: Decimal,
+        /// Executor approvals required before a passed proposal can be executed; None disables them
+        pub execution_approval_config: Option<ExecutionApprovalConfig>,
+        /// Counters behind `get_governance_stats`
+        pub activity: GovernanceActivity,
+        /// Accounts that ever voted directly, to count unique voters
+        pub known_voters: KeyValueStore<Global<Account>, ()>,
+        /// XRD the DAO sets aside to pay the fees of successful votes
+        pub fee_subsidy: Vault,
+        /// Limits of the fee subsidy; None disables it
+        pub fee_subsidy_config: Option<FeeSubsidyConfig>,
+        /// Per-account subsidized vote counts, to prevent draining the subsidy vault
+        pub fee_subsidy_usage: KeyValueStore<Global<Account>, FeeSubsidyUsage>,
+        /// Limits on the links, vote options and selections of new temperature checks
+        pub limits: GovernanceLimits,
+        /// radix-file-storage components description files may be stored on
+        pub trusted_storage: KeyValueStore<ComponentAddress, ()>,
+        /// Non-fungible badges accounts issue to let DAO tooling or custodians vote on their behalf
+        pub signer_badge: ResourceManager,
+        /// Signer badges not revoked yet, with the account each one votes for
+        pub authorized_signers: KeyValueStore<NonFungibleLocalId, Global<Account>>,
     }
 
     impl Governance {
         /// Instantiates the governance component with the given owner badge
+        /// The branding is written to the component metadata, which only the component itself
+        /// can update (through the owner-only `update_branding`).
+        /// `temperature_check_royalty` is charged on every `make_temperature_check` call
+        /// (`RoyaltyAmount::Free` disables it) and can be changed with `set_temperature_check_royalty`.
         pub fn instantiate(
             owner_badge: ResourceAddress,
             governance_parameters: GovernanceParameters,
+            branding: GovernanceBranding,
+            temperature_check_royalty: RoyaltyAmount,
         ) -> Global<Governance> {
+            Self::instantiate_with_rule(
+                rule!(require(owner_badge)),
+                governance_parameters,
+                branding,
+                temperature_check_royalty,
+            )
+        }
+
+        /// Instantiates the governance component with an arbitrary owner rule, e.g. 2-of-3 badges
+        /// (`require_n_of`) or `require_amount`. The rule guards the owner, moderator, auditor and
+        /// security roles.
+        pub fn instantiate_with_rule(
+            owner_rule: AccessRule,
+            governance_parameters: GovernanceParameters,
+            branding: GovernanceBranding,
+            temperature_check_royalty: RoyaltyAmount,
+        ) -> Global<Governance> {
+            Self::validate_governance_parameters(&governance_parameters);
+            ensure(!branding.dao_name.is_empty(), GovernanceError::EmptyDaoName);
+
+            let (address_reservation, component_address) =
+                Runtime::allocate_component_address(Governance::blueprint_id());
+
+            let signer_badge =
+                ResourceBuilder::new_ruid_non_fungible::<AuthorizedSignerBadge>(OwnerRole::Fixed(
+                    owner_rule.clone(),
+                ))
+                .metadata(metadata! {
+                    init {
+                        "name" => "Authorized Signer Badge", locked;
+                    }
+                })
+                .mint_roles(mint_roles! {
+                    minter => rule!(require(global_caller(component_address)));
+                    minter_updater => rule!(deny_all);
+                })
+                .create_with_no_initial_supply();
+
             Self {
                 governance_parameters,
+                parameter_history: Vec::new(),
                 temperature_checks: KeyValueStore::new(),
                 temperature_check_count: 0,
+                external_refs: KeyValueStore::new(),
                 proposals: KeyValueStore::new(),
                 proposal_count: 0,
+                archived: KeyValueStore::new(),
+                vote_delegation: None,
+                branding: branding.clone(),
+                storage_reimbursements: Vault::new(XRD),
+                storage_receipt_resource: None,
+                claimed_storage_receipts: KeyValueStore::new(),
+                voting_power_config: VotingPowerConfig {
+                    resource_weights: vec![(XRD, Decimal::ONE)],
+                    mode: VotingPowerMode::CurrentBalance,
+                },
+                denylist: KeyValueStore::new(),
+                members: KeyValueStore::new(),
+                identity_verifier: None,
+                account_age_config: None,
+                vote_bonds: Vault::new(XRD),
+                challenge_bonds: Vault::new(XRD),
+                dispute_rewards: Vault::new(XRD),
+                executors: KeyValueStore::new(),
+                executor_count: 0,
+                voting_groups: KeyValueStore::new(),
+                voting_group_count: 0,
+                voting_group_members: KeyValueStore::new(),
+                total_bloc_weight: Decimal::ZERO,
+                execution_approval_config: None,
+                activity: GovernanceActivity::default(),
+                known_voters: KeyValueStore::new(),
+                fee_subsidy: Vault::new(XRD),
+                fee_subsidy_config: None,
+                fee_subsidy_usage: KeyValueStore::new(),
+                limits: GovernanceLimits::default(),
+                trusted_storage: KeyValueStore::new(),
+                signer_badge,
+                authorized_signers: KeyValueStore::new(),
             }
             .instantiate()
-            .prepare_to_globalize(OwnerRole::Fixed(rule!(require(owner_badge))))
+            .prepare_to_globalize(OwnerRole::Fixed(owner_rule.clone()))
             .roles(roles! {
-                owner => rule!(require(owner_badge));
+                owner => owner_rule.clone();
+                auditor => owner_rule.clone();
+                security => owner_rule.clone();
+                moderator => owner_rule;
+            })
+            .metadata(metadata! {
+                roles {
+                    metadata_setter => rule!(require(global_caller(component_address)));
+                    metadata_setter_updater => rule!(deny_all);
+                    metadata_locker => rule!(deny_all);
+                    metadata_locker_updater => rule!(deny_all);
+                },
+                init {
+                    "name" => branding.dao_name, updatable;
+                    "icon_url" => branding.logo_url, updatable;
+                    "info_url" => branding.docs_url, updatable;
+                    "forum_url" => branding.forum_url, updatable;
+                }
             })
+            .enable_component_royalties(component_royalties! {
+                roles {
+                    royalty_setter => rule!(require(global_caller(component_address)));
+                    royalty_setter_updater => rule!(deny_all);
+                    royalty_locker => rule!(deny_all);
+                    royalty_locker_updater => rule!(deny_all);
+                    royalty_claimer => rule!(require(global_caller(component_address)));
+                    royalty_claimer_updater => rule!(deny_all);
+                },
+                init {
+                    make_temperature_check => temperature_check_royalty, updatable;
+                    vote_on_temperature_check => RoyaltyAmount::Free, locked;
+                    vote_on_temperature_check_as_signer => RoyaltyAmount::Free, locked;
+                    vote_on_proposal_as_signer => RoyaltyAmount::Free, locked;
+                    issue_signer_badge => RoyaltyAmount::Free, locked;
+                    revoke_signer_badge => RoyaltyAmount::Free, locked;
+                    is_authorized_signer => RoyaltyAmount::Free, locked;
+                    get_signer_badge_resource => RoyaltyAmount::Free, locked;
+                    finalize_temperature_check => RoyaltyAmount::Free, locked;
+                    auto_elevate => RoyaltyAmount::Free, locked;
+                    second_temperature_check => RoyaltyAmount::Free, locked;
+                    co_sponsor => RoyaltyAmount::Free, locked;
+                    elevate_seconded => RoyaltyAmount::Free, locked;
+                    vote_on_temperature_check_as_delegatee => RoyaltyAmount::Free, locked;
+                    vote_on_proposal => RoyaltyAmount::Free, locked;
+                    change_vote => RoyaltyAmount::Free, locked;
+                    vote_on_proposal_with_rationale => RoyaltyAmount::Free, locked;
+                    vote_on_ballot => RoyaltyAmount::Free, locked;
+                    vote_on_proposal_with_points => RoyaltyAmount::Free, locked;
+                    respond_to_survey => RoyaltyAmount::Free, locked;
+                    vote_on_ranking => RoyaltyAmount::Free, locked;
+                    vote_in_group => RoyaltyAmount::Free, locked;
+                    react => RoyaltyAmount::Free, locked;
+                    vote_on_signal => RoyaltyAmount::Free, locked;
+                    vote_on_proposal_with_bond => RoyaltyAmount::Free, locked;
+                    vote_on_proposal_with_proof => RoyaltyAmount::Free, locked;
+                    reclaim_vote_bond => RoyaltyAmount::Free, locked;
+                    announce_intended_vote => RoyaltyAmount::Free, locked;
+                    vote_on_proposal_as_delegatee => RoyaltyAmount::Free, locked;
+                    finalize_proposal => RoyaltyAmount::Free, locked;
+                    challenge_result => RoyaltyAmount::Free, locked;
+                    reclaim_challenge_bond => RoyaltyAmount::Free, locked;
+                    claim_dispute_reward => RoyaltyAmount::Free, locked;
+                    sweep_dispute_rewards => RoyaltyAmount::Free, locked;
+                    spawn_reconfirmation => RoyaltyAmount::Free, locked;
+                    approve_execution => RoyaltyAmount::Free, locked;
+                    get_execution_approval_config => RoyaltyAmount::Free, locked;
+                    get_executor => RoyaltyAmount::Free, locked;
+                    get_voting_group => RoyaltyAmount::Free, locked;
+                    get_voting_group_of => RoyaltyAmount::Free, locked;
+                    get_governance_parameters => RoyaltyAmount::Free, locked;
+                    get_governance_stats => RoyaltyAmount::Free, locked;
+                    get_parameter_history => RoyaltyAmount::Free, locked;
+                    get_temperature_check_count => RoyaltyAmount::Free, locked;
+                    get_temperature_check => RoyaltyAmount::Free, locked;
+                    get_temperature_check_result => RoyaltyAmount::Free, locked;
+                    get_temperature_check_tally => RoyaltyAmount::Free, locked;
+                    get_sponsors => RoyaltyAmount::Free, locked;
+                    get_proposal_for_temperature_check => RoyaltyAmount::Free, locked;
+                    get_temperature_check_by_external_ref => RoyaltyAmount::Free, locked;
+                    get_proposal_by_external_ref => RoyaltyAmount::Free, locked;
+                    get_temperature_check_for_proposal => RoyaltyAmount::Free, locked;
+                    get_proposal => RoyaltyAmount::Free, locked;
+                    get_archived_proposal => RoyaltyAmount::Free, locked;
+                    get_description_file => RoyaltyAmount::Free, locked;
+                    verify_description_hash => RoyaltyAmount::Free, locked;
+                    verify_discussion_hash => RoyaltyAmount::Free, locked;
+                    get_proposal_count => RoyaltyAmount::Free, locked;
+                    get_turnout => RoyaltyAmount::Free, locked;
+                    get_temperature_check_time_remaining => RoyaltyAmount::Free, locked;
+                    get_proposal_time_remaining => RoyaltyAmount::Free, locked;
+                    get_proposal_tally => RoyaltyAmount::Free, locked;
+                    get_result_attestation => RoyaltyAmount::Free, locked;
+                    get_signal_tally => RoyaltyAmount::Free, locked;
+                    get_proposal_status => RoyaltyAmount::Free, locked;
+                    get_temperature_check_vote => RoyaltyAmount::Free, locked;
+                    get_proposal_vote => RoyaltyAmount::Free, locked;
+                    get_vote_rationale => RoyaltyAmount::Free, locked;
+                    get_intended_vote => RoyaltyAmount::Free, locked;
+                    get_survey_responses => RoyaltyAmount::Free, locked;
+                    get_ranking => RoyaltyAmount::Free, locked;
+                    get_group_tally => RoyaltyAmount::Free, locked;
+                    get_reaction => RoyaltyAmount::Free, locked;
+                    get_reaction_counts => RoyaltyAmount::Free, locked;
+                    get_delegated_votes => RoyaltyAmount::Free, locked;
+                    get_delegated_breakdown => RoyaltyAmount::Free, locked;
+                    export_voters => RoyaltyAmount::Free, locked;
+                    dump_votes => RoyaltyAmount::Free, locked;
+                    rollback_execution => RoyaltyAmount::Free, locked;
+                    preview_vote => RoyaltyAmount::Free, locked;
+                    get_branding => RoyaltyAmount::Free, locked;
+                    get_dao_name => RoyaltyAmount::Free, locked;
+                    get_logo_url => RoyaltyAmount::Free, locked;
+                    get_forum_url => RoyaltyAmount::Free, locked;
+                    get_docs_url => RoyaltyAmount::Free, locked;
+                    fund_storage_reimbursements => RoyaltyAmount::Free, locked;
+                    claim_storage_reimbursement => RoyaltyAmount::Free, locked;
+                    get_storage_reimbursement_balance => RoyaltyAmount::Free, locked;
+                    get_voting_power_config => RoyaltyAmount::Free, locked;
+                    get_voting_power => RoyaltyAmount::Free, locked;
+                    is_denylisted => RoyaltyAmount::Free, locked;
+                    is_member => RoyaltyAmount::Free, locked;
+                    get_identity_verifier => RoyaltyAmount::Free, locked;
+                    get_account_age_config => RoyaltyAmount::Free, locked;
+                    is_trusted_storage => RoyaltyAmount::Free, locked;
+                    fund_fee_subsidy => RoyaltyAmount::Free, locked;
+                    get_fee_subsidy_balance => RoyaltyAmount::Free, locked;
+                    get_fee_subsidy_config => RoyaltyAmount::Free, locked;
+                    get_limits => RoyaltyAmount::Free, locked;
+                    make_proposal => RoyaltyAmount::Free, locked;
+                    force_elevate => RoyaltyAmount::Free, locked;
+                    make_two_phase_proposal => RoyaltyAmount::Free, locked;
+                    reopen_proposal => RoyaltyAmount::Free, locked;
+                    execute_proposal => RoyaltyAmount::Free, locked;
+                    set_execution_approval_config => RoyaltyAmount::Free, locked;
+                    register_executor => RoyaltyAmount::Free, locked;
+                    remove_executor => RoyaltyAmount::Free, locked;
+                    register_voting_group => RoyaltyAmount::Free, locked;
+                    update_voting_group_weight => RoyaltyAmount::Free, locked;
+                    add_voting_group_member => RoyaltyAmount::Free, locked;
+                    remove_voting_group_member => RoyaltyAmount::Free, locked;
+                    update_governance_parameters => RoyaltyAmount::Free, locked;
+                    set_vote_delegation => RoyaltyAmount::Free, locked;
+                    update_branding => RoyaltyAmount::Free, locked;
+                    set_storage_receipt_resource => RoyaltyAmount::Free, locked;
+                    update_voting_power_config => RoyaltyAmount::Free, locked;
+                    set_identity_verifier => RoyaltyAmount::Free, locked;
+                    set_account_age_config => RoyaltyAmount::Free, locked;
+                    add_trusted_storage => RoyaltyAmount::Free, locked;
+                    remove_trusted_storage => RoyaltyAmount::Free, locked;
+                    update_fee_subsidy_config => RoyaltyAmount::Free, locked;
+                    update_limits => RoyaltyAmount::Free, locked;
+                    set_temperature_check_royalty => RoyaltyAmount::Free, locked;
+                    claim_royalties => RoyaltyAmount::Free, locked;
+                    add_to_denylist => RoyaltyAmount::Free, locked;
+                    remove_from_denylist => RoyaltyAmount::Free, locked;
+                    archive_proposal => RoyaltyAmount::Free, locked;
+                    add_member => RoyaltyAmount::Free, locked;
+                    remove_member => RoyaltyAmount::Free, locked;
+                }
+            })
+            .with_address(address_reservation)
             .globalize()
         }
 
@@ -78,166 +581,580 @@ mod governance {
         ) -> u64 {
             // Verify the author account is present in the transaction
             Runtime::assert_access_rule(author.get_owner_role().rule);
+            self.assert_not_denylisted(author);
+            self.assert_verified(author);
+
+            // Co-authors consent by proving their presence
+            ensure(draft.co_authors.len() <= MAX_CO_AUTHORS, GovernanceError::TooManyCoAuthors);
+            let mut co_authors: Vec<Global<Account>> = Vec::new();
+            for address in draft.co_authors.iter() {
+                let co_author = Global::<Account>::from(*address);
+                ensure(
+                    co_author != author && !co_authors.contains(&co_author),
+                    GovernanceError::DuplicateCoAuthor,
+                );
+                Runtime::assert_access_rule(co_author.get_owner_role().rule);
+                self.assert_not_denylisted(co_author);
+                co_authors.push(co_author);
+            }
 
             // Validate inputs
-            assert!(
-                !draft.title.is_empty(),
-                "Temperature check title cannot be empty"
-            );
-            assert!(
-                !draft.short_description.is_empty(),
-                "Temperature check short description cannot be empty"
+            ensure(!draft.title.is_empty(), GovernanceError::EmptyTitle);
+            ensure(!draft.short_description.is_empty(), GovernanceError::EmptyShortDescription);
+            ensure(
+                !draft.description.is_empty() || draft.description_file.is_some(),
+                GovernanceError::EmptyDescription,
             );
-            assert!(
-                !draft.description.is_empty(),
-                "Temperature check description cannot be empty"
+            if let Some(description_file) = &draft.description_file {
+                ensure(
+                    self.is_trusted_storage(description_file.file.component_address),
+                    GovernanceError::UntrustedStorage,
+                );
+            }
+            self.validate_attachments(&draft.attachments);
+            let limits = self.limits;
+            Self::validate_vote_options(&draft.vote_options, draft.max_selections, &limits);
+            ensure(
+                draft.links.len() <= limits.max_links as usize,
+                GovernanceError::TooManyLinks { max: limits.max_links },
             );
-            assert!(
-                !draft.vote_options.is_empty(),
-                "Temperature check must have at least one vote option"
+
+            // Validate text lengths
+            let max_description_length = self.governance_parameters.max_description_length;
+            ensure(
+                draft.title.chars().count() <= MAX_TITLE_LENGTH,
+                GovernanceError::TitleTooLong,
             );
-            assert!(
-                draft.vote_options.len() <= MAX_VOTE_OPTIONS,
-                "Too many vote options (max {})",
-                MAX_VOTE_OPTIONS
+            ensure(
+                draft.short_description.chars().count() <= MAX_SHORT_DESCRIPTION_LENGTH,
+                GovernanceError::ShortDescriptionTooLong,
             );
-            assert!(
-                draft.links.len() <= MAX_LINKS,
-                "Too many links (max {})",
-                MAX_LINKS
+            ensure(
+                draft.description.chars().count() <= max_description_length as usize,
+                GovernanceError::DescriptionTooLong { max: max_description_length },
             );
 
-            // Validate vote option colors are unique
-            let mut seen_colors: Vec<VoteOptionColor> = Vec::new();
-            for option in &draft.vote_options {
-                assert!(
-                    !seen_colors.contains(&option.color),
-                    "Duplicate vote option color"
+            // Validate links use https
+            for link in &draft.links {
+                ensure(
+                    link.as_str().starts_with(REQUIRED_LINK_SCHEME),
+                    GovernanceError::InsecureLink,
                 );
-                seen_colors.push(option.color);
             }
 
-            // Validate max_selections
-            if let Some(n) = draft.max_selections {
-                assert!(n > 0, "max_selections must be greater than 0");
-                assert!(n <= MAX_SELECTIONS, "max_selections cannot exceed {}", MAX_SELECTIONS);
-                assert!(
-                    (n as usize) <= draft.vote_options.len(),
-                    "max_selections cannot exceed number of vote options"
+            // Validate the action targets a registered executor with decodable arguments
+            if let Some(ProposalAction::CallExecutor { executor_id, args }) = &draft.action {
+                ensure(
+                    self.executors.get(executor_id).is_some(),
+                    GovernanceError::ExecutorNotFound,
+                );
+                ensure(
+                    scrypto_decode::<ScryptoValue>(args).is_ok(),
+                    GovernanceError::InvalidActionArgs,
                 );
             }
 
-            // Auto-generate IDs for vote options (0, 1, 2, ...)
-            let vote_options: Vec<ProposalVoteOption> = draft
-                .vote_options
-                .into_iter()
-                .enumerate()
-                .map(|(index, input)| ProposalVoteOption {
-                    id: ProposalVoteOptionId(index as u32),
+            Self::validate_proposal_kind(&draft.kind);
+            if let ProposalKind::Ranking { temperature_check_ids } = &draft.kind {
+                ensure(
+                    draft.voting_mode == VotingMode::PointsAllocation
+                        && draft.vote_options.len() == temperature_check_ids.len()
+                        && temperature_check_ids
+                            .iter()
+                            .all(|id| *id < self.temperature_check_count),
+                    GovernanceError::InvalidRankingCandidates,
+                );
+            }
+
+            if let Some(vote_bond) = draft.vote_bond {
+                ensure(vote_bond > Decimal::ZERO, GovernanceError::InvalidVoteBond);
+            }
+            Self::validate_min_balance(draft.min_balance_to_vote);
+            if let Some(abstain_option) = draft.abstain_option {
+                ensure(
+                    (abstain_option.0 as usize) < draft.vote_options.len(),
+                    GovernanceError::InvalidAbstainOption,
+                );
+            }
+            if let Some(external_ref) = &draft.external_ref {
+                ensure(
+                    !external_ref.is_empty()
+                        && external_ref.chars().count() <= MAX_EXTERNAL_REF_LENGTH,
+                    GovernanceError::InvalidExternalRef,
+                );
+                ensure(
+                    self.external_refs.get(external_ref).is_none(),
+                    GovernanceError::DuplicateExternalRef,
+                );
+            }
+            // Points are only cast with `vote_on_proposal_with_points`, which takes no bond or proof
+            ensure(
+                draft.voting_mode == VotingMode::Selection
+                    || (draft.vote_bond.is_none() && draft.eligibility_root.is_none()),
+                GovernanceError::WrongVotingMode,
+            );
+
+            // Extra ballot questions follow the same option rules as the main question
+            ensure(
+                draft.extra_questions.len() <= MAX_EXTRA_QUESTIONS,
+                GovernanceError::TooManyQuestions,
+            );
+            let mut questions: Vec<BallotQuestion> = Vec::new();
+            for (index, input) in draft.extra_questions.into_iter().enumerate() {
+                ensure(
+                    !input.label.is_empty()
+                        && input.label.chars().count() <= MAX_TITLE_LENGTH
+                        && input.approval_threshold > Decimal::ZERO
+                        && input.approval_threshold <= Decimal::ONE,
+                    GovernanceError::InvalidQuestion,
+                );
+                Self::validate_vote_options(&input.vote_options, input.max_selections, &limits);
+
+                let option_count = input.vote_options.len();
+                questions.push(BallotQuestion {
+                    id: index as u32 + 1,
                     label: input.label,
-                    color: input.color,
-                })
-                .collect();
+                    vote_options: Self::assign_option_ids(input.vote_options),
+                    max_selections: input.max_selections,
+                    approval_threshold: input.approval_threshold,
+                    option_weights: vec![Decimal::ZERO; option_count],
+                    option_voter_counts: vec![0; option_count],
+                    total_weight_cast: Decimal::ZERO,
+                    voter_count: 0,
+                    status: ProposalStatus::Active,
+                    winning_options: Vec::new(),
+                });
+            }
+
+            let vote_options = Self::assign_option_ids(draft.vote_options);
 
+            let option_count = vote_options.len();
             let id = self.temperature_check_count;
             self.temperature_check_count += 1;
+            if let Some(external_ref) = &draft.external_ref {
+                self.external_refs.insert(external_ref.clone(), id);
+            }
 
             let now = Clock::current_time_rounded_to_seconds();
-            let deadline = now.add_days(self.governance_parameters.temperature_check_days as i64).unwrap();
+            let (start, deadline) = Self::voting_window(
+                &self.governance_parameters,
+                now,
+                self.governance_parameters.temperature_check_days,
+            );
 
             let temperature_check = TemperatureCheck {
                 title: draft.title,
                 short_description: draft.short_description,
                 description: draft.description,
+                description_file: draft.description_file,
+                attachments: draft.attachments,
+                kind: draft.kind,
+                action: draft.action,
+                vote_bond: draft.vote_bond,
                 vote_options,
                 links: draft.links,
+                discussion_hash: draft.discussion_hash,
                 quorum: self.governance_parameters.temperature_check_quorum,
                 max_selections: draft.max_selections,
                 votes: KeyValueStore::new(),
+                delegated_votes: KeyValueStore::new(),
                 approval_threshold: self.governance_parameters.temperature_check_approval_threshold,
-                start: now,
+                start,
                 deadline,
                 elevated_proposal_id: None,
                 author,
+                co_authors,
+                members_only: draft.members_only,
+                anonymous: draft.anonymous,
+                eligibility_root: draft.eligibility_root,
+                allow_rationales: draft.allow_rationales,
+                questions,
+                voting_mode: draft.voting_mode,
+                abstain_option: draft.abstain_option,
+                min_balance_to_vote: draft.min_balance_to_vote,
+                external_ref: draft.external_ref,
                 last_vote_at: now,
+                for_weight: Decimal::ZERO,
+                against_weight: Decimal::ZERO,
+                option_weights: vec![Decimal::ZERO; option_count],
+                status: TemperatureCheckStatus::Active,
+                auto_elevate: draft.auto_elevate,
+                endorsements: KeyValueStore::new(),
+                endorsement_weight: Decimal::ZERO,
+                propose_threshold: self.governance_parameters.temperature_check_propose_threshold,
+                sponsors: Vec::new(),
+                sponsor_threshold: self.governance_parameters.temperature_check_sponsor_threshold,
             };
 
-            let title = temperature_check.title.clone();
-            let start = temperature_check.start;
-            let deadline = temperature_check.deadline;
+            let created_event = TemperatureCheckCreatedEvent {
+                temperature_check_id: id,
+                title: temperature_check.title.clone(),
+                vote_options: temperature_check.vote_options.clone(),
+                max_selections: temperature_check.max_selections,
+                voting_mode: temperature_check.voting_mode,
+                links: temperature_check.links.clone(),
+                quorum: temperature_check.quorum,
+                approval_threshold: temperature_check.approval_threshold,
+                start: temperature_check.start,
+                deadline: temperature_check.deadline,
+            };
 
             self.temperature_checks.insert(id, temperature_check);
 
-            Runtime::emit_event(TemperatureCheckCreatedEvent {
-                temperature_check_id: id,
-                title,
-                start,
-                deadline,
-            });
+            Runtime::emit_event(created_event);
 
             id
         }
 
+        /// Finalizes a temperature check after its deadline, fixing its status to Passed or Rejected
+        /// Passed requires For + Against to reach the quorum and For to hold at least the approval threshold
+        /// Callable by anyone
+        pub fn finalize_temperature_check(&mut self, temperature_check_id: u64) -> TemperatureCheckStatus {
+            let mut tc = self
+                .temperature_checks
+                .get_mut(&temperature_check_id)
+                .unwrap_or_else(|| GovernanceError::TemperatureCheckNotFound.raise());
+
+            let now = Clock::current_time_rounded_to_seconds();
+            ensure(
+                now.compare(tc.deadline, TimeComparisonOperator::Gte),
+                GovernanceError::VotingNotEnded,
+            );
+            ensure(
+                tc.status == TemperatureCheckStatus::Active,
+                GovernanceError::TemperatureCheckAlreadyFinalized,
+            );
+
+            let total = tc.for_weight + tc.against_weight;
+            let passed = total.is_positive()
+                && total >= tc.quorum
+                && tc.for_weight / total >= tc.approval_threshold;
+            let status = if passed {
+                TemperatureCheckStatus::Passed
+            } else {
+                TemperatureCheckStatus::Rejected
+            };
+            tc.status = status;
+
+            Runtime::emit_event(TemperatureCheckFinalizedEvent {
+                temperature_check_id,
+                status,
+                for_weight: tc.for_weight,
+                against_weight: tc.against_weight,
+            });
+
+            status
+        }
+
         /// Elevates a temperature check to a proposal (GP - Governance Proposal)
-        /// Only callable by the owner
+        /// Only callable by the owner, once the temperature check's deadline has passed
+        /// and it has been finalized as Passed
         ///
         /// # Arguments
         /// * `temperature_check_id` - The ID of the temperature check to elevate
         ///
         /// Returns the ID of the created proposal
         pub fn make_proposal(&mut self, temperature_check_id: u64) -> u64 {
-            // Get the temperature check
+            let tc = self
+                .temperature_checks
+                .get(&temperature_check_id)
+                .unwrap_or_else(|| GovernanceError::TemperatureCheckNotFound.raise());
+
+            let now = Clock::current_time_rounded_to_seconds();
+            ensure(
+                now.compare(tc.deadline, TimeComparisonOperator::Gte),
+                GovernanceError::VotingNotEnded,
+            );
+            ensure(
+                tc.status == TemperatureCheckStatus::Passed,
+                GovernanceError::TemperatureCheckNotPassed,
+            );
+            self.assert_elevation_delay_elapsed(tc.deadline);
+            drop(tc);
+
+            let proposal_id = self.elevate_temperature_check(temperature_check_id, false);
+
+            Runtime::emit_event(TemperatureCheckElevatedEvent {
+                temperature_check_id,
+                proposal_id,
+            });
+
+            proposal_id
+        }
+
+        /// Elevates a temperature check to a proposal regardless of its deadline and result
+        /// Emergency path for the owner; emits `ProposalForceElevatedEvent` so it can be told apart
+        ///
+        /// Returns the ID of the created proposal
+        pub fn force_elevate(&mut self, temperature_check_id: u64) -> u64 {
+            let temperature_check_status = self
+                .temperature_checks
+                .get(&temperature_check_id)
+                .unwrap_or_else(|| GovernanceError::TemperatureCheckNotFound.raise())
+                .status;
+
+            let proposal_id = self.elevate_temperature_check(temperature_check_id, false);
+
+            Runtime::emit_event(ProposalForceElevatedEvent {
+                temperature_check_id,
+                proposal_id,
+                temperature_check_status,
+            });
+
+            proposal_id
+        }
+
+        /// Creates a two-phase proposal straight from a draft, for routine items
+        /// A non-binding signal window of `signal_phase_days` replaces the temperature check vote
+        /// and is followed automatically by the binding window, which uses `binding_quorum`.
+        /// Only callable by the owner; the author must prove their presence
+        ///
+        /// Returns the ID of the created proposal
+        pub fn make_two_phase_proposal(
+            &mut self,
+            author: Global<Account>,
+            draft: TemperatureCheckDraft,
+        ) -> u64 {
+            let temperature_check_id = self.make_temperature_check(author, draft);
+
+            // The temperature check only holds the content; its vote is closed immediately
+            let mut tc = self.temperature_checks.get_mut(&temperature_check_id).unwrap();
+            tc.deadline = tc.start;
+            tc.status = TemperatureCheckStatus::Passed;
+            drop(tc);
+
+            Runtime::emit_event(TemperatureCheckFinalizedEvent {
+                temperature_check_id,
+                status: TemperatureCheckStatus::Passed,
+                for_weight: Decimal::ZERO,
+                against_weight: Decimal::ZERO,
+            });
+
+            self.elevate_temperature_check(temperature_check_id, true)
+        }
+
+        /// Elevates a passed temperature check created with `auto_elevate` to a proposal
+        /// Callable by anyone once the review delay after the temperature check deadline has elapsed
+        ///
+        /// Returns the ID of the created proposal
+        pub fn auto_elevate(&mut self, temperature_check_id: u64) -> u64 {
+            let tc = self
+                .temperature_checks
+                .get(&temperature_check_id)
+                .unwrap_or_else(|| GovernanceError::TemperatureCheckNotFound.raise());
+
+            ensure(tc.auto_elevate, GovernanceError::NotAutoElevated);
+            ensure(
+                tc.status == TemperatureCheckStatus::Passed,
+                GovernanceError::TemperatureCheckNotPassed,
+            );
+
+            let now = Clock::current_time_rounded_to_seconds();
+            let review_end = tc
+                .deadline
+                .add_days(self.governance_parameters.auto_elevation_review_days as i64)
+                .unwrap();
+            ensure(
+                now.compare(review_end, TimeComparisonOperator::Gte),
+                GovernanceError::ReviewPeriodNotEnded,
+            );
+            self.assert_elevation_delay_elapsed(tc.deadline);
+            drop(tc);
+
+            self.elevate_temperature_check(temperature_check_id, false)
+        }
+
+        /// Stakes the account's current voting power as endorsement of a temperature check
+        /// The account must prove its presence and can second a temperature check once.
+        pub fn second_temperature_check(&mut self, account: Global<Account>, temperature_check_id: u64) {
+            Runtime::assert_access_rule(account.get_owner_role().rule);
+            self.assert_not_denylisted(account);
+            self.assert_verified(account);
+
+            let start = self
+                .temperature_checks
+                .get(&temperature_check_id)
+                .map(|tc| tc.start)
+                .unwrap_or_else(|| GovernanceError::TemperatureCheckNotFound.raise());
+            let weight = self.voting_power_at(account, start);
+
             let mut tc = self
                 .temperature_checks
                 .get_mut(&temperature_check_id)
-                .expect("Temperature check not found");
+                .unwrap_or_else(|| GovernanceError::TemperatureCheckNotFound.raise());
 
-            assert!(
+            ensure(
                 tc.elevated_proposal_id.is_none(),
-                "Temperature check has already been elevated to a proposal"
+                GovernanceError::TemperatureCheckAlreadyElevated,
             );
+            ensure(tc.endorsements.get(&account).is_none(), GovernanceError::AlreadySeconded);
 
-            let proposal_id = self.proposal_count;
-            self.proposal_count += 1;
+            tc.endorsements.insert(account, weight);
+            tc.endorsement_weight += weight;
+            drop(tc);
 
-            let now = Clock::current_time_rounded_to_seconds();
-            let deadline = now.add_days(self.governance_parameters.proposal_length_days as i64).unwrap();
+            Runtime::emit_event(TemperatureCheckSecondedEvent {
+                temperature_check_id,
+                account,
+                weight,
+            });
+        }
 
-            let proposal = Proposal {
-                title: tc.title.clone(),
-                short_description: tc.short_description.clone(),
-                description: tc.description.clone(),
-                vote_options: tc.vote_options.clone(),
-                links: tc.links.clone(),
-                quorum: self.governance_parameters.proposal_quorum,
-                max_selections: tc.max_selections,
-                votes: KeyValueStore::new(),
-                approval_threshold: self.governance_parameters.proposal_approval_threshold,
-                start: now,
-                deadline,
+        /// Adds the account to the co-sponsors of a temperature check
+        /// The account must prove its presence and can co-sponsor a temperature check once.
+        /// Unlike seconding, no voting power is staked: only the number of sponsors counts.
+        pub fn co_sponsor(&mut self, temperature_check_id: u64, account: Global<Account>) {
+            Runtime::assert_access_rule(account.get_owner_role().rule);
+            self.assert_not_denylisted(account);
+            self.assert_verified(account);
+
+            let mut tc = self
+                .temperature_checks
+                .get_mut(&temperature_check_id)
+                .unwrap_or_else(|| GovernanceError::TemperatureCheckNotFound.raise());
+
+            ensure(
+                tc.elevated_proposal_id.is_none(),
+                GovernanceError::TemperatureCheckAlreadyElevated,
+            );
+            ensure(!tc.sponsors.contains(&account), GovernanceError::AlreadySponsored);
+            ensure(
+                (tc.sponsors.len() as u32) < MAX_SPONSORS,
+                GovernanceError::TooManySponsors,
+            );
+
+            tc.sponsors.push(account);
+            let sponsor_count = tc.sponsors.len() as u32;
+            drop(tc);
+
+            Runtime::emit_event(TemperatureCheckSponsoredEvent {
                 temperature_check_id,
-                author: tc.author,
-                last_vote_at: now,
-            };
+                account,
+                sponsor_count,
+            });
+        }
 
-            tc.elevated_proposal_id = Some(proposal_id);
+        /// Elevates a passed temperature check whose endorsements reached its propose threshold,
+        /// or whose co-sponsors reached its sponsor threshold
+        /// Callable by anyone, without the owner
+        ///
+        /// Returns the ID of the created proposal
+        pub fn elevate_seconded(&mut self, temperature_check_id: u64) -> u64 {
+            let tc = self
+                .temperature_checks
+                .get(&temperature_check_id)
+                .unwrap_or_else(|| GovernanceError::TemperatureCheckNotFound.raise());
+
+            ensure(
+                tc.status == TemperatureCheckStatus::Passed,
+                GovernanceError::TemperatureCheckNotPassed,
+            );
+            let sponsored = tc.sponsor_threshold > 0
+                && tc.sponsors.len() as u32 >= tc.sponsor_threshold;
+            ensure(
+                tc.endorsement_weight >= tc.propose_threshold || sponsored,
+                GovernanceError::ProposeThresholdNotReached,
+            );
+            self.assert_elevation_delay_elapsed(tc.deadline);
             drop(tc);
 
-            let title = proposal.title.clone();
-            let start = proposal.start;
-            let deadline = proposal.deadline;
+            self.elevate_temperature_check(temperature_check_id, false)
+        }
 
-            self.proposals.insert(proposal_id, proposal);
+        /// Reopens a finalized proposal for re-confirmation (owner only)
+        /// The new proposal starts with the original tally multiplied by `reopen_carry_over_factor`.
+        /// When an account's power is cast again on the new proposal, its carried-over
+        /// contribution is replaced by the fresh vote. A proposal can be re-opened once; to
+        /// re-open again, re-open the latest proposal of the chain.
+        ///
+        /// Returns the ID of the new proposal
+        pub fn reopen_proposal(&mut self, proposal_id: u64) -> u64 {
+            let eligible_weight = self.eligible_voting_weight();
+            let factor = self.governance_parameters.reopen_carry_over_factor;
+            ensure(
+                factor >= Decimal::ZERO && factor <= Decimal::ONE,
+                GovernanceError::InvalidCarryOverFactor,
+            );
 
-            Runtime::emit_event(ProposalCreatedEvent {
-                proposal_id,
+            let origin = self
+                .proposals
+                .get(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+            ensure(origin.status != ProposalStatus::Active, GovernanceError::ProposalNotFinalized);
+            ensure(origin.reopened_as.is_none(), GovernanceError::AlreadyReopened);
+            let temperature_check_id = origin.temperature_check_id;
+            // Anonymous votes cannot be replaced per account, and point splits and group blocs
+            // are not carried over, so nothing is carried over for any of them
+            let factor = if origin.anonymous || origin.voting_mode != VotingMode::Selection {
+                Decimal::ZERO
+            } else {
+                factor
+            };
+            let carried_weights: Vec<Decimal> =
+                origin.option_weights.iter().map(|w| *w * factor).collect();
+            let carried_total = origin.total_weight_cast * factor;
+            let carried_raw_weights: Vec<Decimal> =
+                origin.raw_option_weights.iter().map(|w| *w * factor).collect();
+            let carried_raw_total = origin.raw_total_weight_cast * factor;
+            let carried_abstain_only = origin.abstain_only_weight * factor;
+            // Voters whose votes carry over still count as voters, until they vote again
+            let (carried_voter_count, carried_option_voter_counts) = if factor.is_zero() {
+                (0, vec![0; origin.option_voter_counts.len()])
+            } else {
+                (
+                    origin.voter_count + origin.carried_voter_count,
+                    origin
+                        .option_voter_counts
+                        .iter()
+                        .zip(origin.carried_option_voter_counts.iter())
+                        .map(|(fresh, carried)| fresh + carried)
+                        .collect(),
+                )
+            };
+            drop(origin);
+
+            let tc = self
+                .temperature_checks
+                .get(&temperature_check_id)
+                .unwrap();
+            let now = Clock::current_time_rounded_to_seconds();
+            let mut proposal = Self::build_proposal(
+                &tc,
                 temperature_check_id,
-                title,
-                start,
-                deadline,
+                &self.governance_parameters,
+                eligible_weight,
+                self.total_bloc_weight,
+                now,
+            );
+            drop(tc);
+
+            proposal.reopened_from = Some(proposal_id);
+            proposal.carry_over_factor = factor;
+            proposal.carried_option_weights = carried_weights.clone();
+            proposal.option_weights = carried_weights;
+            proposal.total_weight_cast = carried_total;
+            proposal.raw_option_weights = carried_raw_weights;
+            proposal.raw_total_weight_cast = carried_raw_total;
+            proposal.abstain_only_weight = carried_abstain_only;
+            proposal.carried_voter_count = carried_voter_count;
+            proposal.carried_option_voter_counts = carried_option_voter_counts;
+
+            let new_proposal_id = self.proposal_count;
+            self.proposal_count += 1;
+
+            let created_event = Self::proposal_created_event(new_proposal_id, temperature_check_id, &proposal);
+
+            self.proposals.insert(new_proposal_id, proposal);
+            self.proposals.get_mut(&proposal_id).unwrap().reopened_as = Some(new_proposal_id);
+
+            Runtime::emit_event(created_event);
+            Runtime::emit_event(ProposalReopenedEvent {
+                proposal_id: new_proposal_id,
+                reopened_from: proposal_id,
+                carry_over_factor: factor,
             });
 
-            proposal_id
+            new_proposal_id
         }
 
         /// Vote on a temperature check
@@ -251,43 +1168,308 @@ mod governance {
             // Verify the account is present in the transaction
             Runtime::assert_access_rule(account.get_owner_role().rule);
 
+            self.cast_temperature_check_vote(account, temperature_check_id, vote);
+        }
+
+        /// Vote on a temperature check on behalf of an account, presenting a signer badge the
+        /// account issued with `issue_signer_badge` instead of the account's own key
+        pub fn vote_on_temperature_check_as_signer(
+            &mut self,
+            account: Global<Account>,
+            temperature_check_id: u64,
+            vote: TemperatureCheckVote,
+            signer: Proof,
+        ) {
+            self.assert_signer_of(account, signer);
+
+            self.cast_temperature_check_vote(account, temperature_check_id, vote);
+        }
+
+        /// Vote on a proposal on behalf of an account, presenting a signer badge the account
+        /// issued with `issue_signer_badge` instead of the account's own owner rule, e.g. for
+        /// accounts controlled by an access controller or a multisig
+        /// Works like `vote_on_proposal`
+        pub fn vote_on_proposal_as_signer(
+            &mut self,
+            account: Global<Account>,
+            proposal_id: u64,
+            votes: Vec<ProposalVoteOptionId>,
+            signer: Proof,
+        ) {
+            self.assert_signer_of(account, signer);
+            ensure(
+                self.vote_bond_of(proposal_id).is_none(),
+                GovernanceError::VoteBondRequired,
+            );
+
+            self.record_vote(account, proposal_id, votes, None, None);
+        }
+
+        /// Mints a signer badge that lets its holder vote on temperature checks and proposals
+        /// for the account
+        /// The account must prove its presence; the badge works until the account revokes it
+        pub fn issue_signer_badge(&mut self, account: Global<Account>) -> Bucket {
+            Runtime::assert_access_rule(account.get_owner_role().rule);
+
+            let badge = self
+                .signer_badge
+                .mint_ruid_non_fungible(AuthorizedSignerBadge { account });
+            let badge_id = badge.as_non_fungible().non_fungible_local_id();
+            self.authorized_signers.insert(badge_id.clone(), account);
+
+            Runtime::emit_event(SignerBadgeIssuedEvent { account, badge_id });
+
+            badge
+        }
+
+        /// Revokes a signer badge the account issued, so its holder can no longer vote for it
+        /// The account must prove its presence
+        pub fn revoke_signer_badge(&mut self, account: Global<Account>, badge_id: NonFungibleLocalId) {
+            Runtime::assert_access_rule(account.get_owner_role().rule);
+            ensure(
+                self.is_authorized_signer(account, badge_id.clone()),
+                GovernanceError::SignerBadgeNotFound,
+            );
+
+            self.authorized_signers.remove(&badge_id);
+
+            Runtime::emit_event(SignerBadgeRevokedEvent { account, badge_id });
+        }
+
+        /// Whether a signer badge is authorized to vote for the account
+        pub fn is_authorized_signer(&self, account: Global<Account>, badge_id: NonFungibleLocalId) -> bool {
+            self.authorized_signers
+                .get(&badge_id)
+                .map_or(false, |signer_account| *signer_account == account)
+        }
+
+        /// Returns the resource of the signer badges
+        pub fn get_signer_badge_resource(&self) -> ResourceAddress {
+            self.signer_badge.address()
+        }
+
+        /// Panics unless `signer` is a proof of a signer badge the account issued and has not revoked
+        fn assert_signer_of(&self, account: Global<Account>, signer: Proof) {
+            let badge_id = signer
+                .check_with_message(
+                    self.signer_badge.address(),
+                    GovernanceError::NotAuthorizedSigner.to_string(),
+                )
+                .as_non_fungible()
+                .non_fungible_local_id();
+            ensure(
+                self.is_authorized_signer(account, badge_id),
+                GovernanceError::NotAuthorizedSigner,
+            );
+        }
+
+        /// Records a temperature check vote of an account whose presence or signer was verified
+        fn cast_temperature_check_vote(
+            &mut self,
+            account: Global<Account>,
+            temperature_check_id: u64,
+            vote: TemperatureCheckVote,
+        ) {
+            self.assert_not_denylisted(account);
+            self.assert_verified(account);
+
+            let (members_only, start) = self
+                .temperature_checks
+                .get(&temperature_check_id)
+                .map(|tc| (tc.members_only, tc.start))
+                .unwrap_or_else(|| GovernanceError::TemperatureCheckNotFound.raise());
+            let weight = self.voting_power_at(account, start);
+            ensure(
+                !members_only || self.was_member_at(account, start),
+                GovernanceError::NotAMember,
+            );
+
             // Get the temperature check
             let mut tc = self
                 .temperature_checks
                 .get_mut(&temperature_check_id)
-                .expect("Temperature check not found");
+                .unwrap_or_else(|| GovernanceError::TemperatureCheckNotFound.raise());
 
             // Check the vote is still open
             let now = Clock::current_time_rounded_to_seconds();
-            assert!(
+            ensure(
                 now.compare(tc.start, TimeComparisonOperator::Gte),
-                "Voting has not started yet"
+                GovernanceError::VotingNotStarted,
             );
-            assert!(
+            ensure(
                 now.compare(tc.deadline, TimeComparisonOperator::Lt),
-                "Voting has ended"
+                GovernanceError::VotingEnded,
             );
 
             // Check the account has not already voted
-            assert!(
+            ensure(
                 tc.votes.get(&account).is_none(),
-                "Account has already voted on this temperature check"
+                GovernanceError::AlreadyVotedOnTemperatureCheck,
             );
 
+            // A preference must be a valid selection of the vote options
+            if let TemperatureCheckVote::Options(options) = &vote {
+                if let Some(error) =
+                    Self::selection_error(&tc.vote_options, tc.max_selections, options)
+                {
+                    error.raise();
+                }
+            }
+
+            // A direct vote takes precedence over power already cast by delegatees
+            let overridden = tc.delegated_votes.remove(&account).unwrap_or_default();
+            for delegated_vote in overridden.iter() {
+                let delegatee_vote = tc.votes.get(&delegated_vote.delegatee).unwrap().vote.clone();
+                Self::tally_temperature_check_vote(&mut tc, &delegatee_vote, -delegated_vote.weight);
+            }
+
             // Record the vote and update last_vote_at
-            tc.votes.insert(account, vote);
+            tc.votes.insert(
+                account,
+                TemperatureCheckVoteRecord {
+                    vote: vote.clone(),
+                    weight,
+                    cast_at: now,
+                },
+            );
             tc.last_vote_at = now;
+            Self::tally_temperature_check_vote(&mut tc, &vote, weight);
+            let (for_weight, against_weight) = if tc.members_only {
+                (Decimal::ZERO, Decimal::ZERO)
+            } else {
+                (tc.for_weight, tc.against_weight)
+            };
+
+            drop(tc);
+            self.record_voter(account);
+            self.subsidize_vote_fee(account);
 
             Runtime::emit_event(TemperatureCheckVotedEvent {
                 temperature_check_id,
                 account,
                 vote,
+                weight,
+                for_weight,
+                against_weight,
             });
         }
 
-        /// Vote on a proposal
-        /// The account must prove its presence
-        ///
+        /// Cast the delegated power of delegators on a temperature check, following the
+        /// delegatee's own vote. Delegations are resolved as they stood when the temperature check
+        /// was created and must cover temperature checks; excess delegations do not apply since
+        /// temperature checks are uncapped. A delegator's later direct vote replaces the contribution.
+        /// The delegatee must prove its presence and must already have voted.
+        pub fn vote_on_temperature_check_as_delegatee(
+            &mut self,
+            delegatee: Global<Account>,
+            temperature_check_id: u64,
+            delegators: Vec<Global<Account>>,
+        ) {
+            // Verify the delegatee is present in the transaction
+            Runtime::assert_access_rule(delegatee.get_owner_role().rule);
+            self.assert_not_denylisted(delegatee);
+            self.assert_verified(delegatee);
+            ensure(!delegators.is_empty(), GovernanceError::NoDelegators);
+
+            let (members_only, start) = self
+                .temperature_checks
+                .get(&temperature_check_id)
+                .map(|tc| (tc.members_only, tc.start))
+                .unwrap_or_else(|| GovernanceError::TemperatureCheckNotFound.raise());
+            ensure(
+                !members_only || self.was_member_at(delegatee, start),
+                GovernanceError::NotAMember,
+            );
+
+            // Resolve the delegated weights against the creation snapshot
+            let mut delegated_weights: Vec<(Global<Account>, Decimal)> = Vec::new();
+            for delegator in delegators {
+                ensure(
+                    !delegated_weights.iter().any(|(d, _)| *d == delegator),
+                    GovernanceError::DuplicateDelegator,
+                );
+                self.assert_not_denylisted(delegator);
+                ensure(
+                    !members_only || self.was_member_at(delegator, start),
+                    GovernanceError::NotAMember,
+                );
+                let delegation = self
+                    .delegation_at(delegator, delegatee, start)
+                    .filter(|d| {
+                        d.scope.covers_temperature_checks() && d.mode != DelegationMode::ExcessAboveCap
+                    })
+                    .unwrap_or_else(|| GovernanceError::NoDelegationAtSnapshot.raise());
+                let power = self.voting_power_at(delegator, start);
+                let weight = match delegation.mode {
+                    DelegationMode::DefaultProxy => {
+                        ensure(
+                            !self.has_explicit_delegations_at(delegator, start, false),
+                            GovernanceError::DefaultProxyNotApplicable,
+                        );
+                        power
+                    }
+                    _ => delegation.fraction * power,
+                };
+                delegated_weights.push((delegator, weight));
+            }
+
+            let mut tc = self
+                .temperature_checks
+                .get_mut(&temperature_check_id)
+                .unwrap();
+
+            // Check the vote is still open
+            let now = Clock::current_time_rounded_to_seconds();
+            ensure(
+                now.compare(tc.deadline, TimeComparisonOperator::Lt),
+                GovernanceError::VotingEnded,
+            );
+
+            // Delegated power follows the delegatee's own vote
+            let delegatee_vote = tc
+                .votes
+                .get(&delegatee)
+                .map(|v| v.vote.clone())
+                .unwrap_or_else(|| GovernanceError::DelegateeHasNotVoted.raise());
+
+            for (delegator, weight) in delegated_weights.iter() {
+                ensure(
+                    tc.votes.get(delegator).is_none(),
+                    GovernanceError::DelegatorVotedDirectly,
+                );
+
+                let mut cast = tc
+                    .delegated_votes
+                    .get(delegator)
+                    .map(|v| v.clone())
+                    .unwrap_or_default();
+                ensure(
+                    !cast.iter().any(|v| v.delegatee == delegatee),
+                    GovernanceError::DelegatedPowerAlreadyCast,
+                );
+                cast.push(DelegatedVote {
+                    delegatee,
+                    weight: *weight,
+                });
+                tc.delegated_votes.insert(*delegator, cast);
+
+                Self::tally_temperature_check_vote(&mut tc, &delegatee_vote, *weight);
+            }
+            tc.last_vote_at = now;
+            drop(tc);
+
+            self.subsidize_vote_fee(delegatee);
+
+            Runtime::emit_event(TemperatureCheckDelegatedVotesCastEvent {
+                temperature_check_id,
+                delegatee,
+                delegators: delegated_weights,
+            });
+        }
+
+        /// Vote on a proposal
+        /// The account must prove its presence
+        ///
         /// # Arguments
         /// * `account` - The account casting the vote
         /// * `proposal_id` - The ID of the proposal to vote on
@@ -299,103 +1481,4219 @@ mod governance {
             account: Global<Account>,
             proposal_id: u64,
             votes: Vec<ProposalVoteOptionId>,
+        ) {
+            ensure(
+                self.vote_bond_of(proposal_id).is_none(),
+                GovernanceError::VoteBondRequired,
+            );
+
+            self.cast_vote(account, proposal_id, votes, None, None);
+        }
+
+        /// Change the selection of a direct vote, as the proposal's vote change policy allows
+        /// The account's weight, including delegated power cast through it, moves to the new
+        /// selection, unless that power was cast under a delegate notice period. Anonymous and
+        /// points allocation proposals do not support vote changes.
+        /// The account must prove its presence
+        pub fn change_vote(
+            &mut self,
+            account: Global<Account>,
+            proposal_id: u64,
+            votes: Vec<ProposalVoteOptionId>,
         ) {
             // Verify the account is present in the transaction
             Runtime::assert_access_rule(account.get_owner_role().rule);
+            self.assert_not_denylisted(account);
+            self.assert_verified(account);
 
-            // Get the proposal
             let mut proposal = self
                 .proposals
                 .get_mut(&proposal_id)
-                .expect("Proposal not found");
-
-            // Check the vote is still open
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
             let now = Clock::current_time_rounded_to_seconds();
-            assert!(
+            ensure(
                 now.compare(proposal.start, TimeComparisonOperator::Gte),
-                "Voting has not started yet"
+                GovernanceError::VotingNotStarted,
             );
-            assert!(
+            ensure(
                 now.compare(proposal.deadline, TimeComparisonOperator::Lt),
-                "Voting has ended"
+                GovernanceError::VotingEnded,
+            );
+            ensure(
+                proposal.vote_change_policy != VoteChangePolicy::Never
+                    && !proposal.anonymous
+                    && proposal.voting_mode == VotingMode::Selection,
+                GovernanceError::VoteChangeNotAllowed,
+            );
+            // Checked against the live tally, so a change is possible again if it drops below
+            ensure(
+                proposal.vote_change_policy != VoteChangePolicy::UntilQuorum
+                    || Self::quorum_weight(&proposal) < proposal.quorum,
+                GovernanceError::VoteChangeAfterQuorum,
+            );
+            if let Some(error) =
+                Self::selection_error(&proposal.vote_options, proposal.max_selections, &votes)
+            {
+                error.raise();
+            }
+            let previous_votes = proposal
+                .votes
+                .get(&account)
+                .map(|record| record.votes.clone())
+                .unwrap_or_else(|| GovernanceError::VoteNotFound.raise());
+            // Delegated power was cast following the announced intention, so moving it would
+            // bypass the notice period delegators were given to react
+            ensure(
+                proposal.delegate_notice_hours == 0
+                    || proposal
+                        .delegated_vote_breakdown
+                        .get(&account)
+                        .map_or(true, |breakdown| breakdown.is_empty()),
+                GovernanceError::VoteCarriesDelegatedPower,
             );
 
-            // Validate vote count based on max_selections
-            assert!(!votes.is_empty(), "Must select at least one option");
+            // Move the account's whole effective weight from the previous selection to the new one
+            let raw_weight = proposal
+                .effective_weights
+                .get(&account)
+                .map_or(Decimal::ZERO, |w| *w);
+            Self::add_voter_weight(&mut proposal, account, &previous_votes, -raw_weight);
+            Self::add_voter_weight(&mut proposal, account, &votes, raw_weight);
+            for vote in &previous_votes {
+                proposal.option_voter_counts[vote.0 as usize] -= 1;
+            }
+            for vote in &votes {
+                proposal.option_voter_counts[vote.0 as usize] += 1;
+            }
+            proposal.votes.get_mut(&account).unwrap().votes = votes.clone();
+            proposal.last_vote_at = now;
+            drop(proposal);
 
-            match proposal.max_selections {
-                None => {
-                    // Single choice: exactly one vote
-                    assert!(
-                        votes.len() == 1,
-                        "This is a single-choice proposal, select exactly one option"
-                    );
+            Runtime::emit_event(ProposalVoteChangedEvent {
+                proposal_id,
+                account,
+                previous_votes,
+                votes,
+            });
+        }
+
+        /// Vote on a proposal, attaching a rationale stored alongside the vote
+        /// Works like `vote_on_proposal`; the proposal must allow rationales. A file rationale
+        /// must be stored on a trusted storage component.
+        pub fn vote_on_proposal_with_rationale(
+            &mut self,
+            account: Global<Account>,
+            proposal_id: u64,
+            votes: Vec<ProposalVoteOptionId>,
+            rationale: VoteRationale,
+        ) {
+            ensure(
+                self.vote_bond_of(proposal_id).is_none(),
+                GovernanceError::VoteBondRequired,
+            );
+
+            self.cast_vote(account, proposal_id, votes, None, Some(rationale));
+        }
+
+        /// Vote on a multi-question ballot in one transaction
+        /// `answers` pairs question IDs with the selected options: the main question (ID 0) is
+        /// required and works like `vote_on_proposal`, extra questions can be left unanswered.
+        /// Extra questions count the account's own weight, capped like the main tally; delegated
+        /// power only applies to the main question.
+        pub fn vote_on_ballot(
+            &mut self,
+            account: Global<Account>,
+            proposal_id: u64,
+            answers: Vec<(u32, Vec<ProposalVoteOptionId>)>,
+        ) {
+            ensure(
+                self.vote_bond_of(proposal_id).is_none(),
+                GovernanceError::VoteBondRequired,
+            );
+            for (index, (question_id, _)) in answers.iter().enumerate() {
+                ensure(
+                    !answers[..index].iter().any(|(other, _)| other == question_id),
+                    GovernanceError::DuplicateQuestionAnswer,
+                );
+            }
+            let main_votes = answers
+                .iter()
+                .find(|(question_id, _)| *question_id == 0)
+                .map(|(_, votes)| votes.clone())
+                .unwrap_or_else(|| GovernanceError::MainQuestionUnanswered.raise());
+
+            self.cast_vote(account, proposal_id, main_votes, None, None);
+
+            let mut proposal = self.proposals.get_mut(&proposal_id).unwrap();
+            let own_weight = proposal.votes.get(&account).unwrap().weight;
+            let weight = proposal.weight_cap.map_or(own_weight, |cap| own_weight.min(cap));
+
+            let mut recorded: Vec<(u32, Vec<ProposalVoteOptionId>)> = Vec::new();
+            for (question_id, votes) in answers.into_iter().filter(|(question_id, _)| *question_id != 0) {
+                let question = proposal
+                    .questions
+                    .iter_mut()
+                    .find(|q| q.id == question_id)
+                    .unwrap_or_else(|| GovernanceError::QuestionNotFound.raise());
+                if let Some(error) =
+                    Self::selection_error(&question.vote_options, question.max_selections, &votes)
+                {
+                    error.raise();
                 }
-                Some(max) => {
-                    // Multiple choice: up to max votes
-                    assert!(
-                        votes.len() <= max as usize,
-                        "Cannot select more than {} options",
-                        max
-                    );
+
+                Self::add_weight(&mut question.option_weights, &votes, weight);
+                for vote in &votes {
+                    question.option_voter_counts[vote.0 as usize] += 1;
                 }
+                question.total_weight_cast += weight;
+                question.voter_count += 1;
+                recorded.push((question_id, votes));
             }
 
-            // Check for duplicate selections
-            let mut seen = Vec::new();
-            for vote in &votes {
-                assert!(
-                    !seen.contains(vote),
-                    "Duplicate vote option selected"
-                );
-                seen.push(*vote);
+            if !proposal.anonymous && !recorded.is_empty() {
+                proposal.question_votes.insert(account, recorded);
             }
+        }
 
-            // Validate all vote options exist
-            for vote in &votes {
-                assert!(
-                    proposal.vote_options.iter().any(|opt| opt.id == *vote),
-                    "Invalid vote option"
+        /// Vote on a points allocation proposal, distributing `POINTS_PER_VOTER` points across
+        /// its options. Each option counts the voter's weight times its share of the points, and
+        /// delegated power cast through the voter follows the same split.
+        /// The account must prove its presence
+        ///
+        /// # Arguments
+        /// * `points` - Options with the points given to each; options left out get none
+        pub fn vote_on_proposal_with_points(
+            &mut self,
+            account: Global<Account>,
+            proposal_id: u64,
+            points: Vec<(ProposalVoteOptionId, u32)>,
+        ) {
+            ensure(
+                self.vote_bond_of(proposal_id).is_none(),
+                GovernanceError::VoteBondRequired,
+            );
+            let mut proposal = self
+                .proposals
+                .get_mut(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+            ensure(
+                proposal.voting_mode == VotingMode::PointsAllocation
+                    && !matches!(proposal.kind, ProposalKind::Ranking { .. }),
+                GovernanceError::WrongVotingMode,
+            );
+
+            let option_count = proposal.vote_options.len();
+            let mut total: u32 = 0;
+            for (index, (option, option_points)) in points.iter().enumerate() {
+                ensure(
+                    (option.0 as usize) < option_count
+                        && *option_points > 0
+                        && !points[..index].iter().any(|(other, _)| other == option),
+                    GovernanceError::InvalidPointsAllocation,
                 );
+                total = total.saturating_add(*option_points);
             }
+            ensure(total == POINTS_PER_VOTER, GovernanceError::InvalidPointsAllocation);
 
-            // Check the account has not already voted
-            assert!(
-                proposal.votes.get(&account).is_none(),
-                "Account has already voted on this proposal"
+            // Record the split first, so the tally update of `cast_vote` follows it
+            let votes: Vec<ProposalVoteOptionId> = points.iter().map(|(option, _)| *option).collect();
+            proposal.point_allocations.insert(account, points);
+            drop(proposal);
+
+            self.cast_vote(account, proposal_id, votes, None, None);
+        }
+
+        /// Vote on a ranking proposal by ordering all of its temperature checks, most preferred
+        /// first. The `i`-th of `n` temperature checks gets `n - 1 - i` Borda points, and the
+        /// vote is tallied like a points allocation with those points.
+        /// The account must prove its presence
+        ///
+        /// # Arguments
+        /// * `ranking` - IDs of the proposal's temperature checks, most preferred first
+        pub fn vote_on_ranking(
+            &mut self,
+            account: Global<Account>,
+            proposal_id: u64,
+            ranking: Vec<u64>,
+        ) {
+            ensure(
+                self.vote_bond_of(proposal_id).is_none(),
+                GovernanceError::VoteBondRequired,
+            );
+            let mut proposal = self
+                .proposals
+                .get_mut(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+            let candidates = match &proposal.kind {
+                ProposalKind::Ranking { temperature_check_ids } => temperature_check_ids.clone(),
+                _ => GovernanceError::NotARanking.raise(),
+            };
+            ensure(
+                ranking.len() == candidates.len()
+                    && candidates.iter().all(|candidate| ranking.contains(candidate)),
+                GovernanceError::InvalidRanking,
+            );
+
+            // The last ranked temperature check gets no points, so it is left out
+            let points: Vec<(ProposalVoteOptionId, u32)> = ranking
+                .iter()
+                .enumerate()
+                .take(ranking.len() - 1)
+                .map(|(rank, id)| {
+                    let index = candidates.iter().position(|candidate| candidate == id).unwrap();
+                    (ProposalVoteOptionId(index as u32), (ranking.len() - 1 - rank) as u32)
+                })
+                .collect();
+
+            // Record the points first, so the tally update of `cast_vote` follows them
+            let votes: Vec<ProposalVoteOptionId> = points.iter().map(|(option, _)| *option).collect();
+            proposal.point_allocations.insert(account, points);
+            drop(proposal);
+
+            self.cast_vote(account, proposal_id, votes, None, None);
+        }
+
+        /// Vote on a `Groups` proposal as a member of a voting group. Members count one vote
+        /// each in their group's tally, and the group's bloc weight counts for the option most
+        /// of its voting members selected (for none while tied). Only members that joined
+        /// before the proposal was created can vote, under the usual eligibility checks.
+        /// The account must prove its presence
+        pub fn vote_in_group(
+            &mut self,
+            account: Global<Account>,
+            proposal_id: u64,
+            votes: Vec<ProposalVoteOptionId>,
+        ) {
+            // Verify the account is present in the transaction
+            Runtime::assert_access_rule(account.get_owner_role().rule);
+            self.assert_not_denylisted(account);
+            self.assert_verified(account);
+            ensure(
+                self.vote_bond_of(proposal_id).is_none(),
+                GovernanceError::VoteBondRequired,
+            );
+            if let Some(error) = self.membership_error(proposal_id, account) {
+                error.raise();
+            }
+            if let Some(error) = self.min_balance_error(proposal_id, account) {
+                error.raise();
+            }
+            if let Some(error) = self.account_age_error(proposal_id, account) {
+                error.raise();
+            }
+            let membership = self
+                .voting_group_members
+                .get(&account)
+                .map(|membership| membership.clone())
+                .unwrap_or_else(|| GovernanceError::NotInVotingGroup.raise());
+            let group_id = membership.group_id;
+            let bloc_weight = self.voting_groups.get(&group_id).unwrap().bloc_weight;
+
+            let mut proposal = self
+                .proposals
+                .get_mut(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+            ensure(proposal.voting_mode == VotingMode::Groups, GovernanceError::WrongVotingMode);
+            // Members added while the proposal is open cannot swing their group's majority
+            ensure(
+                membership
+                    .added_at
+                    .compare(proposal.delegation_snapshot, TimeComparisonOperator::Lte),
+                GovernanceError::JoinedVotingGroupAfterSnapshot,
+            );
+            let now = Clock::current_time_rounded_to_seconds();
+            ensure(
+                now.compare(proposal.start, TimeComparisonOperator::Gte),
+                GovernanceError::VotingNotStarted,
+            );
+            ensure(
+                now.compare(proposal.deadline, TimeComparisonOperator::Lt),
+                GovernanceError::VotingEnded,
             );
+            if let Some(error) =
+                Self::selection_error(&proposal.vote_options, proposal.max_selections, &votes)
+            {
+                error.raise();
+            }
+            ensure(
+                proposal.group_votes.get(&account).is_none(),
+                GovernanceError::AlreadyVotedOnProposal,
+            );
+
+            let option_count = proposal.vote_options.len();
+            let mut tally = proposal
+                .group_tallies
+                .get(&group_id)
+                .map(|tally| tally.clone())
+                .unwrap_or_else(|| GroupTally {
+                    bloc_weight,
+                    option_votes: vec![0; option_count],
+                    voter_count: 0,
+                    majority: None,
+                });
+            for vote in &votes {
+                tally.option_votes[vote.0 as usize] += 1;
+            }
+            tally.voter_count += 1;
+
+            // Move the bloc weight to the new majority, if it changed
+            let majority = Self::group_majority(&tally.option_votes);
+            if majority != tally.majority {
+                let bloc_weight = tally.bloc_weight;
+                if let Some(previous) = tally.majority {
+                    Self::subtract_weight(&mut proposal.option_weights, &[previous], bloc_weight);
+                    Self::subtract_weight(
+                        &mut proposal.raw_option_weights,
+                        &[previous],
+                        bloc_weight,
+                    );
+                    proposal.total_weight_cast -= bloc_weight;
+                    proposal.raw_total_weight_cast -= bloc_weight;
+                }
+                if let Some(majority) = majority {
+                    Self::add_weight(&mut proposal.option_weights, &[majority], bloc_weight);
+                    Self::add_weight(&mut proposal.raw_option_weights, &[majority], bloc_weight);
+                    proposal.total_weight_cast += bloc_weight;
+                    proposal.raw_total_weight_cast += bloc_weight;
+                }
+                tally.majority = majority;
+            }
 
-            // Record the votes and update last_vote_at
-            proposal.votes.insert(account, votes.clone());
+            proposal.group_votes.insert(account, votes.clone());
+            proposal.group_tallies.insert(group_id, tally);
             proposal.last_vote_at = now;
+            Self::check_quorum_reached(&mut proposal, proposal_id, now);
+            drop(proposal);
 
-            Runtime::emit_event(ProposalVotedEvent {
+            self.record_voter(account);
+            self.subsidize_vote_fee(account);
+
+            Runtime::emit_event(GroupVoteCastEvent {
                 proposal_id,
+                group_id,
                 account,
                 votes,
+                majority,
             });
         }
 
-        /// Returns the current governance parameters
-        pub fn get_governance_parameters(&self) -> GovernanceParameters {
-            self.governance_parameters.clone()
+        /// Respond to a survey proposal with bounded text or a file on trusted storage, once per
+        /// account while the survey is open. Responses are listable after the deadline.
+        /// The account must prove its presence
+        pub fn respond_to_survey(
+            &mut self,
+            account: Global<Account>,
+            proposal_id: u64,
+            response: SurveyResponse,
+        ) {
+            // Verify the account is present in the transaction
+            Runtime::assert_access_rule(account.get_owner_role().rule);
+            self.assert_not_denylisted(account);
+            self.assert_verified(account);
+            if let Some(error) = self.membership_error(proposal_id, account) {
+                error.raise();
+            }
+            if let Some(error) = self.min_balance_error(proposal_id, account) {
+                error.raise();
+            }
+            if let Some(error) = self.account_age_error(proposal_id, account) {
+                error.raise();
+            }
+            let valid = match &response {
+                SurveyResponse::Text(text) => {
+                    !text.trim().is_empty() && text.chars().count() <= MAX_SURVEY_RESPONSE_LENGTH
+                }
+                SurveyResponse::File(file) => self.is_trusted_storage(file.component_address),
+            };
+            ensure(valid, GovernanceError::InvalidSurveyResponse);
+
+            let mut proposal = self.proposals.get_mut(&proposal_id).unwrap();
+            ensure(matches!(proposal.kind, ProposalKind::Survey), GovernanceError::NotASurvey);
+            let now = Clock::current_time_rounded_to_seconds();
+            ensure(
+                now.compare(proposal.start, TimeComparisonOperator::Gte),
+                GovernanceError::VotingNotStarted,
+            );
+            ensure(
+                now.compare(proposal.deadline, TimeComparisonOperator::Lt),
+                GovernanceError::VotingEnded,
+            );
+            ensure(
+                proposal.respondents.get(&account).is_none(),
+                GovernanceError::AlreadyResponded,
+            );
+
+            let response_number = proposal.response_count;
+            proposal.responses.insert(
+                response_number,
+                SurveyResponseRecord {
+                    account,
+                    response,
+                    submitted_at: now,
+                },
+            );
+            proposal.respondents.insert(account, ());
+            proposal.response_count += 1;
+            let response_count = proposal.response_count;
+            drop(proposal);
+
+            Runtime::emit_event(SurveyResponseSubmittedEvent {
+                proposal_id,
+                account,
+                response_count,
+            });
         }
 
-        /// Returns the current temperature check count
-        pub fn get_temperature_check_count(&self) -> u64 {
-            self.temperature_check_count
+        /// React to a proposal with a non-binding signal (0 to `REACTION_KINDS - 1`, mapped to
+        /// emojis by front-ends). Each account has one reaction per proposal; reacting again
+        /// replaces it. Reactions carry no weight and are accepted at any time.
+        /// The account must prove its presence
+        pub fn react(&mut self, account: Global<Account>, proposal_id: u64, reaction: u8) {
+            // Verify the account is present in the transaction
+            Runtime::assert_access_rule(account.get_owner_role().rule);
+            self.assert_not_denylisted(account);
+            ensure(reaction < REACTION_KINDS, GovernanceError::InvalidReaction);
+
+            let mut proposal = self
+                .proposals
+                .get_mut(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+            let previous = proposal.reactions.get(&account).map(|r| *r);
+            ensure(previous != Some(reaction), GovernanceError::AlreadyReacted);
+
+            if let Some(previous) = previous {
+                proposal.reaction_counts[previous as usize] -= 1;
+            }
+            proposal.reaction_counts[reaction as usize] += 1;
+            proposal.reactions.insert(account, reaction);
+            drop(proposal);
+
+            Runtime::emit_event(ProposalReactionEvent {
+                proposal_id,
+                account,
+                reaction,
+                previous,
+            });
         }
 
-        /// Returns the current proposal count
-        pub fn get_proposal_count(&self) -> u64 {
-            self.proposal_count
+        /// Vote on a proposal that takes vote bonds, locking the required XRD until finalization
+        /// Works like `vote_on_proposal`; returns the part of the bond above the required amount
+        pub fn vote_on_proposal_with_bond(
+            &mut self,
+            account: Global<Account>,
+            proposal_id: u64,
+            votes: Vec<ProposalVoteOptionId>,
+            mut bond: Bucket,
+        ) -> Bucket {
+            let required = self
+                .vote_bond_of(proposal_id)
+                .unwrap_or_else(|| GovernanceError::NoVoteBondRequired.raise());
+            ensure(
+                bond.resource_address() == XRD && bond.amount() >= required,
+                GovernanceError::InsufficientVoteBond,
+            );
+
+            self.cast_vote(account, proposal_id, votes, None, None);
+
+            self.vote_bonds.put(bond.take(required));
+            let mut proposal = self.proposals.get_mut(&proposal_id).unwrap();
+            proposal.bonds.insert(account, required);
+            proposal.outstanding_bonds += required;
+            drop(proposal);
+
+            bond
         }
 
-        /// Updates the governance parameters (owner only)
-        pub fn update_governance_parameters(&mut self, new_params: GovernanceParameters) {
-            self.governance_parameters = new_params.clone();
+        /// Vote on a proposal with an eligibility allowlist, proving with a Merkle proof that
+        /// `(account, weight)` is a leaf of the allowlist. The vote counts at the listed weight.
+        /// The account must prove its presence.
+        ///
+        /// # Arguments
+        /// * `weight` - The weight listed for the account in the allowlist
+        /// * `proof` - The sibling nodes from the account's leaf up to the root
+        pub fn vote_on_proposal_with_proof(
+            &mut self,
+            account: Global<Account>,
+            proposal_id: u64,
+            votes: Vec<ProposalVoteOptionId>,
+            weight: Decimal,
+            proof: Vec<Hash>,
+        ) {
+            let (eligibility_root, vote_bond) = self
+                .proposals
+                .get(&proposal_id)
+                .map(|p| (p.eligibility_root, p.vote_bond))
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+            ensure(vote_bond.is_none(), GovernanceError::VoteBondRequired);
+            let root = eligibility_root.unwrap_or_else(|| GovernanceError::NoEligibilityAllowlist.raise());
+            ensure(
+                verify_merkle_proof(root, eligibility_leaf(account.address(), weight), &proof),
+                GovernanceError::InvalidEligibilityProof,
+            );
+
+            self.cast_vote(account, proposal_id, votes, Some(weight), None);
+        }
+
+        /// Returns the vote bond an account locked on a finalized proposal
+        /// The account must prove its presence
+        pub fn reclaim_vote_bond(&mut self, account: Global<Account>, proposal_id: u64) -> Bucket {
+            Runtime::assert_access_rule(account.get_owner_role().rule);
+
+            let mut proposal = self
+                .proposals
+                .get_mut(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+
+            ensure(proposal.status != ProposalStatus::Active, GovernanceError::VoteBondLocked);
+            let amount = proposal
+                .bonds
+                .remove(&account)
+                .unwrap_or_else(|| GovernanceError::NoVoteBond.raise());
+            proposal.outstanding_bonds -= amount;
+            drop(proposal);
+
+            Runtime::emit_event(VoteBondReclaimedEvent {
+                proposal_id,
+                account,
+                amount,
+            });
 
-            Runtime::emit_event(GovernanceParametersUpdatedEvent { new_params });
+            self.vote_bonds.take(amount)
+        }
+
+        /// Verifies the account is present and records its direct vote (see `record_vote`)
+        fn cast_vote(
+            &mut self,
+            account: Global<Account>,
+            proposal_id: u64,
+            votes: Vec<ProposalVoteOptionId>,
+            listed_weight: Option<Decimal>,
+            rationale: Option<VoteRationale>,
+        ) {
+            // Verify the account is present in the transaction
+            Runtime::assert_access_rule(account.get_owner_role().rule);
+
+            self.record_vote(account, proposal_id, votes, listed_weight, rationale);
+        }
+
+        /// Records a direct vote on a proposal of an account whose presence or signer was verified,
+        /// at `listed_weight` if it was proven against the proposal's eligibility allowlist, else
+        /// at the account's voting power
+        fn record_vote(
+            &mut self,
+            account: Global<Account>,
+            proposal_id: u64,
+            votes: Vec<ProposalVoteOptionId>,
+            listed_weight: Option<Decimal>,
+            rationale: Option<VoteRationale>,
+        ) {
+            self.assert_not_denylisted(account);
+            self.assert_verified(account);
+            if let Some(rationale) = &rationale {
+                self.validate_rationale(rationale);
+            }
+
+            if let Some(error) = self.membership_error(proposal_id, account) {
+                error.raise();
+            }
+            if let Some(error) = self.min_balance_error(proposal_id, account) {
+                error.raise();
+            }
+            if let Some(error) = self.account_age_error(proposal_id, account) {
+                error.raise();
+            }
+            let (weight, delegates_excess) = match listed_weight {
+                Some(weight) => (weight, false),
+                None => {
+                    let has_allowlist = self
+                        .proposals
+                        .get(&proposal_id)
+                        .map(|p| p.eligibility_root.is_some())
+                        .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+                    ensure(!has_allowlist, GovernanceError::EligibilityProofRequired);
+                    self.direct_vote_weight(account, proposal_id)
+                }
+            };
+            let carried_over = self.carried_over_to_replace(proposal_id, account);
+
+            // Get the proposal
+            let mut proposal = self
+                .proposals
+                .get_mut(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+
+            let now = Clock::current_time_rounded_to_seconds();
+            if let Some(error) = Self::proposal_vote_error(&proposal, account, &votes, now) {
+                error.raise();
+            }
+            ensure(
+                rationale.is_none() || proposal.allow_rationales,
+                GovernanceError::RationalesDisabled,
+            );
+
+            // Votes within the early vote window count the account's own weight with the bonus
+            let base_weight = weight;
+            let early_vote_multiplier = Self::early_vote_multiplier(&proposal, now);
+            let weight = base_weight * early_vote_multiplier;
+            if early_vote_multiplier > Decimal::ONE {
+                proposal.early_vote_multipliers.insert(account, early_vote_multiplier);
+            }
+
+            // A direct vote takes precedence over power already cast by delegatees,
+            // except for power above the weight cap, which the account cannot use itself
+            let overridden = if delegates_excess {
+                Vec::new()
+            } else {
+                proposal.delegated_votes.remove(&account).unwrap_or_default()
+            };
+            for delegated_vote in overridden.iter() {
+                let delegatee_votes = proposal
+                    .votes
+                    .get(&delegated_vote.delegatee)
+                    .map(|v| v.votes.clone())
+                    .unwrap_or_default();
+                Self::add_voter_weight(
+                    &mut proposal,
+                    delegated_vote.delegatee,
+                    &delegatee_votes,
+                    -delegated_vote.weight,
+                );
+                if let Some(mut breakdown) =
+                    proposal.delegated_vote_breakdown.get_mut(&delegated_vote.delegatee)
+                {
+                    breakdown.retain(|(delegator, _)| *delegator != account);
+                }
+            }
+
+            // On a reopened proposal, the fresh vote replaces the carried-over one
+            for contribution in carried_over.iter() {
+                Self::remove_carried_over(&mut proposal, contribution);
+            }
+            if proposal.reopened_from.is_some() {
+                proposal.carried_over_replaced.insert(account, ());
+            }
+
+            // Record the votes (only the marker for anonymous proposals) and update last_vote_at
+            let recorded_votes = if proposal.anonymous { Vec::new() } else { votes.clone() };
+            proposal.votes.insert(
+                account,
+                ProposalVoteRecord {
+                    votes: recorded_votes.clone(),
+                    weight,
+                    cast_at: now,
+                },
+            );
+            if let Some(rationale) = &rationale {
+                proposal.rationales.insert(account, rationale.clone());
+            }
+            proposal.last_vote_at = now;
+
+            // Update tally and turnout
+            Self::add_voter_weight(&mut proposal, account, &votes, weight);
+            let points = if proposal.anonymous {
+                // No delegated power follows an anonymous vote, so the split is not needed anymore
+                proposal.point_allocations.remove(&account);
+                Vec::new()
+            } else {
+                proposal
+                    .point_allocations
+                    .get(&account)
+                    .map(|points| points.clone())
+                    .unwrap_or_default()
+            };
+            for vote in &votes {
+                proposal.option_voter_counts[vote.0 as usize] += 1;
+            }
+            let voter_number = proposal.voter_count;
+            proposal.voter_index.insert(voter_number, account);
+            proposal.voter_count += 1;
+            Self::check_quorum_reached(&mut proposal, proposal_id, now);
+            let delegated_weight = proposal
+                .effective_weights
+                .get(&account)
+                .map_or(Decimal::ZERO, |w| *w - weight);
+            let option_weights = if proposal.anonymous || proposal.members_only {
+                Vec::new()
+            } else {
+                proposal.option_weights.clone()
+            };
+            let large_vote_threshold = self.large_vote_threshold(proposal.quorum);
+            drop(proposal);
+
+            let overridden_weight = overridden
+                .iter()
+                .fold(Decimal::ZERO, |total, delegated_vote| total + delegated_vote.weight);
+            self.activity.total_weight_cast += weight - overridden_weight;
+            self.record_voter(account);
+            self.subsidize_vote_fee(account);
+
+            for delegated_vote in overridden {
+                Runtime::emit_event(DelegatedVoteOverriddenEvent {
+                    proposal_id,
+                    delegator: account,
+                    delegatee: delegated_vote.delegatee,
+                    weight: delegated_vote.weight,
+                });
+            }
+
+            Runtime::emit_event(ProposalVotedEvent {
+                proposal_id,
+                account,
+                votes: recorded_votes.clone(),
+                points,
+                weight,
+                base_weight,
+                early_vote_multiplier,
+                delegated_weight,
+                option_weights,
+                rationale,
+            });
+            if large_vote_threshold.is_some_and(|threshold| weight > threshold) {
+                Runtime::emit_event(LargeVoteEvent {
+                    proposal_id,
+                    account,
+                    delegatee: None,
+                    weight,
+                    votes: recorded_votes,
+                });
+            }
+        }
+
+        /// Weight above which a single vote on a proposal with the given quorum is reported with
+        /// `LargeVoteEvent`, None if disabled
+        fn large_vote_threshold(&self, quorum: Decimal) -> Option<Decimal> {
+            self.governance_parameters
+                .large_vote_fraction
+                .map(|fraction| quorum * fraction)
+        }
+
+        /// Checks that a vote rationale is a non-empty bounded text or a file on trusted storage
+        fn validate_rationale(&self, rationale: &VoteRationale) {
+            match rationale {
+                VoteRationale::Text(text) => {
+                    ensure(!text.trim().is_empty(), GovernanceError::EmptyRationale);
+                    ensure(
+                        text.chars().count() <= MAX_RATIONALE_LENGTH,
+                        GovernanceError::RationaleTooLong,
+                    );
+                }
+                VoteRationale::File(file) => ensure(
+                    self.is_trusted_storage(file.component_address),
+                    GovernanceError::UntrustedStorage,
+                ),
+            }
+        }
+
+        /// Vote in the signal window of a two-phase proposal
+        /// The account must prove its presence. Signal votes are non-binding and separate from
+        /// the binding vote, so the account can vote again once the binding window opens.
+        pub fn vote_on_signal(
+            &mut self,
+            account: Global<Account>,
+            proposal_id: u64,
+            votes: Vec<ProposalVoteOptionId>,
+        ) {
+            // Verify the account is present in the transaction
+            Runtime::assert_access_rule(account.get_owner_role().rule);
+            self.assert_not_denylisted(account);
+            self.assert_verified(account);
+            if let Some(error) = self.membership_error(proposal_id, account) {
+                error.raise();
+            }
+            if let Some(error) = self.min_balance_error(proposal_id, account) {
+                error.raise();
+            }
+            if let Some(error) = self.account_age_error(proposal_id, account) {
+                error.raise();
+            }
+
+            let weight = self.proposal_voting_power(account, proposal_id);
+
+            let mut proposal = self
+                .proposals
+                .get_mut(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+            ensure(
+                proposal.voting_mode == VotingMode::Selection,
+                GovernanceError::WrongVotingMode,
+            );
+            if let Some(error) =
+                Self::selection_error(&proposal.vote_options, proposal.max_selections, &votes)
+            {
+                error.raise();
+            }
+            ensure(
+                proposal.eligibility_root.is_none(),
+                GovernanceError::EligibilityProofRequired,
+            );
+
+            let now = Clock::current_time_rounded_to_seconds();
+            let anonymous = proposal.anonymous;
+            let signal = proposal
+                .signal
+                .as_mut()
+                .unwrap_or_else(|| GovernanceError::NotTwoPhase.raise());
+            ensure(
+                now.compare(signal.deadline, TimeComparisonOperator::Lt),
+                GovernanceError::VotingEnded,
+            );
+            ensure(
+                signal.votes.get(&account).is_none(),
+                GovernanceError::AlreadyVotedOnSignal,
+            );
+
+            let recorded_votes = if anonymous { Vec::new() } else { votes.clone() };
+            signal.votes.insert(account, recorded_votes.clone());
+            Self::add_weight(&mut signal.option_weights, &votes, weight);
+            for vote in &votes {
+                signal.option_voter_counts[vote.0 as usize] += 1;
+            }
+            signal.voter_count += 1;
+            signal.total_weight_cast += weight;
+            proposal.last_vote_at = now;
+            drop(proposal);
+
+            self.record_voter(account);
+            self.subsidize_vote_fee(account);
+
+            Runtime::emit_event(ProposalSignalVotedEvent {
+                proposal_id,
+                account,
+                votes: recorded_votes,
+            });
+        }
+
+        /// Announce how a delegatee intends to vote on a proposal, before casting delegated power
+        /// When the proposal has a delegate notice period, delegated power can only be cast once
+        /// it has elapsed since the last announcement, and only following the announced options.
+        /// The delegatee must prove its presence.
+        ///
+        /// # Arguments
+        /// * `statement_file` - Optional statement explaining the intention, stored on a trusted
+        ///   storage component
+        pub fn announce_intended_vote(
+            &mut self,
+            delegatee: Global<Account>,
+            proposal_id: u64,
+            votes: Vec<ProposalVoteOptionId>,
+            statement_file: Option<File>,
+        ) {
+            // Verify the delegatee is present in the transaction
+            Runtime::assert_access_rule(delegatee.get_owner_role().rule);
+            self.assert_not_denylisted(delegatee);
+            if let Some(file) = &statement_file {
+                ensure(
+                    self.is_trusted_storage(file.component_address),
+                    GovernanceError::UntrustedStorage,
+                );
+            }
+
+            let mut proposal = self
+                .proposals
+                .get_mut(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+            let now = Clock::current_time_rounded_to_seconds();
+            ensure(
+                now.compare(proposal.deadline, TimeComparisonOperator::Lt),
+                GovernanceError::VotingEnded,
+            );
+            ensure(!proposal.anonymous, GovernanceError::AnonymousDelegatedVote);
+            if proposal.voting_mode == VotingMode::Selection {
+                if let Some(error) =
+                    Self::selection_error(&proposal.vote_options, proposal.max_selections, &votes)
+                {
+                    error.raise();
+                }
+            }
+
+            // Announcing again replaces the intention and restarts the notice period
+            proposal.intended_votes.insert(
+                delegatee,
+                IntendedVote {
+                    votes: votes.clone(),
+                    statement_file: statement_file.clone(),
+                    announced_at: now,
+                },
+            );
+            let castable_from = now.add_hours(proposal.delegate_notice_hours as i64).unwrap();
+            drop(proposal);
+
+            Runtime::emit_event(IntendedVoteAnnouncedEvent {
+                proposal_id,
+                delegatee,
+                votes,
+                statement_file,
+                castable_from,
+            });
+        }
+
+        /// Cast the delegated power of delegators on a proposal, following the delegatee's own vote
+        /// The delegatee must prove its presence and must already have voted on the proposal
+        ///
+        /// # Arguments
+        /// * `delegatee` - The account the power was delegated to
+        /// * `proposal_id` - The ID of the proposal to vote on
+        /// * `delegators` - The delegators whose power to cast. Delegators that voted directly
+        ///   cannot be included; if they vote directly afterwards, their direct vote replaces
+        ///   the delegated contribution.
+        pub fn vote_on_proposal_as_delegatee(
+            &mut self,
+            delegatee: Global<Account>,
+            proposal_id: u64,
+            delegators: Vec<Global<Account>>,
+        ) {
+            // Verify the delegatee is present in the transaction
+            Runtime::assert_access_rule(delegatee.get_owner_role().rule);
+            self.assert_not_denylisted(delegatee);
+            self.assert_verified(delegatee);
+
+            ensure(!delegators.is_empty(), GovernanceError::NoDelegators);
+            if let Some(error) = self.membership_error(proposal_id, delegatee) {
+                error.raise();
+            }
+
+            // Resolve the delegated weights against the proposal's snapshot before touching the proposal
+            let (delegation_snapshot, weight_cap, anonymous, has_allowlist) = self
+                .proposals
+                .get(&proposal_id)
+                .map(|p| (p.delegation_snapshot, p.weight_cap, p.anonymous, p.eligibility_root.is_some()))
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+            // Delegated power follows the delegatee's selection, which anonymous proposals do not keep
+            ensure(!anonymous, GovernanceError::AnonymousDelegatedVote);
+            // On allowlist proposals only the listed weights count, which cannot be delegated
+            ensure(!has_allowlist, GovernanceError::EligibilityProofRequired);
+            let mut delegated_weights: Vec<(Global<Account>, Decimal)> = Vec::new();
+            // Delegators whose delegation only covers their power above the weight cap
+            let mut excess_delegators: Vec<Global<Account>> = Vec::new();
+            for delegator in delegators {
+                ensure(
+                    !delegated_weights.iter().any(|(d, _)| *d == delegator),
+                    GovernanceError::DuplicateDelegator,
+                );
+                self.assert_not_denylisted(delegator);
+                if let Some(error) = self.membership_error(proposal_id, delegator) {
+                    error.raise();
+                }
+                if let Some(error) = self.min_balance_error(proposal_id, delegator) {
+                    error.raise();
+                }
+                if let Some(error) = self.account_age_error(proposal_id, delegator) {
+                    error.raise();
+                }
+                let delegation = self
+                    .delegation_at(delegator, delegatee, delegation_snapshot)
+                    .filter(|d| d.scope.covers_proposals())
+                    .unwrap_or_else(|| GovernanceError::NoDelegationAtSnapshot.raise());
+                let power = self.voting_power_at(delegator, delegation_snapshot);
+                let weight = match delegation.mode {
+                    DelegationMode::Full => delegation.fraction * power,
+                    DelegationMode::ExcessAboveCap => {
+                        excess_delegators.push(delegator);
+                        delegation.fraction * Self::excess_above(power, weight_cap)
+                    }
+                    DelegationMode::DefaultProxy => {
+                        ensure(
+                            !self.has_explicit_delegations_at(delegator, delegation_snapshot, true),
+                            GovernanceError::DefaultProxyNotApplicable,
+                        );
+                        power
+                    }
+                };
+                delegated_weights.push((delegator, weight));
+            }
+            let carried_over: Vec<Vec<CarriedOverVote>> = delegated_weights
+                .iter()
+                .map(|(delegator, _)| self.carried_over_to_replace(proposal_id, *delegator))
+                .collect();
+
+            // Get the proposal
+            let mut proposal = self
+                .proposals
+                .get_mut(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+
+            // Check the vote is still open
+            let now = Clock::current_time_rounded_to_seconds();
+            ensure(
+                now.compare(proposal.start, TimeComparisonOperator::Gte),
+                GovernanceError::VotingNotStarted,
+            );
+            ensure(
+                now.compare(proposal.deadline, TimeComparisonOperator::Lt),
+                GovernanceError::VotingEnded,
+            );
+
+            // Delegated power follows the delegatee's own vote
+            let delegatee_votes = proposal
+                .votes
+                .get(&delegatee)
+                .map(|v| v.votes.clone())
+                .unwrap_or_else(|| GovernanceError::DelegateeHasNotVoted.raise());
+
+            // With a notice period, the vote must have been announced early enough to let
+            // delegators vote directly or revoke
+            if proposal.delegate_notice_hours > 0 {
+                let intended_vote = proposal
+                    .intended_votes
+                    .get(&delegatee)
+                    .map(|v| v.clone())
+                    .unwrap_or_else(|| GovernanceError::IntendedVoteNotAnnounced.raise());
+                let castable_from = intended_vote
+                    .announced_at
+                    .add_hours(proposal.delegate_notice_hours as i64)
+                    .unwrap();
+                ensure(
+                    now.compare(castable_from, TimeComparisonOperator::Gte),
+                    GovernanceError::DelegateNoticePeriodNotElapsed,
+                );
+                ensure(
+                    intended_vote.votes == delegatee_votes,
+                    GovernanceError::VoteDiffersFromIntendedVote,
+                );
+            }
+
+            for (delegator, weight) in delegated_weights.iter() {
+                ensure(
+                    excess_delegators.contains(delegator) || proposal.votes.get(delegator).is_none(),
+                    GovernanceError::DelegatorVotedDirectly,
+                );
+
+                let mut cast = proposal
+                    .delegated_votes
+                    .get(delegator)
+                    .map(|v| v.clone())
+                    .unwrap_or_default();
+                ensure(
+                    !cast.iter().any(|v| v.delegatee == delegatee),
+                    GovernanceError::DelegatedPowerAlreadyCast,
+                );
+                cast.push(DelegatedVote {
+                    delegatee,
+                    weight: *weight,
+                });
+                proposal.delegated_votes.insert(*delegator, cast);
+
+                Self::add_voter_weight(&mut proposal, delegatee, &delegatee_votes, *weight);
+            }
+
+            // On a reopened proposal, the freshly cast power replaces the carried-over one
+            for (index, (delegator, _)) in delegated_weights.iter().enumerate() {
+                for contribution in carried_over[index].iter() {
+                    Self::remove_carried_over(&mut proposal, contribution);
+                }
+                if proposal.reopened_from.is_some() {
+                    proposal.carried_over_replaced.insert(*delegator, ());
+                }
+            }
+            let mut breakdown = proposal
+                .delegated_vote_breakdown
+                .get(&delegatee)
+                .map(|b| b.clone())
+                .unwrap_or_default();
+            breakdown.extend(delegated_weights.iter().cloned());
+            proposal.delegated_vote_breakdown.insert(delegatee, breakdown);
+            proposal.last_vote_at = now;
+            Self::check_quorum_reached(&mut proposal, proposal_id, now);
+            let large_vote_threshold = self.large_vote_threshold(proposal.quorum);
+            drop(proposal);
+
+            for (_, weight) in delegated_weights.iter() {
+                self.activity.total_weight_cast += *weight;
+            }
+            self.subsidize_vote_fee(delegatee);
+
+            let large_votes: Vec<(Global<Account>, Decimal)> = delegated_weights
+                .iter()
+                .filter(|(_, weight)| {
+                    large_vote_threshold.is_some_and(|threshold| *weight > threshold)
+                })
+                .cloned()
+                .collect();
+            Runtime::emit_event(DelegatedVotesCastEvent {
+                proposal_id,
+                delegatee,
+                delegators: delegated_weights,
+            });
+            for (delegator, weight) in large_votes {
+                Runtime::emit_event(LargeVoteEvent {
+                    proposal_id,
+                    account: delegator,
+                    delegatee: Some(delegatee),
+                    weight,
+                    votes: delegatee_votes.clone(),
+                });
+            }
+        }
+
+        /// Returns the current governance parameters
+        pub fn get_governance_parameters(&self) -> GovernanceParameters {
+            self.governance_parameters.clone()
+        }
+
+        /// Returns the current temperature check count
+        pub fn get_temperature_check_count(&self) -> u64 {
+            self.temperature_check_count
+        }
+
+        /// Returns the current proposal count
+        pub fn get_proposal_count(&self) -> u64 {
+            self.proposal_count
+        }
+
+        /// Returns a temperature check without its vote store
+        /// The tally of a members-only temperature check is hidden until it is finalized
+        pub fn get_temperature_check(&self, temperature_check_id: u64) -> TemperatureCheckView {
+            let mut view = self
+                .temperature_checks
+                .get(&temperature_check_id)
+                .map(|tc| TemperatureCheckView::from(&*tc))
+                .unwrap_or_else(|| GovernanceError::TemperatureCheckNotFound.raise());
+            if view.members_only && view.status == TemperatureCheckStatus::Active {
+                view.hide_tally();
+            }
+
+            view
+        }
+
+        /// Returns the result of a temperature check and the proposal it was elevated to
+        /// The weights of a members-only temperature check are hidden until it is finalized
+        pub fn get_temperature_check_result(&self, temperature_check_id: u64) -> TemperatureCheckResult {
+            let tc = self
+                .temperature_checks
+                .get(&temperature_check_id)
+                .unwrap_or_else(|| GovernanceError::TemperatureCheckNotFound.raise());
+            let hidden = tc.members_only && tc.status == TemperatureCheckStatus::Active;
+
+            TemperatureCheckResult {
+                status: tc.status,
+                passed: tc.status == TemperatureCheckStatus::Passed,
+                for_weight: if hidden { Decimal::ZERO } else { tc.for_weight },
+                against_weight: if hidden { Decimal::ZERO } else { tc.against_weight },
+                option_weights: if hidden {
+                    vec![Decimal::ZERO; tc.option_weights.len()]
+                } else {
+                    tc.option_weights.clone()
+                },
+                elevated_proposal_id: tc.elevated_proposal_id,
+            }
+        }
+
+        /// Returns the live tally of a temperature check, with when it last changed and whether
+        /// it can still change
+        /// The tally of a members-only temperature check is hidden until it is finalized
+        pub fn get_temperature_check_tally(&self, temperature_check_id: u64) -> TemperatureCheckTally {
+            let tc = self
+                .temperature_checks
+                .get(&temperature_check_id)
+                .unwrap_or_else(|| GovernanceError::TemperatureCheckNotFound.raise());
+            let finalized = tc.status != TemperatureCheckStatus::Active;
+            ensure(
+                !tc.members_only || finalized,
+                GovernanceError::TallyHiddenUntilFinalized,
+            );
+
+            let now = Clock::current_time_rounded_to_seconds();
+            let total = tc.for_weight + tc.against_weight;
+            TemperatureCheckTally {
+                for_weight: tc.for_weight,
+                against_weight: tc.against_weight,
+                option_weights: tc.option_weights.clone(),
+                quorum_met: total.is_positive() && total >= tc.quorum,
+                threshold_met: total.is_positive() && tc.for_weight / total >= tc.approval_threshold,
+                last_vote_at: tc.last_vote_at,
+                as_of: now,
+                voting_ended: now.compare(tc.deadline, TimeComparisonOperator::Gte),
+                finalized,
+            }
+        }
+
+        /// Returns the co-sponsors of a temperature check, in the order they sponsored it
+        pub fn get_sponsors(&self, temperature_check_id: u64) -> Vec<Global<Account>> {
+            self.temperature_checks
+                .get(&temperature_check_id)
+                .map(|tc| tc.sponsors.clone())
+                .unwrap_or_else(|| GovernanceError::TemperatureCheckNotFound.raise())
+        }
+
+        /// Returns the ID of the proposal a temperature check was elevated to, None if not elevated
+        pub fn get_proposal_for_temperature_check(&self, temperature_check_id: u64) -> Option<u64> {
+            self.temperature_checks
+                .get(&temperature_check_id)
+                .map(|tc| tc.elevated_proposal_id)
+                .unwrap_or_else(|| GovernanceError::TemperatureCheckNotFound.raise())
+        }
+
+        /// Returns the ID of the temperature check created with an external reference, None if unknown
+        pub fn get_temperature_check_by_external_ref(&self, external_ref: String) -> Option<u64> {
+            self.external_refs.get(&external_ref).map(|id| *id)
+        }
+
+        /// Returns the ID of the proposal built from the temperature check with an external
+        /// reference, None if unknown or not elevated
+        pub fn get_proposal_by_external_ref(&self, external_ref: String) -> Option<u64> {
+            let temperature_check_id = self.get_temperature_check_by_external_ref(external_ref)?;
+            self.get_proposal_for_temperature_check(temperature_check_id)
+        }
+
+        /// Returns the ID of the temperature check a proposal (active or archived) was built from
+        pub fn get_temperature_check_for_proposal(&self, proposal_id: u64) -> u64 {
+            if let Some(proposal) = self.proposals.get(&proposal_id) {
+                return proposal.temperature_check_id;
+            }
+
+            self.archived
+                .get(&proposal_id)
+                .map(|archived| archived.temperature_check_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise())
+        }
+
+        /// Returns a proposal without its vote and delegation stores
+        /// The tally of a members-only proposal is hidden until it is finalized
+        pub fn get_proposal(&self, proposal_id: u64) -> ProposalView {
+            let mut view = self
+                .proposals
+                .get(&proposal_id)
+                .map(|proposal| ProposalView::from(&*proposal))
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+            if view.members_only && view.status == ProposalStatus::Active {
+                view.hide_tally();
+            }
+
+            view
+        }
+
+        /// Returns the file holding a temperature check's description, None if stored inline
+        pub fn get_description_file(&self, temperature_check_id: u64) -> Option<DescriptionFile> {
+            self.temperature_checks
+                .get(&temperature_check_id)
+                .map(|tc| tc.description_file.clone())
+                .unwrap_or_else(|| GovernanceError::TemperatureCheckNotFound.raise())
+        }
+
+        /// Checks fetched description content against the on-ledger commitment: the file's
+        /// content hash, or the hash of the inline description
+        pub fn verify_description_hash(&self, temperature_check_id: u64, content_hash: Hash) -> bool {
+            let tc = self
+                .temperature_checks
+                .get(&temperature_check_id)
+                .unwrap_or_else(|| GovernanceError::TemperatureCheckNotFound.raise());
+
+            match &tc.description_file {
+                Some(description_file) => description_file.content_hash == content_hash,
+                None => hash(tc.description.as_bytes()) == content_hash,
+            }
+        }
+
+        /// Checks fetched discussion post content against the hash committed at creation,
+        /// false if the temperature check committed to none
+        pub fn verify_discussion_hash(&self, temperature_check_id: u64, content_hash: Hash) -> bool {
+            self.temperature_checks
+                .get(&temperature_check_id)
+                .map(|tc| tc.discussion_hash == Some(content_hash))
+                .unwrap_or_else(|| GovernanceError::TemperatureCheckNotFound.raise())
+        }
+
+        /// Returns the seconds left until a temperature check's deadline (0 once it has passed)
+        pub fn get_temperature_check_time_remaining(&self, temperature_check_id: u64) -> i64 {
+            let deadline = self
+                .temperature_checks
+                .get(&temperature_check_id)
+                .map(|tc| tc.deadline)
+                .unwrap_or_else(|| GovernanceError::TemperatureCheckNotFound.raise());
+            Self::seconds_until(deadline)
+        }
+
+        /// Returns the seconds left until a proposal's deadline (0 once it has passed)
+        /// For two-phase proposals this is the deadline of the binding window
+        pub fn get_proposal_time_remaining(&self, proposal_id: u64) -> i64 {
+            let deadline = self
+                .proposals
+                .get(&proposal_id)
+                .map(|p| p.deadline)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+            Self::seconds_until(deadline)
+        }
+
+        /// Returns the participation figures of a proposal
+        pub fn get_turnout(&self, proposal_id: u64) -> ProposalTurnout {
+            let proposal = self
+                .proposals
+                .get(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+            Self::assert_tally_visible(&proposal);
+
+            Self::turnout_of(&proposal)
+        }
+
+        /// Returns the current tally of a proposal (final once the proposal is finalized)
+        pub fn get_proposal_tally(&self, proposal_id: u64) -> ProposalTally {
+            let proposal = self
+                .proposals
+                .get(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+            Self::assert_tally_visible(&proposal);
+
+            Self::tally_of(&proposal)
+        }
+
+        /// Returns the SBOR-encoded `ResultAttestation` of a finalized proposal (active or
+        /// archived), a canonical payload for off-chain oracles to sign
+        pub fn get_result_attestation(&self, proposal_id: u64) -> Vec<u8> {
+            let (status, tally, finalized_at) = match self.proposals.get(&proposal_id) {
+                Some(proposal) => (
+                    proposal.status,
+                    Self::tally_of(&proposal),
+                    proposal
+                        .finalized_at
+                        .unwrap_or_else(|| GovernanceError::ProposalNotFinalized.raise()),
+                ),
+                None => self
+                    .archived
+                    .get(&proposal_id)
+                    .map(|archived| {
+                        (archived.status, archived.tally.clone(), archived.finalized_at)
+                    })
+                    .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise()),
+            };
+
+            let attestation = ResultAttestation {
+                version: RESULT_ATTESTATION_VERSION,
+                governance: Runtime::global_address(),
+                proposal_id,
+                status,
+                option_weights: tally.options.iter().map(|option| option.weight).collect(),
+                total_weight_cast: tally.turnout.total_weight_cast,
+                winning_options: tally.winning_options,
+                finalized_at,
+            };
+            scrypto_encode(&attestation).unwrap()
+        }
+
+        /// Returns the tally of a two-phase proposal's signal window
+        pub fn get_signal_tally(&self, proposal_id: u64) -> ProposalTally {
+            let proposal = self
+                .proposals
+                .get(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+            Self::assert_tally_visible(&proposal);
+
+            let signal = proposal
+                .signal
+                .as_ref()
+                .unwrap_or_else(|| GovernanceError::NotTwoPhase.raise());
+
+            Self::signal_tally_of(&proposal, signal)
+        }
+
+        /// Returns the status of a proposal
+        pub fn get_proposal_status(&self, proposal_id: u64) -> ProposalStatus {
+            self.proposals
+                .get(&proposal_id)
+                .map(|p| p.status)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise())
+        }
+
+        /// Finalizes a proposal after its deadline, fixing its status to Passed or Rejected
+        /// Callable by anyone
+        pub fn finalize_proposal(&mut self, proposal_id: u64) -> ProposalStatus {
+            let mut proposal = self
+                .proposals
+                .get_mut(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+
+            let now = Clock::current_time_rounded_to_seconds();
+            ensure(
+                now.compare(proposal.deadline, TimeComparisonOperator::Gte),
+                GovernanceError::VotingNotEnded,
+            );
+            ensure(
+                proposal.status == ProposalStatus::Active,
+                GovernanceError::ProposalAlreadyFinalized,
+            );
+
+            let tally = Self::tally_of(&proposal);
+            let status = if tally.quorum_met && tally.threshold_met {
+                ProposalStatus::Passed
+            } else {
+                ProposalStatus::Rejected
+            };
+            proposal.status = status;
+            let quorum = proposal.quorum;
+            for question in proposal.questions.iter_mut() {
+                Self::finalize_question(question, quorum);
+            }
+            let questions = proposal.questions.clone();
+            if proposal.voting_mode == VotingMode::PointsAllocation {
+                let total = proposal
+                    .option_weights
+                    .iter()
+                    .fold(Decimal::ZERO, |total, weight| total + *weight);
+                proposal.allocation = proposal
+                    .option_weights
+                    .iter()
+                    .map(|weight| if total.is_positive() { *weight / total } else { Decimal::ZERO })
+                    .collect();
+            }
+            let allocation = proposal.allocation.clone();
+            proposal.finalized_at = Some(now);
+            let challenge_period_days = self.governance_parameters.challenge_period_days;
+            if status == ProposalStatus::Passed && challenge_period_days > 0 {
+                // Only direct voters can claim dispute rewards: carried-over and bloc weight
+                // would leave their share stuck in the rewards vault
+                let winning_weight = if proposal.voting_mode == VotingMode::Groups {
+                    Decimal::ZERO
+                } else {
+                    tally.winning_options.iter().fold(Decimal::ZERO, |total, option| {
+                        let index = option.0 as usize;
+                        total
+                            + (proposal.option_weights[index] - proposal.carried_option_weights[index])
+                                .max(Decimal::ZERO)
+                    })
+                };
+                proposal.challenge = Some(ResultChallenge {
+                    window_end: now.add_days(challenge_period_days as i64).unwrap(),
+                    required_weight: self.governance_parameters.challenge_threshold
+                        * proposal.eligible_weight,
+                    bond: self.governance_parameters.challenge_bond,
+                    weight: Decimal::ZERO,
+                    challengers: KeyValueStore::new(),
+                    bonds: KeyValueStore::new(),
+                    outstanding_bonds: Decimal::ZERO,
+                    winning_options: tally.winning_options.clone(),
+                    winning_weight,
+                    reward_pool: Decimal::ZERO,
+                    rewards_paid: Decimal::ZERO,
+                    slashed: false,
+                    reward_claims: KeyValueStore::new(),
+                });
+            }
+            let reconfirmation_of = proposal.reconfirmation_of;
+            drop(proposal);
+
+            match status {
+                ProposalStatus::Passed => self.activity.passed_proposals += 1,
+                _ => self.activity.rejected_proposals += 1,
+            }
+
+            Runtime::emit_event(ProposalFinalizedEvent {
+                proposal_id,
+                status,
+                tally,
+                questions,
+                allocation,
+                finalized_at: now,
+            });
+
+            // A failed re-confirmation lapses the decision it re-confirms
+            if let (Some(original_id), ProposalStatus::Rejected) = (reconfirmation_of, status) {
+                let mut original = self.proposals.get_mut(&original_id).unwrap();
+                original.status = ProposalStatus::Lapsed;
+                drop(original);
+                self.activity.passed_proposals -= 1;
+                self.activity.lapsed_proposals += 1;
+
+                Runtime::emit_event(ProposalLapsedEvent {
+                    proposal_id: original_id,
+                    reconfirmation_id: proposal_id,
+                });
+            }
+
+            status
+        }
+
+        /// Challenges the result of a passed proposal during its challenge window, locking the
+        /// challenge bond. Once the challengers' voting power reaches the proposal's required
+        /// weight, the proposal becomes Disputed and needs a re-vote (see `reopen_proposal`).
+        /// The account must prove its presence; returns the part of the bond above the required amount
+        pub fn challenge_result(
+            &mut self,
+            account: Global<Account>,
+            proposal_id: u64,
+            mut bond: Bucket,
+        ) -> Bucket {
+            Runtime::assert_access_rule(account.get_owner_role().rule);
+            self.assert_not_denylisted(account);
+            self.assert_verified(account);
+
+            let weight = self.proposal_voting_power(account, proposal_id);
+
+            let mut proposal = self
+                .proposals
+                .get_mut(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+            ensure(proposal.status == ProposalStatus::Passed, GovernanceError::ChallengeWindowClosed);
+
+            let now = Clock::current_time_rounded_to_seconds();
+            let challenge = proposal
+                .challenge
+                .as_mut()
+                .unwrap_or_else(|| GovernanceError::ChallengeWindowClosed.raise());
+            ensure(
+                now.compare(challenge.window_end, TimeComparisonOperator::Lt),
+                GovernanceError::ChallengeWindowClosed,
+            );
+            ensure(
+                challenge.challengers.get(&account).is_none(),
+                GovernanceError::AlreadyChallenged,
+            );
+            let required_bond = challenge.bond;
+            ensure(
+                bond.resource_address() == XRD && bond.amount() >= required_bond,
+                GovernanceError::InsufficientChallengeBond,
+            );
+
+            challenge.challengers.insert(account, ());
+            challenge.bonds.insert(account, required_bond);
+            challenge.outstanding_bonds += required_bond;
+            challenge.weight += weight;
+            let challenge_weight = challenge.weight;
+            let disputed = challenge.weight >= challenge.required_weight;
+            if disputed {
+                proposal.status = ProposalStatus::Disputed;
+            }
+            drop(proposal);
+
+            self.challenge_bonds.put(bond.take(required_bond));
+
+            Runtime::emit_event(ResultChallengedEvent {
+                proposal_id,
+                account,
+                weight,
+                challenge_weight,
+            });
+            if disputed {
+                self.activity.passed_proposals -= 1;
+                self.activity.disputed_proposals += 1;
+
+                Runtime::emit_event(ProposalDisputedEvent {
+                    proposal_id,
+                    challenge_weight,
+                });
+            }
+
+            bond
+        }
+
+        /// Returns the challenge bond an account locked on a proposal once the proposal was
+        /// disputed. Bonds of a failed challenge are slashed to the winning voters (see
+        /// `claim_dispute_reward`), except on anonymous proposals, whose voters' sides are unknown:
+        /// there the bond is returned once the challenge window has ended.
+        /// The account must prove its presence
+        pub fn reclaim_challenge_bond(&mut self, account: Global<Account>, proposal_id: u64) -> Bucket {
+            Runtime::assert_access_rule(account.get_owner_role().rule);
+
+            let mut proposal = self
+                .proposals
+                .get_mut(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+            let disputed = proposal.status == ProposalStatus::Disputed;
+            let anonymous = proposal.anonymous;
+
+            let now = Clock::current_time_rounded_to_seconds();
+            let challenge = proposal
+                .challenge
+                .as_mut()
+                .unwrap_or_else(|| GovernanceError::NoChallengeBond.raise());
+            ensure(
+                disputed || now.compare(challenge.window_end, TimeComparisonOperator::Gte),
+                GovernanceError::ChallengeWindowOpen,
+            );
+            ensure(disputed || anonymous, GovernanceError::ChallengeBondSlashed);
+            let amount = challenge
+                .bonds
+                .remove(&account)
+                .unwrap_or_else(|| GovernanceError::NoChallengeBond.raise());
+            challenge.outstanding_bonds -= amount;
+            drop(proposal);
+
+            Runtime::emit_event(ChallengeBondReclaimedEvent {
+                proposal_id,
+                account,
+                amount,
+            });
+
+            self.challenge_bonds.take(amount)
+        }
+
+        /// Pays a voter on the winning side of a passed proposal its share of the bonds slashed
+        /// from a failed challenge, pro rata to the weight it counted for on the winning options
+        /// (including delegated power cast through it). Claimable once per voter, for
+        /// `DISPUTE_REWARD_CLAIM_DAYS` after the challenge window has ended without a dispute.
+        /// The account must prove its presence
+        pub fn claim_dispute_reward(&mut self, proposal_id: u64, account: Global<Account>) -> Bucket {
+            Runtime::assert_access_rule(account.get_owner_role().rule);
+
+            let mut proposal = self
+                .proposals
+                .get_mut(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+            ensure(
+                proposal.status != ProposalStatus::Disputed && !proposal.anonymous,
+                GovernanceError::NoDisputeReward,
+            );
+            let contribution = Self::winning_contribution(&proposal, account);
+
+            let now = Clock::current_time_rounded_to_seconds();
+            let challenge = proposal
+                .challenge
+                .as_mut()
+                .unwrap_or_else(|| GovernanceError::NoDisputeReward.raise());
+            ensure(
+                now.compare(challenge.window_end, TimeComparisonOperator::Gte),
+                GovernanceError::ChallengeWindowOpen,
+            );
+            ensure(
+                now.compare(Self::dispute_claim_end(challenge), TimeComparisonOperator::Lt),
+                GovernanceError::DisputeRewardClaimPeriodEnded,
+            );
+            ensure(
+                challenge.reward_claims.get(&account).is_none(),
+                GovernanceError::DisputeRewardAlreadyClaimed,
+            );
+
+            // The first claim slashes the failed challenge's bonds into the rewards vault
+            let slashed = Self::slash_challenge_bonds(challenge);
+            let amount = if challenge.winning_weight.is_positive() {
+                challenge.reward_pool * contribution / challenge.winning_weight
+            } else {
+                Decimal::ZERO
+            };
+            ensure(amount.is_positive(), GovernanceError::NoDisputeReward);
+            challenge.reward_claims.insert(account, ());
+            challenge.rewards_paid += amount;
+            drop(proposal);
+
+            if slashed.is_positive() {
+                let bonds = self.challenge_bonds.take(slashed);
+                self.dispute_rewards.put(bonds);
+            }
+
+            Runtime::emit_event(DisputeRewardClaimedEvent {
+                proposal_id,
+                account,
+                amount,
+            });
+
+            self.dispute_rewards.take(amount)
+        }
+
+        /// Sweeps the dispute rewards of a failed challenge that were left unclaimed once its
+        /// claim period has ended, including shares no voter can claim (e.g. of bloc weight).
+        /// Works on archived proposals too, which keep their unswept rewards.
+        pub fn sweep_dispute_rewards(&mut self, proposal_id: u64) -> Bucket {
+            let now = Clock::current_time_rounded_to_seconds();
+            let (slashed, amount) = match self.proposals.get_mut(&proposal_id) {
+                Some(mut proposal) => {
+                    ensure(
+                        proposal.status != ProposalStatus::Disputed && !proposal.anonymous,
+                        GovernanceError::NoDisputeReward,
+                    );
+                    let challenge = proposal
+                        .challenge
+                        .as_mut()
+                        .unwrap_or_else(|| GovernanceError::NoDisputeReward.raise());
+                    ensure(
+                        now.compare(Self::dispute_claim_end(challenge), TimeComparisonOperator::Gte),
+                        GovernanceError::DisputeRewardClaimPeriodOpen,
+                    );
+                    let slashed = Self::slash_challenge_bonds(challenge);
+                    let unclaimed = challenge.reward_pool - challenge.rewards_paid;
+                    challenge.rewards_paid = challenge.reward_pool;
+                    (slashed, unclaimed)
+                }
+                None => {
+                    let mut archived = self
+                        .archived
+                        .get_mut(&proposal_id)
+                        .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+                    let unswept = archived.unswept_dispute_rewards;
+                    archived.unswept_dispute_rewards = Decimal::ZERO;
+                    (Decimal::ZERO, unswept)
+                }
+            };
+            ensure(amount.is_positive(), GovernanceError::NoDisputeReward);
+
+            if slashed.is_positive() {
+                let bonds = self.challenge_bonds.take(slashed);
+                self.dispute_rewards.put(bonds);
+            }
+
+            Runtime::emit_event(DisputeRewardsSweptEvent {
+                proposal_id,
+                amount,
+            });
+
+            self.dispute_rewards.take(amount)
+        }
+
+        /// Moves a finalized proposal out of the active store into the archive, keeping only
+        /// its final tally. Allowed `archive_retention_days` after finalization, once its
+        /// re-confirmation (if any) is settled and all vote bonds have been reclaimed. A failed
+        /// challenge's bonds are slashed, and its unclaimed rewards kept for the owner to sweep,
+        /// once its claim period has ended.
+        pub fn archive_proposal(&mut self, proposal_id: u64) {
+            let proposal = self
+                .proposals
+                .get(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+
+            let finalized_at = proposal
+                .finalized_at
+                .unwrap_or_else(|| GovernanceError::ProposalNotFinalized.raise());
+            let now = Clock::current_time_rounded_to_seconds();
+            let archivable_at = finalized_at
+                .add_days(self.governance_parameters.archive_retention_days as i64)
+                .unwrap();
+            ensure(
+                now.compare(archivable_at, TimeComparisonOperator::Gte),
+                GovernanceError::ArchiveRetentionNotElapsed,
+            );
+            ensure(
+                proposal.outstanding_bonds.is_zero(),
+                GovernanceError::UnreclaimedVoteBonds,
+            );
+            // Challengers reclaim their bonds from disputed and anonymous proposals, while the
+            // bonds of a failed challenge no longer wait for winning voters to claim them
+            let failed_challenge =
+                proposal.status != ProposalStatus::Disputed && !proposal.anonymous;
+            let (slashed_bonds, unswept_dispute_rewards) = match &proposal.challenge {
+                Some(challenge) if failed_challenge => {
+                    let slashed = if challenge.slashed {
+                        Decimal::ZERO
+                    } else {
+                        challenge.outstanding_bonds
+                    };
+                    let unclaimed = challenge.reward_pool + slashed - challenge.rewards_paid;
+                    let claim_end = Self::dispute_claim_end(challenge);
+                    ensure(
+                        unclaimed.is_zero() || now.compare(claim_end, TimeComparisonOperator::Gte),
+                        GovernanceError::DisputeRewardClaimPeriodOpen,
+                    );
+                    (slashed, unclaimed)
+                }
+                Some(challenge) => {
+                    ensure(
+                        challenge.outstanding_bonds.is_zero(),
+                        GovernanceError::UnreclaimedChallengeBonds,
+                    );
+                    (Decimal::ZERO, Decimal::ZERO)
+                }
+                None => (Decimal::ZERO, Decimal::ZERO),
+            };
+
+            // A standing decision that must be re-confirmed stays active until settled
+            let reconfirmation_settled = match proposal.reconfirmation_id {
+                Some(id) => self
+                    .proposals
+                    .get(&id)
+                    .map_or(true, |reconfirmation| reconfirmation.status != ProposalStatus::Active),
+                None => proposal.reconfirm_after_days.is_none()
+                    || proposal.status != ProposalStatus::Passed,
+            };
+            ensure(reconfirmation_settled, GovernanceError::ReconfirmationPending);
+
+            let archived = ArchivedProposal {
+                title: proposal.title.clone(),
+                temperature_check_id: proposal.temperature_check_id,
+                author: proposal.author,
+                kind: proposal.kind.clone(),
+                action: proposal.action.clone(),
+                status: proposal.status,
+                tally: Self::tally_of(&proposal),
+                voter_count: proposal.voter_count + proposal.carried_voter_count,
+                eligible_weight: proposal.eligible_weight,
+                start: proposal.start,
+                deadline: proposal.deadline,
+                finalized_at,
+                executed_at: proposal.executed_at,
+                archived_at: now,
+                unswept_dispute_rewards,
+            };
+            let status = archived.status;
+            drop(proposal);
+
+            if slashed_bonds.is_positive() {
+                let bonds = self.challenge_bonds.take(slashed_bonds);
+                self.dispute_rewards.put(bonds);
+            }
+            self.proposals.remove(&proposal_id);
+            self.archived.insert(proposal_id, archived);
+
+            Runtime::emit_event(ProposalArchivedEvent {
+                proposal_id,
+                status,
+            });
+        }
+
+        /// Returns the condensed record of an archived proposal
+        pub fn get_archived_proposal(&self, proposal_id: u64) -> ArchivedProposal {
+            self.archived
+                .get(&proposal_id)
+                .map(|archived| archived.clone())
+                .unwrap_or_else(|| GovernanceError::ArchivedProposalNotFound.raise())
+        }
+
+        /// Approves executing a passed proposal, presenting an executor badge
+        /// Each executor badge ID can approve a proposal once.
+        pub fn approve_execution(&mut self, proposal_id: u64, executor_proof: Proof) {
+            let config = self
+                .execution_approval_config
+                .clone()
+                .unwrap_or_else(|| GovernanceError::ExecutionApprovalsNotConfigured.raise());
+            let executor = executor_proof
+                .check_with_message(
+                    config.executor_badge,
+                    GovernanceError::InvalidExecutorBadge.to_string(),
+                )
+                .as_non_fungible()
+                .non_fungible_local_id();
+
+            let mut proposal = self
+                .proposals
+                .get_mut(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+
+            ensure(proposal.status == ProposalStatus::Passed, GovernanceError::ProposalNotPassed);
+            ensure(proposal.executed_at.is_none(), GovernanceError::ProposalAlreadyExecuted);
+            ensure(
+                !proposal.execution_approvals.contains(&executor),
+                GovernanceError::ExecutionAlreadyApproved,
+            );
+
+            proposal.execution_approvals.push(executor.clone());
+            let approvals = proposal.execution_approvals.len() as u32;
+            drop(proposal);
+
+            Runtime::emit_event(ExecutionApprovedEvent {
+                proposal_id,
+                executor,
+                approvals,
+            });
+        }
+
+        /// Returns the execution approval requirement, None if approvals are disabled
+        pub fn get_execution_approval_config(&self) -> Option<ExecutionApprovalConfig> {
+            self.execution_approval_config.clone()
+        }
+
+        /// Sets the executor badge and number of approvals required before execution,
+        /// or disables approvals with None (owner only)
+        pub fn set_execution_approval_config(&mut self, config: Option<ExecutionApprovalConfig>) {
+            if let Some(config) = &config {
+                ensure(config.required_approvals > 0, GovernanceError::InvalidApprovalCount);
+            }
+
+            self.execution_approval_config = config.clone();
+
+            Runtime::emit_event(ExecutionApprovalConfigUpdatedEvent { config });
+        }
+
+        /// Executes a passed proposal (owner only)
+        /// When execution approvals are configured, the required number of distinct executors
+        /// must have called `approve_execution` first. The challenge window must have ended.
+        /// Dispatches on the proposal kind (a parameter change is applied, a funding approval is
+        /// emitted for the treasury), then carries out the proposal action, if any.
+        ///
+        /// # Arguments
+        /// * `proposal_id` - The ID of the passed proposal
+        /// * `reconfirm_after_days` - If set, the decision must be re-confirmed this many days
+        ///   after execution, see `spawn_reconfirmation`
+        pub fn execute_proposal(&mut self, proposal_id: u64, reconfirm_after_days: Option<u16>) {
+            let required_approvals = self
+                .execution_approval_config
+                .as_ref()
+                .map(|config| config.required_approvals)
+                .unwrap_or(0);
+
+            let mut proposal = self
+                .proposals
+                .get_mut(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+
+            ensure(proposal.status == ProposalStatus::Passed, GovernanceError::ProposalNotPassed);
+            ensure(proposal.executed_at.is_none(), GovernanceError::ProposalAlreadyExecuted);
+            ensure(
+                proposal.execution_approvals.len() as u32 >= required_approvals,
+                GovernanceError::InsufficientExecutionApprovals { required: required_approvals },
+            );
+            if let Some(days) = reconfirm_after_days {
+                ensure(days > 0, GovernanceError::InvalidReconfirmationDelay);
+            }
+
+            let now = Clock::current_time_rounded_to_seconds();
+            if let Some(challenge) = &proposal.challenge {
+                ensure(
+                    now.compare(challenge.window_end, TimeComparisonOperator::Gte),
+                    GovernanceError::ChallengeWindowOpen,
+                );
+            }
+            proposal.executed_at = Some(now);
+            proposal.reconfirm_after_days = reconfirm_after_days;
+            let kind = proposal.kind.clone();
+            let action = proposal.action.clone();
+            drop(proposal);
+
+            Runtime::emit_event(ProposalExecutedEvent {
+                proposal_id,
+                executed_at: now,
+                reconfirm_after_days,
+            });
+
+            match kind {
+                ProposalKind::Textual => {}
+                ProposalKind::Funding { amount, recipient } => {
+                    Runtime::emit_event(FundingApprovedEvent { proposal_id, amount, recipient })
+                }
+                ProposalKind::ParameterChange { parameters } => {
+                    // The window of the parameters the change passed under applies
+                    let window_days = self.governance_parameters.rollback_window_days;
+                    if window_days > 0 {
+                        let rollback = ExecutionRollback {
+                            previous_parameters: self.governance_parameters.clone(),
+                            applied_parameters: parameters.clone(),
+                            deadline: now.add_days(window_days as i64).unwrap(),
+                            rolled_back_at: None,
+                        };
+                        self.proposals.get_mut(&proposal_id).unwrap().rollback = Some(rollback);
+                    }
+                    self.apply_governance_parameters(parameters)
+                }
+                ProposalKind::Survey | ProposalKind::Ranking { .. } => {}
+            }
+
+            match action {
+                Some(ProposalAction::CallExecutor { executor_id, args }) => {
+                    self.call_executor(proposal_id, executor_id, args)
+                }
+                None => {}
+            }
+        }
+
+        /// Restores the parameters replaced by an executed parameter change, within the rollback
+        /// window captured at execution (security role or owner)
+        /// Only possible while the parameters the execution applied are still in force, so later
+        /// changes are never discarded; roll those back (or revert them) first.
+        pub fn rollback_execution(&mut self, proposal_id: u64) {
+            let mut proposal = self
+                .proposals
+                .get_mut(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+            let rollback = proposal
+                .rollback
+                .as_mut()
+                .unwrap_or_else(|| GovernanceError::NotRollbackable.raise());
+
+            ensure(rollback.rolled_back_at.is_none(), GovernanceError::AlreadyRolledBack);
+            let now = Clock::current_time_rounded_to_seconds();
+            ensure(
+                now.compare(rollback.deadline, TimeComparisonOperator::Lt),
+                GovernanceError::RollbackWindowClosed,
+            );
+            ensure(
+                rollback.applied_parameters == self.governance_parameters,
+                GovernanceError::ParametersChangedSinceExecution,
+            );
+            rollback.rolled_back_at = Some(now);
+            let previous_parameters = rollback.previous_parameters.clone();
+            drop(proposal);
+
+            self.apply_governance_parameters(previous_parameters);
+
+            Runtime::emit_event(ExecutionRolledBackEvent {
+                proposal_id,
+                rolled_back_at: now,
+            });
+        }
+
+        /// Registers a trusted component implementing `execute(proposal_id, args)` (owner only)
+        ///
+        /// Returns the executor ID proposals refer to
+        pub fn register_executor(&mut self, component: ComponentAddress) -> u64 {
+            let executor_id = self.executor_count;
+            self.executor_count += 1;
+            self.executors.insert(executor_id, component);
+
+            Runtime::emit_event(ExecutorRegisteredEvent { executor_id, component });
+
+            executor_id
+        }
+
+        /// Removes an executor from the registry (owner only)
+        /// Proposals referring to it can no longer be executed
+        pub fn remove_executor(&mut self, executor_id: u64) {
+            self.executors
+                .remove(&executor_id)
+                .unwrap_or_else(|| GovernanceError::ExecutorNotFound.raise());
+
+            Runtime::emit_event(ExecutorRemovedEvent { executor_id });
+        }
+
+        /// Returns the component registered under an executor ID
+        pub fn get_executor(&self, executor_id: u64) -> Option<ComponentAddress> {
+            self.executors.get(&executor_id).map(|component| *component)
+        }
+
+        /// Registers a voting group with the bloc weight its majority casts on `Groups`
+        /// proposals (owner only)
+        ///
+        /// Returns the ID of the voting group
+        pub fn register_voting_group(&mut self, name: String, bloc_weight: Decimal) -> u64 {
+            Self::validate_voting_group(&name, bloc_weight);
+
+            let group_id = self.voting_group_count;
+            self.voting_group_count += 1;
+            self.total_bloc_weight += bloc_weight;
+            self.voting_groups.insert(
+                group_id,
+                VotingGroup {
+                    name: name.clone(),
+                    bloc_weight,
+                    member_count: 0,
+                },
+            );
+
+            Runtime::emit_event(VotingGroupUpdatedEvent {
+                group_id,
+                name,
+                bloc_weight,
+            });
+
+            group_id
+        }
+
+        /// Changes the bloc weight of a voting group (owner only)
+        /// Groups keep the bloc weight captured at their first vote on a proposal
+        pub fn update_voting_group_weight(&mut self, group_id: u64, bloc_weight: Decimal) {
+            let mut group = self
+                .voting_groups
+                .get_mut(&group_id)
+                .unwrap_or_else(|| GovernanceError::VotingGroupNotFound.raise());
+            Self::validate_voting_group(&group.name, bloc_weight);
+            let previous_bloc_weight = group.bloc_weight;
+            group.bloc_weight = bloc_weight;
+            let name = group.name.clone();
+            drop(group);
+            self.total_bloc_weight += bloc_weight - previous_bloc_weight;
+
+            Runtime::emit_event(VotingGroupUpdatedEvent {
+                group_id,
+                name,
+                bloc_weight,
+            });
+        }
+
+        /// Adds an account to a voting group (owner only)
+        /// An account is in at most one voting group, and only votes on `Groups` proposals
+        /// created after it joined
+        pub fn add_voting_group_member(&mut self, group_id: u64, account: Global<Account>) {
+            ensure(
+                self.voting_group_members.get(&account).is_none(),
+                GovernanceError::AlreadyInVotingGroup,
+            );
+            self.voting_groups
+                .get_mut(&group_id)
+                .unwrap_or_else(|| GovernanceError::VotingGroupNotFound.raise())
+                .member_count += 1;
+            self.voting_group_members.insert(
+                account,
+                VotingGroupMembership {
+                    group_id,
+                    added_at: Clock::current_time_rounded_to_seconds(),
+                },
+            );
+
+            Runtime::emit_event(VotingGroupMemberAddedEvent { group_id, account });
+        }
+
+        /// Removes an account from its voting group (owner only)
+        /// Votes it already cast keep counting in its group's tallies
+        pub fn remove_voting_group_member(&mut self, account: Global<Account>) {
+            let group_id = self
+                .voting_group_members
+                .remove(&account)
+                .unwrap_or_else(|| GovernanceError::NotInVotingGroup.raise())
+                .group_id;
+            self.voting_groups.get_mut(&group_id).unwrap().member_count -= 1;
+
+            Runtime::emit_event(VotingGroupMemberRemovedEvent { group_id, account });
+        }
+
+        /// Returns a voting group of the registry
+        pub fn get_voting_group(&self, group_id: u64) -> Option<VotingGroup> {
+            self.voting_groups.get(&group_id).map(|group| group.clone())
+        }
+
+        /// Returns the ID of the voting group an account is in, if any
+        pub fn get_voting_group_of(&self, account: Global<Account>) -> Option<u64> {
+            self.voting_group_members.get(&account).map(|membership| membership.group_id)
+        }
+
+        /// Creates the re-confirmation proposal of an executed decision whose sunset has elapsed
+        /// Callable by anyone. If the re-confirmation is rejected, the decision becomes Lapsed.
+        ///
+        /// Returns the ID of the re-confirmation proposal
+        pub fn spawn_reconfirmation(&mut self, proposal_id: u64) -> u64 {
+            let eligible_weight = self.eligible_voting_weight();
+
+            let original = self
+                .proposals
+                .get(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+
+            ensure(original.status == ProposalStatus::Passed, GovernanceError::DecisionNotStanding);
+            let executed_at = original
+                .executed_at
+                .unwrap_or_else(|| GovernanceError::ProposalNotExecuted.raise());
+            let reconfirm_after_days = original
+                .reconfirm_after_days
+                .unwrap_or_else(|| GovernanceError::NoReconfirmationDelay.raise());
+            ensure(
+                original.reconfirmation_id.is_none(),
+                GovernanceError::ReconfirmationAlreadySpawned,
+            );
+
+            let now = Clock::current_time_rounded_to_seconds();
+            let reconfirm_at = executed_at.add_days(reconfirm_after_days as i64).unwrap();
+            ensure(
+                now.compare(reconfirm_at, TimeComparisonOperator::Gte),
+                GovernanceError::ReconfirmationNotDue,
+            );
+            let temperature_check_id = original.temperature_check_id;
+            drop(original);
+
+            let tc = self
+                .temperature_checks
+                .get(&temperature_check_id)
+                .unwrap();
+            let mut proposal = Self::build_proposal(
+                &tc,
+                temperature_check_id,
+                &self.governance_parameters,
+                eligible_weight,
+                self.total_bloc_weight,
+                now,
+            );
+            drop(tc);
+            proposal.reconfirmation_of = Some(proposal_id);
+            // Re-confirming keeps the decision standing, it does not carry out the decision again
+            proposal.kind = ProposalKind::Textual;
+            proposal.action = None;
+
+            let reconfirmation_id = self.proposal_count;
+            self.proposal_count += 1;
+
+            let created_event = Self::proposal_created_event(reconfirmation_id, temperature_check_id, &proposal);
+
+            self.proposals.insert(reconfirmation_id, proposal);
+            self.proposals.get_mut(&proposal_id).unwrap().reconfirmation_id = Some(reconfirmation_id);
+
+            Runtime::emit_event(created_event);
+            Runtime::emit_event(ReconfirmationSpawnedEvent {
+                proposal_id: reconfirmation_id,
+                reconfirmation_of: proposal_id,
+            });
+
+            reconfirmation_id
+        }
+
+        /// Previews a direct vote on a proposal without changing any state, so wallets can show
+        /// the effective weight and any failure reason before submitting. Account presence is
+        /// not checked.
+        pub fn preview_vote(
+            &self,
+            account: Global<Account>,
+            proposal_id: u64,
+            votes: Vec<ProposalVoteOptionId>,
+        ) -> VotePreview {
+            let weight = self.voting_power_of(account);
+            let mut preview = VotePreview {
+                failure_reason: None,
+                weight,
+                overridden_delegated_weight: Decimal::ZERO,
+                replaced_carried_over_weight: Decimal::ZERO,
+            };
+
+            if self.is_denylisted(account) {
+                preview.failure_reason = Some(GovernanceError::AccountDenylisted);
+                return preview;
+            }
+            if !self.is_verified(account) {
+                preview.failure_reason = Some(GovernanceError::AccountNotVerified);
+                return preview;
+            }
+            if self.proposals.get(&proposal_id).is_none() {
+                preview.failure_reason = Some(GovernanceError::ProposalNotFound);
+                return preview;
+            }
+
+            let (weight, delegates_excess) = self.direct_vote_weight(account, proposal_id);
+            preview.weight = weight;
+            if let Some(error) = self
+                .membership_error(proposal_id, account)
+                .or_else(|| self.min_balance_error(proposal_id, account))
+                .or_else(|| self.account_age_error(proposal_id, account))
+            {
+                preview.failure_reason = Some(error);
+                return preview;
+            }
+
+            preview.replaced_carried_over_weight = self
+                .carried_over_to_replace(proposal_id, account)
+                .iter()
+                .fold(Decimal::ZERO, |total, contribution| total + contribution.weight);
+
+            let proposal = self.proposals.get(&proposal_id).unwrap();
+            let now = Clock::current_time_rounded_to_seconds();
+            preview.failure_reason = Self::proposal_vote_error(&proposal, account, &votes, now);
+            preview.weight = weight * Self::early_vote_multiplier(&proposal, now);
+            preview.overridden_delegated_weight = if delegates_excess {
+                Decimal::ZERO
+            } else {
+                proposal
+                    .delegated_votes
+                    .get(&account)
+                    .map(|delegated_votes| {
+                        delegated_votes
+                            .iter()
+                            .fold(Decimal::ZERO, |total, delegated_vote| total + delegated_vote.weight)
+                    })
+                    .unwrap_or(Decimal::ZERO)
+            };
+
+            preview
+        }
+
+        /// Returns the delegated votes cast with a delegator's power on a proposal
+        pub fn get_delegated_votes(
+            &self,
+            proposal_id: u64,
+            delegator: Global<Account>,
+        ) -> Vec<DelegatedVote> {
+            let proposal = self
+                .proposals
+                .get(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+
+            proposal
+                .delegated_votes
+                .get(&delegator)
+                .map(|v| v.clone())
+                .unwrap_or_default()
+        }
+
+        /// Returns an account's direct vote on a temperature check with the weight it was counted at
+        /// Hidden for private consultations until the temperature check is finalized
+        pub fn get_temperature_check_vote(
+            &self,
+            temperature_check_id: u64,
+            account: Global<Account>,
+        ) -> Option<TemperatureCheckVoteRecord> {
+            let tc = self
+                .temperature_checks
+                .get(&temperature_check_id)
+                .unwrap_or_else(|| GovernanceError::TemperatureCheckNotFound.raise());
+            ensure(
+                !tc.members_only || tc.status != TemperatureCheckStatus::Active,
+                GovernanceError::TallyHiddenUntilFinalized,
+            );
+
+            tc.votes.get(&account).map(|v| v.clone())
+        }
+
+        /// Returns an account's direct vote on a proposal with the weight it was counted at
+        /// (selected options are empty for anonymous proposals)
+        /// Hidden for private consultations until the proposal is finalized
+        pub fn get_proposal_vote(
+            &self,
+            proposal_id: u64,
+            account: Global<Account>,
+        ) -> Option<ProposalVoteRecord> {
+            let proposal = self
+                .proposals
+                .get(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+            Self::assert_tally_visible(&proposal);
+
+            proposal.votes.get(&account).map(|v| v.clone())
+        }
+
+        /// Returns the rationale an account attached to its vote on a proposal, if any
+        pub fn get_vote_rationale(
+            &self,
+            proposal_id: u64,
+            account: Global<Account>,
+        ) -> Option<VoteRationale> {
+            let proposal = self
+                .proposals
+                .get(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+
+            proposal.rationales.get(&account).map(|r| r.clone())
+        }
+
+        /// Returns up to `limit` responses to a survey in submission order, starting at the
+        /// `start`-th response. Only available once the survey's deadline has passed.
+        pub fn get_survey_responses(
+            &self,
+            proposal_id: u64,
+            start: u64,
+            limit: u64,
+        ) -> Vec<SurveyResponseRecord> {
+            let proposal = self
+                .proposals
+                .get(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+            ensure(matches!(proposal.kind, ProposalKind::Survey), GovernanceError::NotASurvey);
+            ensure(
+                Clock::current_time_rounded_to_seconds()
+                    .compare(proposal.deadline, TimeComparisonOperator::Gte),
+                GovernanceError::SurveyResponsesHidden,
+            );
+
+            let end = start.saturating_add(limit).min(proposal.response_count);
+            (start..end)
+                .map(|response_number| proposal.responses.get(&response_number).unwrap().clone())
+                .collect()
+        }
+
+        /// Returns the temperature checks of a ranking proposal by descending weighted Borda
+        /// count (ties in the proposal's order), from the live tally. Hidden until
+        /// finalization on members-only proposals
+        pub fn get_ranking(&self, proposal_id: u64) -> Vec<RankedTemperatureCheck> {
+            let proposal = self
+                .proposals
+                .get(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+            Self::assert_tally_visible(&proposal);
+            let candidates = match &proposal.kind {
+                ProposalKind::Ranking { temperature_check_ids } => temperature_check_ids.clone(),
+                _ => GovernanceError::NotARanking.raise(),
+            };
+
+            // Every ballot orders all n temperature checks and so holds n(n-1)/2 Borda points,
+            // of which each option tallies the weight times its share
+            let n = candidates.len() as u64;
+            let ballot_points = Decimal::from(n * (n - 1) / 2);
+            let mut ranking: Vec<RankedTemperatureCheck> = candidates
+                .into_iter()
+                .enumerate()
+                .map(|(index, temperature_check_id)| RankedTemperatureCheck {
+                    temperature_check_id,
+                    borda_score: proposal.option_weights[index] * ballot_points,
+                })
+                .collect();
+            ranking.sort_by(|a, b| b.borda_score.cmp(&a.borda_score));
+            ranking
+        }
+
+        /// Returns a voting group's intra-group tally on a `Groups` proposal, None if none of its
+        /// members voted
+        pub fn get_group_tally(&self, proposal_id: u64, group_id: u64) -> Option<GroupTally> {
+            let proposal = self
+                .proposals
+                .get(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+
+            proposal.group_tallies.get(&group_id).map(|tally| tally.clone())
+        }
+
+        /// Returns an account's reaction to a proposal, if any
+        pub fn get_reaction(&self, proposal_id: u64, account: Global<Account>) -> Option<u8> {
+            let proposal = self
+                .proposals
+                .get(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+
+            proposal.reactions.get(&account).map(|r| *r)
+        }
+
+        /// Returns the number of accounts per reaction on a proposal, indexed by reaction
+        pub fn get_reaction_counts(&self, proposal_id: u64) -> Vec<u64> {
+            let proposal = self
+                .proposals
+                .get(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+
+            proposal.reaction_counts.clone()
+        }
+
+        /// Returns the voting intention a delegatee announced on a proposal, if any
+        pub fn get_intended_vote(
+            &self,
+            proposal_id: u64,
+            delegatee: Global<Account>,
+        ) -> Option<IntendedVote> {
+            let proposal = self
+                .proposals
+                .get(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+
+            proposal.intended_votes.get(&delegatee).map(|v| v.clone())
+        }
+
+        /// Returns the delegators whose power a delegatee cast on a proposal, with the weight of
+        /// each, so delegators can audit how their power was used
+        pub fn get_delegated_breakdown(
+            &self,
+            proposal_id: u64,
+            delegatee: Global<Account>,
+        ) -> Vec<(Global<Account>, Decimal)> {
+            let proposal = self
+                .proposals
+                .get(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+
+            proposal
+                .delegated_vote_breakdown
+                .get(&delegatee)
+                .map(|b| b.clone())
+                .unwrap_or_default()
+        }
+
+        /// Returns up to `limit` direct voters of a proposal in voting order, starting at the
+        /// `start`-th voter, with their selected options (empty for anonymous proposals) and voting
+        /// weight, so reward programs can enumerate participants on-ledger. Delegated power is not
+        /// included.
+        pub fn export_voters(
+            &self,
+            proposal_id: u64,
+            start: u64,
+            limit: u64,
+        ) -> Vec<(Global<Account>, Vec<ProposalVoteOptionId>, Decimal)> {
+            let proposal = self
+                .proposals
+                .get(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+            Self::assert_tally_visible(&proposal);
+
+            let end = start.saturating_add(limit).min(proposal.voter_count);
+            (start..end)
+                .map(|voter_number| {
+                    let account = *proposal.voter_index.get(&voter_number).unwrap();
+                    let record = proposal.votes.get(&account).unwrap().clone();
+                    (account, record.votes, record.weight)
+                })
+                .collect()
+        }
+
+        /// Returns up to `limit` raw records of the direct votes on a finalized proposal in voting
+        /// order, starting at the `start`-th voter (auditor only). Unlike `export_voters`, records
+        /// include the multiplier, delegators, bond and rationale of each vote.
+        pub fn dump_votes(&self, proposal_id: u64, start: u64, limit: u64) -> Vec<VoteRecord> {
+            let proposal = self
+                .proposals
+                .get(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+            ensure(proposal.status != ProposalStatus::Active, GovernanceError::ProposalNotFinalized);
+
+            let end = start.saturating_add(limit).min(proposal.voter_count);
+            (start..end)
+                .map(|voter_number| {
+                    let account = *proposal.voter_index.get(&voter_number).unwrap();
+                    let record = proposal.votes.get(&account).unwrap().clone();
+                    let weight = record.weight;
+                    VoteRecord {
+                        account,
+                        votes: record.votes,
+                        weight,
+                        early_vote_multiplier: proposal
+                            .early_vote_multipliers
+                            .get(&account)
+                            .map_or(Decimal::ONE, |m| *m),
+                        effective_weight: proposal
+                            .effective_weights
+                            .get(&account)
+                            .map_or(weight, |w| *w),
+                        delegators: proposal
+                            .delegated_vote_breakdown
+                            .get(&account)
+                            .map(|b| b.clone())
+                            .unwrap_or_default(),
+                        bond: proposal.bonds.get(&account).map_or(Decimal::ZERO, |b| *b),
+                        rationale: proposal.rationales.get(&account).map(|r| r.clone()),
+                    }
+                })
+                .collect()
+        }
+
+        /// Adds XRD to the vault that subsidizes voting fees
+        pub fn fund_fee_subsidy(&mut self, funds: Bucket) {
+            self.fee_subsidy.put(funds);
+        }
+
+        /// Returns the XRD available to subsidize voting fees
+        pub fn get_fee_subsidy_balance(&self) -> Decimal {
+            self.fee_subsidy.amount()
+        }
+
+        /// Returns the fee subsidy limits, None if the subsidy is disabled
+        pub fn get_fee_subsidy_config(&self) -> Option<FeeSubsidyConfig> {
+            self.fee_subsidy_config.clone()
+        }
+
+        /// Sets the fee subsidy limits, or disables the subsidy with None (owner only)
+        pub fn update_fee_subsidy_config(&mut self, config: Option<FeeSubsidyConfig>) {
+            if let Some(config) = &config {
+                ensure(config.max_fee_per_vote > Decimal::ZERO, GovernanceError::NonPositiveFeeCap);
+                ensure(config.max_subsidized_votes_per_day > 0, GovernanceError::NoSubsidizedVotes);
+            }
+
+            self.fee_subsidy_config = config.clone();
+
+            Runtime::emit_event(FeeSubsidyConfigUpdatedEvent { config });
+        }
+
+        /// Returns the limits on the links, vote options and selections of new temperature checks
+        pub fn get_limits(&self) -> GovernanceLimits {
+            self.limits
+        }
+
+        /// Updates the limits on new temperature checks (owner only)
+        /// Each limit must be positive and within its hard maximum, and `max_selections` cannot
+        /// exceed `max_vote_options`. Existing temperature checks and proposals are not affected.
+        pub fn update_limits(&mut self, limits: GovernanceLimits) {
+            ensure(limits.is_valid(), GovernanceError::InvalidGovernanceLimits);
+
+            self.limits = limits;
+
+            Runtime::emit_event(GovernanceLimitsUpdatedEvent { limits });
+        }
+
+        /// Sets the royalty charged on `make_temperature_check` (owner only)
+        /// `RoyaltyAmount::Free` disables it
+        pub fn set_temperature_check_royalty(&mut self, amount: RoyaltyAmount) {
+            Runtime::global_component().set_royalty("make_temperature_check", amount);
+        }
+
+        /// Withdraws the royalties collected by the component (owner only)
+        pub fn claim_royalties(&mut self) -> Bucket {
+            Runtime::global_component().claim_component_royalties()
+        }
+
+        /// Adds XRD to the attachment storage reimbursement escrow
+        pub fn fund_storage_reimbursements(&mut self, funds: Bucket) {
+            self.storage_reimbursements.put(funds);
+        }
+
+        /// Returns the XRD available for attachment storage reimbursements
+        pub fn get_storage_reimbursement_balance(&self) -> Decimal {
+            self.storage_reimbursements.amount()
+        }
+
+        /// Reimburses the author of a passed proposal for attachment storage fees
+        /// The claimant must prove their presence and present a storage receipt issued for the
+        /// proposal, which is consumed by the claim
+        ///
+        /// # Arguments
+        /// * `proposal_id` - The passed proposal the attachments belong to
+        /// * `claimant` - The author or a co-author of the proposal
+        /// * `amount` - XRD to reimburse, at most the fees on the receipt; all claims of a proposal
+        ///   together are capped by the proposal's `max_storage_reimbursement`
+        /// * `receipt_proof` - Proof of a single receipt of the configured storage receipt resource
+        pub fn claim_storage_reimbursement(
+            &mut self,
+            proposal_id: u64,
+            claimant: Global<Account>,
+            amount: Decimal,
+            receipt_proof: Proof,
+        ) -> Bucket {
+            let receipt_resource = self
+                .storage_receipt_resource
+                .unwrap_or_else(|| GovernanceError::StorageReimbursementsDisabled.raise());
+            let receipt = receipt_proof
+                .check_with_message(
+                    receipt_resource,
+                    GovernanceError::InvalidStorageReceipt.to_string(),
+                )
+                .as_non_fungible()
+                .non_fungible::<StorageReceipt>();
+            let receipt_id = receipt.global_id().clone();
+            let receipt_data = receipt.data();
+
+            ensure(amount > Decimal::ZERO, GovernanceError::NonPositiveAmount);
+            ensure(
+                receipt_data.proposal_id == proposal_id,
+                GovernanceError::InvalidStorageReceipt,
+            );
+            ensure(
+                amount <= receipt_data.amount,
+                GovernanceError::ReimbursementExceedsReceipt,
+            );
+            ensure(
+                self.claimed_storage_receipts.get(&receipt_id).is_none(),
+                GovernanceError::StorageReceiptAlreadyClaimed,
+            );
+
+            let mut proposal = self
+                .proposals
+                .get_mut(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+
+            Self::assert_author(proposal.author, &proposal.co_authors, claimant);
+
+            ensure(proposal.status == ProposalStatus::Passed, GovernanceError::ProposalNotPassed);
+            ensure(
+                proposal.storage_reimbursed + amount <= proposal.max_storage_reimbursement,
+                GovernanceError::ReimbursementCapExceeded,
+            );
+
+            proposal.storage_reimbursed += amount;
+            drop(proposal);
+            self.claimed_storage_receipts.insert(receipt_id, ());
+
+            Runtime::emit_event(StorageReimbursementClaimedEvent {
+                proposal_id,
+                author: claimant,
+                amount,
+            });
+
+            self.storage_reimbursements.take(amount)
+        }
+
+        /// Returns the voting power configuration
+        pub fn get_voting_power_config(&self) -> VotingPowerConfig {
+            self.voting_power_config.clone()
+        }
+
+        /// Returns the current voting power of an account, from its current balances whatever the
+        /// voting power mode
+        pub fn get_voting_power(&self, account: Global<Account>) -> Decimal {
+            self.voting_power_of(account)
+        }
+
+        /// Updates the resources and multipliers voting power is computed from (owner only)
+        /// Proposals that already started keep the eligible weight captured at their creation
+        pub fn update_voting_power_config(&mut self, config: VotingPowerConfig) {
+            ensure(!config.resource_weights.is_empty(), GovernanceError::NoVotingPowerResources);
+            ensure(
+                config.resource_weights.len() <= MAX_VOTING_POWER_RESOURCES,
+                GovernanceError::TooManyVotingPowerResources,
+            );
+
+            let mut seen_resources: Vec<ResourceAddress> = Vec::new();
+            for (resource, weight) in &config.resource_weights {
+                ensure(*weight > Decimal::ZERO, GovernanceError::NonPositiveResourceWeight);
+                ensure(
+                    !seen_resources.contains(resource),
+                    GovernanceError::DuplicateVotingPowerResource,
+                );
+                seen_resources.push(*resource);
+            }
+            if let VotingPowerMode::AverageBalance { days, .. } = config.mode {
+                ensure(
+                    (1..=MAX_BALANCE_AVERAGING_DAYS).contains(&days),
+                    GovernanceError::InvalidBalanceAveraging,
+                );
+            }
+
+            self.voting_power_config = config.clone();
+
+            Runtime::emit_event(VotingPowerConfigUpdatedEvent { config });
+        }
+
+        /// Returns whether an account is on the denylist
+        pub fn is_denylisted(&self, account: Global<Account>) -> bool {
+            self.denylist.get(&account).is_some()
+        }
+
+        /// Bars an account from voting, casting delegated power and creating temperature checks
+        /// (moderator or owner only)
+        pub fn add_to_denylist(&mut self, account: Global<Account>, reason: String) {
+            ensure(!self.is_denylisted(account), GovernanceError::AccountAlreadyDenylisted);
+
+            self.denylist.insert(account, reason.clone());
+
+            Runtime::emit_event(AccountDenylistedEvent { account, reason });
+        }
+
+        /// Removes an account from the denylist (moderator or owner only)
+        pub fn remove_from_denylist(&mut self, account: Global<Account>) {
+            self.denylist
+                .remove(&account)
+                .unwrap_or_else(|| GovernanceError::AccountNotDenylisted.raise());
+
+            Runtime::emit_event(AccountRemovedFromDenylistEvent { account });
+        }
+
+        /// Returns whether an account is currently on the member allowlist
+        pub fn is_member(&self, account: Global<Account>) -> bool {
+            self.members
+                .get(&account)
+                .map(|membership| membership.removed_at.is_none())
+                .unwrap_or(false)
+        }
+
+        /// Adds an account to the member allowlist of private consultations (moderator or owner only)
+        /// Only consultations created afterwards let the account vote
+        pub fn add_member(&mut self, account: Global<Account>) {
+            ensure(!self.is_member(account), GovernanceError::AlreadyAMember);
+
+            self.members.insert(
+                account,
+                Membership {
+                    added_at: Clock::current_time_rounded_to_seconds(),
+                    removed_at: None,
+                },
+            );
+
+            Runtime::emit_event(MemberAddedEvent { account });
+        }
+
+        /// Removes an account from the member allowlist (moderator or owner only)
+        /// Consultations created while the account was a member still let it vote
+        pub fn remove_member(&mut self, account: Global<Account>) {
+            ensure(self.is_member(account), GovernanceError::NotAMember);
+
+            self.members.get_mut(&account).unwrap().removed_at =
+                Some(Clock::current_time_rounded_to_seconds());
+
+            Runtime::emit_event(MemberRemovedEvent { account });
+        }
+
+        /// Returns the identity verifier component, if any
+        pub fn get_identity_verifier(&self) -> Option<ComponentAddress> {
+            self.identity_verifier
+        }
+
+        /// Sets or clears the identity verifier component (owner only)
+        /// The component must expose `is_verified(account: Global<Account>) -> bool`
+        pub fn set_identity_verifier(&mut self, identity_verifier: Option<ComponentAddress>) {
+            self.identity_verifier = identity_verifier;
+
+            Runtime::emit_event(IdentityVerifierUpdatedEvent { identity_verifier });
+        }
+
+        /// Returns the account age oracle configuration, if any
+        pub fn get_account_age_config(&self) -> Option<AccountAgeConfig> {
+            self.account_age_config.clone()
+        }
+
+        /// Sets or clears the account age oracle configuration (owner only)
+        /// The oracle must expose `get_account_created_at(account: Global<Account>) -> Option<Instant>`
+        pub fn set_account_age_config(&mut self, config: Option<AccountAgeConfig>) {
+            self.account_age_config = config.clone();
+
+            Runtime::emit_event(AccountAgeConfigUpdatedEvent { config });
+        }
+
+        /// Whether description files and vote rationale files may be stored on the component
+        pub fn is_trusted_storage(&self, component: ComponentAddress) -> bool {
+            self.trusted_storage.get(&component).is_some()
+        }
+
+        /// Adds a radix-file-storage component to the trusted storage allowlist (owner only)
+        pub fn add_trusted_storage(&mut self, component: ComponentAddress) {
+            ensure(!self.is_trusted_storage(component), GovernanceError::StorageAlreadyTrusted);
+
+            self.trusted_storage.insert(component, ());
+
+            Runtime::emit_event(TrustedStorageAddedEvent { component });
+        }
+
+        /// Removes a component from the trusted storage allowlist (owner only)
+        /// Temperature checks already referencing it keep their description file
+        pub fn remove_trusted_storage(&mut self, component: ComponentAddress) {
+            ensure(self.is_trusted_storage(component), GovernanceError::StorageNotTrusted);
+
+            self.trusted_storage.remove(&component);
+
+            Runtime::emit_event(TrustedStorageRemovedEvent { component });
+        }
+
+        /// Sets the resource of storage receipts accepted for reimbursement claims (owner only)
+        pub fn set_storage_receipt_resource(&mut self, receipt_resource: ResourceAddress) {
+            self.storage_receipt_resource = Some(receipt_resource);
+        }
+
+        /// Sets the vote delegation component used to resolve delegated votes (owner only)
+        /// The component must be a VoteDelegation of this package that has authorized this
+        /// governance component with `set_governance`
+        pub fn set_vote_delegation(&mut self, vote_delegation: Global<VoteDelegation>) {
+            let blueprint_id =
+                ScryptoVmV1Api::object_get_blueprint_id(vote_delegation.address().as_node_id());
+            ensure(
+                blueprint_id == VoteDelegation::blueprint_id(),
+                GovernanceError::NotVoteDelegationBlueprint,
+            );
+            ensure(
+                vote_delegation.get_governance() == Some(Runtime::global_address()),
+                GovernanceError::VoteDelegationNotLinked,
+            );
+
+            self.vote_delegation = Some(vote_delegation);
+        }
+
+        /// Returns the DAO branding
+        pub fn get_branding(&self) -> GovernanceBranding {
+            self.branding.clone()
+        }
+
+        /// Returns the DAO name
+        pub fn get_dao_name(&self) -> String {
+            self.branding.dao_name.clone()
+        }
+
+        /// Returns the DAO logo URL
+        pub fn get_logo_url(&self) -> Url {
+            self.branding.logo_url.clone()
+        }
+
+        /// Returns the governance forum URL
+        pub fn get_forum_url(&self) -> Url {
+            self.branding.forum_url.clone()
+        }
+
+        /// Returns the governance docs URL
+        pub fn get_docs_url(&self) -> Url {
+            self.branding.docs_url.clone()
+        }
+
+        /// Updates the DAO branding and the matching component metadata (owner only)
+        pub fn update_branding(&mut self, branding: GovernanceBranding) {
+            ensure(!branding.dao_name.is_empty(), GovernanceError::EmptyDaoName);
+
+            let component = Runtime::global_component();
+            component.set_metadata("name", branding.dao_name.clone());
+            component.set_metadata("icon_url", branding.logo_url.clone());
+            component.set_metadata("info_url", branding.docs_url.clone());
+            component.set_metadata("forum_url", branding.forum_url.clone());
+
+            self.branding = branding.clone();
+
+            Runtime::emit_event(GovernanceBrandingUpdatedEvent { branding });
+        }
+
+        /// Updates the governance parameters (owner only)
+        /// Voting periods must stay within the hard-coded bounds in lib.rs.
+        /// The change is recorded in the parameter change log, which keeps the last
+        /// `MAX_PARAMETER_HISTORY` changes
+        pub fn update_governance_parameters(&mut self, new_params: GovernanceParameters) {
+            Self::validate_governance_parameters(&new_params);
+            self.apply_governance_parameters(new_params);
+        }
+
+        /// Replaces the governance parameters, recording the change in the parameter change log
+        fn apply_governance_parameters(&mut self, new_params: GovernanceParameters) {
+            let old_params = std::mem::replace(&mut self.governance_parameters, new_params.clone());
+
+            if self.parameter_history.len() >= MAX_PARAMETER_HISTORY {
+                self.parameter_history.remove(0);
+            }
+            self.parameter_history.push(ParameterChange {
+                changed_at: Clock::current_time_rounded_to_seconds(),
+                old_params: old_params.clone(),
+                new_params: new_params.clone(),
+            });
+
+            Runtime::emit_event(GovernanceParametersUpdatedEvent { old_params, new_params });
+        }
+
+        /// Returns a one-call summary of governance activity for dashboards
+        pub fn get_governance_stats(&self) -> GovernanceStats {
+            let activity = &self.activity;
+            let finalized = activity.passed_proposals
+                + activity.rejected_proposals
+                + activity.lapsed_proposals
+                + activity.disputed_proposals;
+
+            GovernanceStats {
+                temperature_check_count: self.temperature_check_count,
+                proposal_count: self.proposal_count,
+                active_proposals: self.proposal_count - finalized,
+                passed_proposals: activity.passed_proposals,
+                rejected_proposals: activity.rejected_proposals,
+                lapsed_proposals: activity.lapsed_proposals,
+                disputed_proposals: activity.disputed_proposals,
+                unique_voters: activity.unique_voters,
+                total_weight_cast: activity.total_weight_cast,
+                parameters: self.governance_parameters.clone(),
+            }
+        }
+
+        /// Returns the logged governance parameter changes, oldest first
+        pub fn get_parameter_history(&self) -> Vec<ParameterChange> {
+            self.parameter_history.clone()
+        }
+
+        /// Returns the voting weight of an account: its balances multiplied by the configured weights
+        fn voting_power_of(&self, account: Global<Account>) -> Decimal {
+            self.voting_power_config
+                .resource_weights
+                .iter()
+                .fold(Decimal::ZERO, |power, (resource, weight)| {
+                    power + account.balance(*resource) * *weight
+                })
+        }
+
+        /// Returns the voting weight of an account on a temperature check or proposal opened at
+        /// `opened_at`: its current voting power, or with `VotingPowerMode::AverageBalance` its
+        /// average balances over the configured days before `opened_at`
+        fn voting_power_at(&self, account: Global<Account>, opened_at: Instant) -> Decimal {
+            let (balance_history, days) = match self.voting_power_config.mode {
+                VotingPowerMode::CurrentBalance => return self.voting_power_of(account),
+                VotingPowerMode::AverageBalance { balance_history, days } => (balance_history, days),
+            };
+            let balance_history: Global<AnyComponent> = Global::from(balance_history);
+            let from = opened_at.add_days(-(days as i64)).unwrap();
+
+            self.voting_power_config
+                .resource_weights
+                .iter()
+                .fold(Decimal::ZERO, |power, (resource, weight)| {
+                    let average: Decimal = balance_history.call_raw(
+                        BALANCE_HISTORY_METHOD,
+                        scrypto_args!(account, *resource, from, opened_at),
+                    );
+                    power + average * *weight
+                })
+        }
+
+        /// Returns the voting weight of an account on a proposal (see `voting_power_at`)
+        fn proposal_voting_power(&self, account: Global<Account>, proposal_id: u64) -> Decimal {
+            let snapshot = self
+                .proposals
+                .get(&proposal_id)
+                .map(|p| p.delegation_snapshot)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+
+            self.voting_power_at(account, snapshot)
+        }
+
+        /// Returns the delegation from delegator to delegatee at the given snapshot, if any
+        fn delegation_at(
+            &self,
+            delegator: Global<Account>,
+            delegatee: Global<Account>,
+            snapshot: Instant,
+        ) -> Option<Delegation> {
+            let vote_delegation = self
+                .vote_delegation
+                .unwrap_or_else(|| GovernanceError::VoteDelegationNotConfigured.raise());
+
+            vote_delegation
+                .get_delegations_at(delegator, snapshot)
+                .into_iter()
+                .find(|d| d.delegatee == delegatee)
+        }
+
+        /// Whether a delegator had delegations other than a default proxy at the snapshot,
+        /// covering proposals or temperature checks
+        fn has_explicit_delegations_at(
+            &self,
+            delegator: Global<Account>,
+            snapshot: Instant,
+            for_proposals: bool,
+        ) -> bool {
+            self.vote_delegation
+                .unwrap_or_else(|| GovernanceError::VoteDelegationNotConfigured.raise())
+                .get_delegations_at(delegator, snapshot)
+                .iter()
+                .filter(|d| d.mode != DelegationMode::DefaultProxy)
+                .any(|d| match for_proposals {
+                    true => d.scope.covers_proposals(),
+                    false => d.scope.covers_temperature_checks(),
+                })
+        }
+
+        /// Returns the weight an account votes with directly on a proposal, and whether it
+        /// delegated its power above the weight cap. That delegated excess is withheld from the
+        /// direct vote, and the direct vote does not replace it.
+        fn direct_vote_weight(&self, account: Global<Account>, proposal_id: u64) -> (Decimal, bool) {
+            let (weight_cap, snapshot) = self
+                .proposals
+                .get(&proposal_id)
+                .map(|p| (p.weight_cap, p.delegation_snapshot))
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+            let power = self.voting_power_at(account, snapshot);
+
+            // Without a cap there is no excess, so the delegations need not be looked up
+            let (cap, vote_delegation) = match (weight_cap, self.vote_delegation) {
+                (Some(cap), Some(vote_delegation)) => (cap, vote_delegation),
+                _ => return (power, false),
+            };
+            let excess_fraction = vote_delegation
+                .get_delegations_at(account, snapshot)
+                .iter()
+                .filter(|d| d.mode == DelegationMode::ExcessAboveCap)
+                .fold(Decimal::ZERO, |total, d| total + d.fraction);
+            if excess_fraction.is_zero() {
+                return (power, false);
+            }
+
+            (power - excess_fraction * Self::excess_above(power, Some(cap)), true)
+        }
+
+        /// Returns the part of a voting power above the weight cap (zero if uncapped)
+        fn excess_above(power: Decimal, weight_cap: Option<Decimal>) -> Decimal {
+            match weight_cap {
+                Some(cap) if power > cap => power - cap,
+                _ => Decimal::ZERO,
+            }
+        }
+
+        /// Panics if `elevation_delay_days` have not passed since a temperature check's deadline
+        fn assert_elevation_delay_elapsed(&self, deadline: Instant) {
+            let elevation_start = deadline
+                .add_days(self.governance_parameters.elevation_delay_days as i64)
+                .unwrap();
+            ensure(
+                Clock::current_time_rounded_to_seconds()
+                    .compare(elevation_start, TimeComparisonOperator::Gte),
+                GovernanceError::ReviewPeriodNotEnded,
+            );
+        }
+
+        /// Adds weight cast with a temperature check vote to its tally (a negative weight removes it)
+        fn tally_temperature_check_vote(
+            tc: &mut TemperatureCheck,
+            vote: &TemperatureCheckVote,
+            weight: Decimal,
+        ) {
+            if vote.is_for() {
+                tc.for_weight += weight;
+            } else {
+                tc.against_weight += weight;
+            }
+            for option in vote.options() {
+                tc.option_weights[option.0 as usize] += weight;
+            }
+        }
+
+        /// Creates a proposal from a temperature check and marks it as elevated
+        /// Returns the ID of the created proposal
+        fn elevate_temperature_check(&mut self, temperature_check_id: u64, two_phase: bool) -> u64 {
+            let eligible_weight = self.eligible_voting_weight();
+
+            // Get the temperature check
+            let mut tc = self
+                .temperature_checks
+                .get_mut(&temperature_check_id)
+                .unwrap_or_else(|| GovernanceError::TemperatureCheckNotFound.raise());
+
+            ensure(
+                tc.elevated_proposal_id.is_none(),
+                GovernanceError::TemperatureCheckAlreadyElevated,
+            );
+
+            let proposal_id = self.proposal_count;
+            self.proposal_count += 1;
+
+            let now = Clock::current_time_rounded_to_seconds();
+            let mut proposal = Self::build_proposal(
+                &tc,
+                temperature_check_id,
+                &self.governance_parameters,
+                eligible_weight,
+                self.total_bloc_weight,
+                now,
+            );
+            if two_phase {
+                Self::add_signal_phase(&mut proposal, &self.governance_parameters, now);
+            }
+
+            tc.elevated_proposal_id = Some(proposal_id);
+            drop(tc);
+
+            let created_event = Self::proposal_created_event(proposal_id, temperature_check_id, &proposal);
+
+            self.proposals.insert(proposal_id, proposal);
+
+            Runtime::emit_event(created_event);
+
+            proposal_id
+        }
+
+        /// Seconds from now until `instant`, or 0 if it has passed
+        fn seconds_until(instant: Instant) -> i64 {
+            let now = Clock::current_time_rounded_to_seconds();
+            (instant.seconds_since_unix_epoch - now.seconds_since_unix_epoch).max(0)
+        }
+
+        /// Voting window opening at `now` and lasting at least `days`, rounded to the configured
+        /// boundary: the start down and the deadline up
+        fn voting_window(params: &GovernanceParameters, now: Instant, days: u16) -> (Instant, Instant) {
+            let rounding = params.deadline_rounding;
+            let deadline = now.add_days(days as i64).unwrap();
+            (rounding.round_down(now), rounding.round_up(deadline))
+        }
+
+        /// Builds the creation event of a proposal, carrying what indexers need to render it
+        fn proposal_created_event(
+            proposal_id: u64,
+            temperature_check_id: u64,
+            proposal: &Proposal,
+        ) -> ProposalCreatedEvent {
+            ProposalCreatedEvent {
+                proposal_id,
+                temperature_check_id,
+                title: proposal.title.clone(),
+                vote_options: proposal.vote_options.clone(),
+                max_selections: proposal.max_selections,
+                voting_mode: proposal.voting_mode,
+                links: proposal.links.clone(),
+                quorum: proposal.quorum,
+                approval_threshold: proposal.approval_threshold,
+                start: proposal.start,
+                deadline: proposal.deadline,
+            }
+        }
+
+        /// Builds a new proposal from a temperature check using the current parameters
+        fn build_proposal(
+            tc: &TemperatureCheck,
+            temperature_check_id: u64,
+            params: &GovernanceParameters,
+            eligible_weight: Decimal,
+            total_bloc_weight: Decimal,
+            now: Instant,
+        ) -> Proposal {
+            let (start, deadline) = Self::voting_window(params, now, params.proposal_length_days);
+            // `Groups` proposals tally bloc weights, so their quorum and eligible weight are too
+            let (quorum, eligible_weight) = match tc.voting_mode {
+                VotingMode::Groups => (params.group_quorum, total_bloc_weight),
+                _ => (params.proposal_quorum, eligible_weight),
+            };
+
+            Proposal {
+                title: tc.title.clone(),
+                short_description: tc.short_description.clone(),
+                description: tc.description.clone(),
+                description_file: tc.description_file.clone(),
+                attachments: tc.attachments.clone(),
+                kind: tc.kind.clone(),
+                action: tc.action.clone(),
+                vote_bond: tc.vote_bond,
+                vote_options: tc.vote_options.clone(),
+                links: tc.links.clone(),
+                quorum,
+                max_selections: tc.max_selections,
+                votes: KeyValueStore::new(),
+                voter_index: KeyValueStore::new(),
+                effective_weights: KeyValueStore::new(),
+                weight_cap: params
+                    .max_weight_per_account
+                    .map(|cap| cap.resolve(eligible_weight)),
+                early_vote_bonus: params.early_vote_bonus,
+                adaptive_approval_threshold: params.adaptive_approval_threshold,
+                early_vote_multipliers: KeyValueStore::new(),
+                delegate_notice_hours: params.delegate_notice_hours,
+                min_balance_to_vote: tc.min_balance_to_vote.or(params.min_balance_to_vote),
+                vote_change_policy: params.vote_change_policy,
+                abstain_option: tc.abstain_option,
+                abstain_quorum_weight: params.abstain_quorum_weight,
+                abstain_only_weight: Decimal::ZERO,
+                intended_votes: KeyValueStore::new(),
+                approval_threshold: params.proposal_approval_threshold,
+                start,
+                deadline,
+                temperature_check_id,
+                reopened_from: None,
+                carry_over_factor: Decimal::ZERO,
+                carried_over_replaced: KeyValueStore::new(),
+                carried_voter_count: 0,
+                carried_option_voter_counts: vec![0; tc.vote_options.len()],
+                carried_option_weights: vec![Decimal::ZERO; tc.vote_options.len()],
+                reopened_as: None,
+                author: tc.author,
+                co_authors: tc.co_authors.clone(),
+                members_only: tc.members_only,
+                anonymous: tc.anonymous,
+                eligibility_root: tc.eligibility_root,
+                allow_rationales: tc.allow_rationales,
+                questions: tc.questions.clone(),
+                question_votes: KeyValueStore::new(),
+                voting_mode: tc.voting_mode,
+                point_allocations: KeyValueStore::new(),
+                group_votes: KeyValueStore::new(),
+                group_tallies: KeyValueStore::new(),
+                allocation: Vec::new(),
+                rationales: KeyValueStore::new(),
+                responses: KeyValueStore::new(),
+                respondents: KeyValueStore::new(),
+                response_count: 0,
+                reactions: KeyValueStore::new(),
+                reaction_counts: vec![0; REACTION_KINDS as usize],
+                last_vote_at: now,
+                delegation_snapshot: now,
+                voter_count: 0,
+                total_weight_cast: Decimal::ZERO,
+                quorum_reached: false,
+                eligible_weight,
+                option_weights: vec![Decimal::ZERO; tc.vote_options.len()],
+                raw_option_weights: vec![Decimal::ZERO; tc.vote_options.len()],
+                raw_total_weight_cast: Decimal::ZERO,
+                option_voter_counts: vec![0; tc.vote_options.len()],
+                status: ProposalStatus::Active,
+                finalized_at: None,
+                delegated_votes: KeyValueStore::new(),
+                delegated_vote_breakdown: KeyValueStore::new(),
+                max_storage_reimbursement: params.max_storage_reimbursement,
+                storage_reimbursed: Decimal::ZERO,
+                executed_at: None,
+                reconfirm_after_days: None,
+                reconfirmation_id: None,
+                reconfirmation_of: None,
+                execution_approvals: Vec::new(),
+                bonds: KeyValueStore::new(),
+                outstanding_bonds: Decimal::ZERO,
+                signal: None,
+                challenge: None,
+                rollback: None,
+            }
+        }
+
+        /// Turns a freshly built proposal into a two-phase one: the signal window opens now and
+        /// the binding window, with the binding quorum, starts when it ends
+        fn add_signal_phase(proposal: &mut Proposal, params: &GovernanceParameters, now: Instant) {
+            let (_, signal_deadline) = Self::voting_window(params, now, params.signal_phase_days);
+            let option_count = proposal.vote_options.len();
+
+            proposal.signal = Some(SignalPhase {
+                deadline: signal_deadline,
+                quorum: proposal.quorum,
+                votes: KeyValueStore::new(),
+                option_weights: vec![Decimal::ZERO; option_count],
+                option_voter_counts: vec![0; option_count],
+                voter_count: 0,
+                total_weight_cast: Decimal::ZERO,
+            });
+            proposal.quorum = params.binding_quorum;
+            proposal.start = signal_deadline;
+            proposal.deadline = signal_deadline
+                .add_days(params.proposal_length_days as i64)
+                .unwrap();
+        }
+
+        /// Returns the carried-over contributions of an account's power that must be removed
+        /// when that power is cast again on a reopened proposal, with their decayed weights.
+        /// Empty if the proposal was not reopened or the contribution was already replaced.
+        fn carried_over_to_replace(
+            &self,
+            proposal_id: u64,
+            account: Global<Account>,
+        ) -> Vec<CarriedOverVote> {
+            let proposal = self
+                .proposals
+                .get(&proposal_id)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+            let origin_id = match proposal.reopened_from {
+                Some(origin_id) => origin_id,
+                None => return Vec::new(),
+            };
+            if proposal.carried_over_replaced.get(&account).is_some() {
+                return Vec::new();
+            }
+            let factor = proposal.carry_over_factor;
+            drop(proposal);
+
+            self.contributions_to_tally(origin_id, account, factor)
+        }
+
+        /// Returns the contributions of an account's power to a proposal's tally, scaled by
+        /// `factor`: its direct vote, its power cast by delegatees and, if the proposal was itself
+        /// reopened, what was carried over into it and not replaced, down the reopen chain
+        fn contributions_to_tally(
+            &self,
+            proposal_id: u64,
+            account: Global<Account>,
+            factor: Decimal,
+        ) -> Vec<CarriedOverVote> {
+            if factor.is_zero() {
+                return Vec::new();
+            }
+            let origin = self.proposals.get(&proposal_id).unwrap();
+            let mut contributions = Vec::new();
+
+            // The account's own direct vote
+            if let Some(record) = origin.votes.get(&account) {
+                let raw_weight = record.weight * factor;
+                contributions.push(CarriedOverVote {
+                    votes: record.votes.clone(),
+                    weight: raw_weight * Self::cap_ratio(&origin, account),
+                    raw_weight,
+                    direct: true,
+                });
+            }
+
+            // The account's power cast by delegatees
+            if let Some(delegated_votes) = origin.delegated_votes.get(&account) {
+                for delegated_vote in delegated_votes.iter() {
+                    let votes = origin
+                        .votes
+                        .get(&delegated_vote.delegatee)
+                        .map(|v| v.votes.clone())
+                        .unwrap_or_default();
+                    let raw_weight = delegated_vote.weight * factor;
+                    contributions.push(CarriedOverVote {
+                        votes,
+                        weight: raw_weight * Self::cap_ratio(&origin, delegated_vote.delegatee),
+                        raw_weight,
+                        direct: false,
+                    });
+                }
+            }
+
+            // What this proposal itself carried over, unless the account replaced it here
+            let earlier = match origin.reopened_from {
+                Some(earlier_id) if origin.carried_over_replaced.get(&account).is_none() => {
+                    Some((earlier_id, factor * origin.carry_over_factor))
+                }
+                _ => None,
+            };
+            drop(origin);
+            if let Some((earlier_id, earlier_factor)) = earlier {
+                contributions.extend(self.contributions_to_tally(
+                    earlier_id,
+                    account,
+                    earlier_factor,
+                ));
+            }
+
+            contributions
+        }
+
+        /// Removes a carried-over contribution from a reopened proposal's tally
+        fn remove_carried_over(proposal: &mut Proposal, contribution: &CarriedOverVote) {
+            Self::subtract_weight(&mut proposal.option_weights, &contribution.votes, contribution.weight);
+            Self::subtract_weight(&mut proposal.raw_option_weights, &contribution.votes, contribution.raw_weight);
+            Self::subtract_weight(&mut proposal.carried_option_weights, &contribution.votes, contribution.weight);
+            proposal.total_weight_cast -= contribution.weight;
+            proposal.raw_total_weight_cast -= contribution.raw_weight;
+            if Self::is_abstain_only(proposal, &contribution.votes) {
+                proposal.abstain_only_weight -= contribution.weight;
+            }
+            if contribution.direct {
+                proposal.carried_voter_count = proposal.carried_voter_count.saturating_sub(1);
+                for vote in contribution.votes.iter() {
+                    let count = &mut proposal.carried_option_voter_counts[vote.0 as usize];
+                    *count = count.saturating_sub(1);
+                }
+            }
+        }
+
+        /// Adds (or, if negative, removes) raw weight to a direct voter's effective weight and
+        /// updates the tally, counting at most the proposal's weight cap for that voter
+        fn add_voter_weight(
+            proposal: &mut Proposal,
+            voter: Global<Account>,
+            votes: &[ProposalVoteOptionId],
+            raw_delta: Decimal,
+        ) {
+            let raw_before = proposal
+                .effective_weights
+                .get(&voter)
+                .map(|w| *w)
+                .unwrap_or(Decimal::ZERO);
+            let raw_after = raw_before + raw_delta;
+            let capped = |raw: Decimal| match proposal.weight_cap {
+                Some(cap) => raw.min(cap),
+                None => raw,
+            };
+            let delta = capped(raw_after) - capped(raw_before);
+
+            proposal.effective_weights.insert(voter, raw_after);
+            let points = proposal.point_allocations.get(&voter).map(|points| points.clone());
+            match points {
+                Some(points) => {
+                    Self::add_points_weight(&mut proposal.option_weights, &points, delta);
+                    Self::add_points_weight(&mut proposal.raw_option_weights, &points, raw_delta);
+                }
+                None => {
+                    Self::add_weight(&mut proposal.option_weights, votes, delta);
+                    Self::add_weight(&mut proposal.raw_option_weights, votes, raw_delta);
+                    if Self::is_abstain_only(proposal, votes) {
+                        proposal.abstain_only_weight += delta;
+                    }
+                }
+            }
+            proposal.total_weight_cast += delta;
+            proposal.raw_total_weight_cast += raw_delta;
+        }
+
+        /// Whether a selection holds only the proposal's abstain option
+        fn is_abstain_only(proposal: &Proposal, votes: &[ProposalVoteOptionId]) -> bool {
+            proposal.abstain_option.is_some_and(|abstain| votes == [abstain])
+        }
+
+        /// Weight counted towards the quorum: the weight cast, with the abstain-only weight
+        /// counted at the proposal's `abstain_quorum_weight`
+        fn quorum_weight(proposal: &Proposal) -> Decimal {
+            proposal.total_weight_cast
+                - proposal.abstain_only_weight
+                    * (Decimal::ONE - proposal.abstain_quorum_weight.factor())
+        }
+
+        /// Emits `QuorumReachedEvent` the first time the weight cast reaches the quorum
+        fn check_quorum_reached(proposal: &mut Proposal, proposal_id: u64, now: Instant) {
+            let quorum_weight = Self::quorum_weight(proposal);
+            if proposal.quorum_reached
+                || !quorum_weight.is_positive()
+                || quorum_weight < proposal.quorum
+            {
+                return;
+            }
+            proposal.quorum_reached = true;
+
+            if !proposal.members_only {
+                Runtime::emit_event(QuorumReachedEvent {
+                    proposal_id,
+                    quorum: proposal.quorum,
+                    total_weight_cast: proposal.total_weight_cast,
+                    reached_at: now,
+                });
+            }
+        }
+
+        /// Returns the tallied weight a direct voter contributed to the winning options of a
+        /// challenged proposal (zero if it did not vote for them)
+        fn winning_contribution(proposal: &Proposal, voter: Global<Account>) -> Decimal {
+            let winning_options = match &proposal.challenge {
+                Some(challenge) => challenge.winning_options.clone(),
+                None => return Decimal::ZERO,
+            };
+            let votes = match proposal.votes.get(&voter) {
+                Some(record) => record.votes.clone(),
+                None => return Decimal::ZERO,
+            };
+            let raw = proposal
+                .effective_weights
+                .get(&voter)
+                .map_or(Decimal::ZERO, |w| *w);
+            let counted = match proposal.weight_cap {
+                Some(cap) => raw.min(cap),
+                None => raw,
+            };
+
+            match proposal.point_allocations.get(&voter) {
+                Some(points) => {
+                    let winning_points = points
+                        .iter()
+                        .filter(|(option, _)| winning_options.contains(option))
+                        .fold(0u32, |total, (_, option_points)| total + option_points);
+                    let total_points = Self::points_total(&points);
+                    counted * Decimal::from(winning_points) / Decimal::from(total_points)
+                }
+                None => {
+                    let winning_votes = votes.iter().filter(|vote| winning_options.contains(vote)).count();
+                    counted * Decimal::from(winning_votes as u64)
+                }
+            }
+        }
+
+        /// Returns when the claim period of a challenge's dispute rewards ends
+        fn dispute_claim_end(challenge: &ResultChallenge) -> Instant {
+            challenge
+                .window_end
+                .add_days(DISPUTE_REWARD_CLAIM_DAYS as i64)
+                .unwrap()
+        }
+
+        /// Slashes the bonds of a failed challenge into its reward pool, once, and returns the
+        /// amount to move into the dispute rewards vault
+        fn slash_challenge_bonds(challenge: &mut ResultChallenge) -> Decimal {
+            if challenge.slashed {
+                return Decimal::ZERO;
+            }
+            challenge.slashed = true;
+            challenge.reward_pool = challenge.outstanding_bonds;
+            challenge.outstanding_bonds = Decimal::ZERO;
+            challenge.reward_pool
+        }
+
+        /// Returns the share of a direct voter's effective weight counted in the tally
+        /// (1 when uncapped or below the cap)
+        fn cap_ratio(proposal: &Proposal, voter: Global<Account>) -> Decimal {
+            let raw = proposal
+                .effective_weights
+                .get(&voter)
+                .map(|w| *w)
+                .unwrap_or(Decimal::ZERO);
+            match proposal.weight_cap {
+                Some(cap) if raw > cap => cap / raw,
+                _ => Decimal::ONE,
+            }
+        }
+
+        /// Returns whether an account was on the member allowlist at the given instant
+        fn was_member_at(&self, account: Global<Account>, at: Instant) -> bool {
+            self.members
+                .get(&account)
+                .map(|membership| {
+                    membership.added_at.compare(at, TimeComparisonOperator::Lte)
+                        && membership
+                            .removed_at
+                            .map_or(true, |removed_at| removed_at.compare(at, TimeComparisonOperator::Gt))
+                })
+                .unwrap_or(false)
+        }
+
+        /// Returns NotAMember if the proposal is members-only and the account was not a member
+        /// when it was created
+        fn membership_error(&self, proposal_id: u64, account: Global<Account>) -> Option<GovernanceError> {
+            let (members_only, snapshot) = self
+                .proposals
+                .get(&proposal_id)
+                .map(|p| (p.members_only, p.delegation_snapshot))
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+
+            if members_only && !self.was_member_at(account, snapshot) {
+                Some(GovernanceError::NotAMember)
+            } else {
+                None
+            }
+        }
+
+        /// Returns BalanceBelowMinimum if the proposal sets a minimum balance to vote and the
+        /// account's voting power is below it
+        fn min_balance_error(&self, proposal_id: u64, account: Global<Account>) -> Option<GovernanceError> {
+            let (min_balance, snapshot) = self
+                .proposals
+                .get(&proposal_id)
+                .map(|p| (p.min_balance_to_vote, p.delegation_snapshot))
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+
+            match min_balance {
+                Some(min_balance) if self.voting_power_at(account, snapshot) < min_balance => {
+                    Some(GovernanceError::BalanceBelowMinimum)
+                }
+                _ => None,
+            }
+        }
+
+        /// Returns AccountTooNew if an enforced account age oracle reports the account was created
+        /// after the proposal's snapshot, or doesn't know the account
+        fn account_age_error(&self, proposal_id: u64, account: Global<Account>) -> Option<GovernanceError> {
+            let oracle = match &self.account_age_config {
+                Some(config) if config.enforced => config.oracle,
+                _ => return None,
+            };
+            let snapshot = self
+                .proposals
+                .get(&proposal_id)
+                .map(|p| p.delegation_snapshot)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise());
+
+            let oracle: Global<AnyComponent> = Global::from(oracle);
+            let created_at: Option<Instant> =
+                oracle.call_raw(ACCOUNT_AGE_ORACLE_METHOD, scrypto_args!(account));
+            match created_at {
+                Some(created_at) if created_at.compare(snapshot, TimeComparisonOperator::Lte) => None,
+                _ => Some(GovernanceError::AccountTooNew),
+            }
+        }
+
+        /// Panics while a members-only proposal is not finalized
+        fn assert_tally_visible(proposal: &Proposal) {
+            ensure(
+                !proposal.members_only || proposal.status != ProposalStatus::Active,
+                GovernanceError::TallyHiddenUntilFinalized,
+            );
+        }
+
+        /// Panics unless the account is the author or a co-author and is present in the transaction
+        fn assert_author(author: Global<Account>, co_authors: &[Global<Account>], account: Global<Account>) {
+            ensure(
+                account == author || co_authors.contains(&account),
+                GovernanceError::NotAnAuthor,
+            );
+            Runtime::assert_access_rule(account.get_owner_role().rule);
+        }
+
+        /// Panics unless the governance parameters are valid
+        fn validate_governance_parameters(params: &GovernanceParameters) {
+            Self::validate_voting_periods(params);
+            // The binding window of two-phase proposals must ask more than their signal window
+            ensure(
+                params.binding_quorum.is_positive() && params.binding_quorum > params.proposal_quorum,
+                GovernanceError::InvalidBindingQuorum,
+            );
+            Self::validate_challenge_threshold(params.challenge_threshold);
+            Self::validate_weight_cap(params.max_weight_per_account);
+            Self::validate_sponsor_threshold(params.temperature_check_sponsor_threshold);
+            Self::validate_early_vote_bonus(params.early_vote_bonus);
+            if let Some(adaptive) = params.adaptive_approval_threshold {
+                ensure(
+                    adaptive.is_valid() && adaptive.threshold <= params.proposal_approval_threshold,
+                    GovernanceError::InvalidAdaptiveApprovalThreshold,
+                );
+            }
+            Self::validate_min_balance(params.min_balance_to_vote);
+            if let Some(fraction) = params.large_vote_fraction {
+                ensure(fraction.is_positive(), GovernanceError::InvalidLargeVoteFraction);
+            }
+            ensure(
+                (params.delegate_notice_hours as u32) < params.proposal_length_days as u32 * 24,
+                GovernanceError::InvalidDelegateNoticePeriod,
+            );
+        }
+
+        /// Panics unless the kind-specific fields of a proposal are valid
+        fn validate_proposal_kind(kind: &ProposalKind) {
+            match kind {
+                ProposalKind::Textual => {}
+                ProposalKind::Funding { amount, recipient } => {
+                    ensure(*amount > Decimal::ZERO, GovernanceError::InvalidFundingAmount);
+                    ensure(
+                        recipient.as_node_id().is_global_account()
+                            && ScryptoVmV1Api::object_get_blueprint_id(recipient.as_node_id())
+                                == BlueprintId::new(&ACCOUNT_PACKAGE, ACCOUNT_BLUEPRINT),
+                        GovernanceError::InvalidFundingRecipient,
+                    );
+                }
+                ProposalKind::ParameterChange { parameters } => {
+                    Self::validate_governance_parameters(parameters)
+                }
+                ProposalKind::Survey => {}
+                ProposalKind::Ranking { temperature_check_ids } => ensure(
+                    temperature_check_ids.len() >= 2
+                        && temperature_check_ids
+                            .iter()
+                            .enumerate()
+                            .all(|(index, id)| !temperature_check_ids[..index].contains(id)),
+                    GovernanceError::InvalidRankingCandidates,
+                ),
+            }
+        }
+
+        /// Panics if the temperature check, proposal or signal phase length is outside its bounds
+        fn validate_voting_periods(params: &GovernanceParameters) {
+            ensure(
+                (MIN_TEMPERATURE_CHECK_DAYS..=MAX_TEMPERATURE_CHECK_DAYS)
+                    .contains(&params.temperature_check_days),
+                GovernanceError::TemperatureCheckLengthOutOfBounds,
+            );
+            ensure(
+                (MIN_PROPOSAL_LENGTH_DAYS..=MAX_PROPOSAL_LENGTH_DAYS)
+                    .contains(&params.proposal_length_days),
+                GovernanceError::ProposalLengthOutOfBounds,
+            );
+            ensure(
+                (MIN_SIGNAL_PHASE_DAYS..=MAX_SIGNAL_PHASE_DAYS).contains(&params.signal_phase_days),
+                GovernanceError::SignalPhaseLengthOutOfBounds,
+            );
+        }
+
+        /// Panics unless the challenge threshold is a fraction between 0 and 1
+        fn validate_challenge_threshold(threshold: Decimal) {
+            ensure(
+                threshold > Decimal::ZERO && threshold <= Decimal::ONE,
+                GovernanceError::InvalidChallengeThreshold,
+            );
+        }
+
+        /// Panics if the sponsor threshold exceeds the number of sponsors a temperature check can have
+        fn validate_sponsor_threshold(threshold: u32) {
+            ensure(threshold <= MAX_SPONSORS, GovernanceError::InvalidSponsorThreshold);
+        }
+
+        /// Panics if a minimum balance to vote is set but not positive
+        fn validate_min_balance(min_balance: Option<Decimal>) {
+            if let Some(min_balance) = min_balance {
+                ensure(min_balance.is_positive(), GovernanceError::InvalidMinBalance);
+            }
+        }
+
+        /// Panics unless the attachments are within the count and total declared size limits,
+        /// have bounded non-empty labels and MIME types, and are stored on trusted storage
+        fn validate_attachments(&self, attachments: &[Attachment]) {
+            ensure(attachments.len() <= MAX_ATTACHMENTS, GovernanceError::TooManyAttachments);
+            let mut total_size: u64 = 0;
+            for attachment in attachments {
+                ensure(
+                    !attachment.label.is_empty()
+                        && attachment.label.chars().count() <= MAX_ATTACHMENT_LABEL_LENGTH
+                        && !attachment.mime_type.is_empty()
+                        && attachment.mime_type.chars().count() <= MAX_MIME_TYPE_LENGTH,
+                    GovernanceError::InvalidAttachment,
+                );
+                ensure(
+                    self.is_trusted_storage(attachment.file.component_address),
+                    GovernanceError::UntrustedStorage,
+                );
+                total_size = total_size.saturating_add(attachment.size_bytes);
+            }
+            ensure(
+                total_size <= MAX_ATTACHMENTS_TOTAL_SIZE,
+                GovernanceError::AttachmentsTooLarge,
+            );
+        }
+
+        /// Panics if an early vote bonus is set but invalid
+        fn validate_early_vote_bonus(bonus: Option<EarlyVoteBonus>) {
+            if let Some(bonus) = bonus {
+                ensure(bonus.is_valid(), GovernanceError::InvalidEarlyVoteBonus);
+            }
+        }
+
+        /// Returns the multiplier a direct vote cast now gets: the early vote bonus while within
+        /// its window fraction of the proposal's voting window, else 1
+        fn early_vote_multiplier(proposal: &Proposal, now: Instant) -> Decimal {
+            proposal.early_vote_bonus.map_or(Decimal::ONE, |bonus| {
+                let start = proposal.start.seconds_since_unix_epoch;
+                let elapsed = Decimal::from(now.seconds_since_unix_epoch - start);
+                let length = Decimal::from(proposal.deadline.seconds_since_unix_epoch - start);
+                if elapsed < bonus.window_fraction * length {
+                    bonus.multiplier
+                } else {
+                    Decimal::ONE
+                }
+            })
+        }
+
+        /// Panics if a weight cap is set but invalid
+        fn validate_weight_cap(cap: Option<WeightCap>) {
+            if let Some(cap) = cap {
+                ensure(cap.is_valid(), GovernanceError::InvalidWeightCap);
+            }
+        }
+
+        /// Locks the fee of a vote from the subsidy vault, up to the per-vote cap, if the subsidy
+        /// is enabled, funded and the account is within its daily limit. The fee is contingent:
+        /// it is only paid if the transaction commits, like the usage count, so transactions
+        /// failing after the vote cannot drain the vault.
+        fn subsidize_vote_fee(&mut self, account: Global<Account>) {
+            let config = match &self.fee_subsidy_config {
+                Some(config) => config.clone(),
+                None => return,
+            };
+            let amount = config.max_fee_per_vote.min(self.fee_subsidy.amount());
+            if amount.is_zero() {
+                return;
+            }
+
+            let now = Clock::current_time_rounded_to_seconds();
+            let usage = match self.fee_subsidy_usage.get(&account).map(|u| u.clone()) {
+                Some(usage) if now.compare(
+                    usage.window_start.add_days(1).unwrap(),
+                    TimeComparisonOperator::Lt,
+                ) => usage,
+                _ => FeeSubsidyUsage { window_start: now, votes: 0 },
+            };
+            if usage.votes >= config.max_subsidized_votes_per_day {
+                return;
+            }
+
+            self.fee_subsidy_usage.insert(
+                account,
+                FeeSubsidyUsage {
+                    window_start: usage.window_start,
+                    votes: usage.votes + 1,
+                },
+            );
+            self.fee_subsidy.as_fungible().lock_contingent_fee(amount);
+        }
+
+        /// Dispatches a proposal action to a registered executor
+        fn call_executor(&mut self, proposal_id: u64, executor_id: u64, args: Vec<u8>) {
+            let component = self
+                .get_executor(executor_id)
+                .unwrap_or_else(|| GovernanceError::ExecutorNotFound.raise());
+            let args: ScryptoValue = scrypto_decode(&args)
+                .unwrap_or_else(|_| GovernanceError::InvalidActionArgs.raise());
+
+            let executor: Global<AnyComponent> = Global::from(component);
+            executor.call_raw::<()>(EXECUTOR_METHOD, scrypto_args!(proposal_id, args));
+
+            Runtime::emit_event(ExecutorCalledEvent {
+                proposal_id,
+                executor_id,
+                component,
+            });
+        }
+
+        /// Returns the vote bond a proposal takes, if any
+        fn vote_bond_of(&self, proposal_id: u64) -> Option<Decimal> {
+            self.proposals
+                .get(&proposal_id)
+                .map(|proposal| proposal.vote_bond)
+                .unwrap_or_else(|| GovernanceError::ProposalNotFound.raise())
+        }
+
+        /// Counts the account as a unique voter the first time it votes
+        fn record_voter(&mut self, account: Global<Account>) {
+            if self.known_voters.get(&account).is_none() {
+                self.known_voters.insert(account, ());
+                self.activity.unique_voters += 1;
+            }
+        }
+
+        /// Panics if the account is on the denylist
+        fn assert_not_denylisted(&self, account: Global<Account>) {
+            ensure(!self.is_denylisted(account), GovernanceError::AccountDenylisted);
+        }
+
+        /// Panics if an identity verifier is configured and does not verify the account
+        fn assert_verified(&self, account: Global<Account>) {
+            ensure(self.is_verified(account), GovernanceError::AccountNotVerified);
+        }
+
+        /// Whether the account passes the identity verifier, true if none is configured
+        fn is_verified(&self, account: Global<Account>) -> bool {
+            match self.identity_verifier {
+                Some(verifier_address) => {
+                    let verifier: Global<AnyComponent> = Global::from(verifier_address);
+                    verifier.call_raw(IDENTITY_VERIFIER_METHOD, scrypto_args!(account))
+                }
+                None => true,
+            }
+        }
+
+        /// Validates a direct vote on a proposal, returning the error it would be rejected with
+        fn proposal_vote_error(
+            proposal: &Proposal,
+            account: Global<Account>,
+            votes: &[ProposalVoteOptionId],
+            now: Instant,
+        ) -> Option<GovernanceError> {
+            // Check the vote is still open
+            if !now.compare(proposal.start, TimeComparisonOperator::Gte) {
+                return Some(GovernanceError::VotingNotStarted);
+            }
+            if !now.compare(proposal.deadline, TimeComparisonOperator::Lt) {
+                return Some(GovernanceError::VotingEnded);
+            }
+
+            // Points allocation votes are validated by `vote_on_proposal_with_points`, which
+            // records the split before the vote is cast
+            match proposal.voting_mode {
+                VotingMode::Selection => {
+                    if let Some(error) =
+                        Self::selection_error(&proposal.vote_options, proposal.max_selections, votes)
+                    {
+                        return Some(error);
+                    }
+                }
+                VotingMode::PointsAllocation => {
+                    if proposal.point_allocations.get(&account).is_none() {
+                        return Some(GovernanceError::WrongVotingMode);
+                    }
+                }
+                // Group members vote with `vote_in_group`, which does not weigh accounts
+                VotingMode::Groups => return Some(GovernanceError::WrongVotingMode),
+            }
+
+            // Check the account has not already voted
+            if proposal.votes.get(&account).is_some() {
+                return Some(GovernanceError::AlreadyVotedOnProposal);
+            }
+
+            None
+        }
+
+        /// Panics unless the vote options have unique, valid labels and colors within the limits,
+        /// and `max_selections` is within the limits and the number of options
+        fn validate_vote_options(
+            options: &[ProposalVoteOptionInput],
+            max_selections: Option<u32>,
+            limits: &GovernanceLimits,
+        ) {
+            ensure(!options.is_empty(), GovernanceError::NoVoteOptions);
+            ensure(
+                options.len() <= limits.max_vote_options as usize,
+                GovernanceError::TooManyVoteOptions { max: limits.max_vote_options },
+            );
+
+            // Validate vote option labels and colors are unique
+            let mut seen_colors: Vec<VoteOptionColor> = Vec::new();
+            let mut seen_labels: Vec<&str> = Vec::new();
+            for option in options {
+                ensure(!option.label.is_empty(), GovernanceError::EmptyOptionLabel);
+                ensure(
+                    option.label.chars().count() <= MAX_OPTION_LABEL_LENGTH,
+                    GovernanceError::OptionLabelTooLong,
+                );
+                ensure(
+                    !seen_labels.contains(&option.label.as_str()),
+                    GovernanceError::DuplicateOptionLabel,
+                );
+                seen_labels.push(option.label.as_str());
+                ensure(
+                    !seen_colors.contains(&option.color),
+                    GovernanceError::DuplicateVoteOptionColor,
+                );
+                seen_colors.push(option.color);
+            }
+
+            // Validate max_selections
+            if let Some(n) = max_selections {
+                ensure(n > 0, GovernanceError::ZeroMaxSelections);
+                ensure(
+                    n <= limits.max_selections,
+                    GovernanceError::MaxSelectionsTooHigh { max: limits.max_selections },
+                );
+                ensure(
+                    (n as usize) <= options.len(),
+                    GovernanceError::MaxSelectionsExceedsOptions,
+                );
+            }
+        }
+
+        /// Auto-generates IDs for vote options (0, 1, 2, ...)
+        fn assign_option_ids(options: Vec<ProposalVoteOptionInput>) -> Vec<ProposalVoteOption> {
+            options
+                .into_iter()
+                .enumerate()
+                .map(|(index, input)| ProposalVoteOption {
+                    id: ProposalVoteOptionId(index as u32),
+                    label: input.label,
+                    color: input.color,
+                })
+                .collect()
+        }
+
+        /// Returns why a selection of options is invalid for the given vote options and
+        /// `max_selections`, None if it is valid
+        fn selection_error(
+            vote_options: &[ProposalVoteOption],
+            max_selections: Option<u32>,
+            votes: &[ProposalVoteOptionId],
+        ) -> Option<GovernanceError> {
+            // Validate vote count based on max_selections
+            if votes.is_empty() {
+                return Some(GovernanceError::NoOptionSelected);
+            }
+            match max_selections {
+                None => {
+                    // Single choice: exactly one vote
+                    if votes.len() != 1 {
+                        return Some(GovernanceError::SingleChoiceOnly);
+                    }
+                }
+                Some(max) => {
+                    // Multiple choice: up to max votes
+                    if votes.len() > max as usize {
+                        return Some(GovernanceError::TooManySelections { max });
+                    }
+                }
+            }
+
+            // Check for duplicate selections
+            for (index, vote) in votes.iter().enumerate() {
+                if votes[..index].contains(vote) {
+                    return Some(GovernanceError::DuplicateVoteOption);
+                }
+            }
+
+            // Validate all vote options exist
+            for vote in votes {
+                if !vote_options.iter().any(|opt| opt.id == *vote) {
+                    return Some(GovernanceError::InvalidVoteOption);
+                }
+            }
+
+            None
+        }
+
+        /// Computes the participation figures of a proposal
+        fn turnout_of(proposal: &Proposal) -> ProposalTurnout {
+            let turnout_fraction = if proposal.eligible_weight > Decimal::ZERO {
+                proposal.total_weight_cast / proposal.eligible_weight
+            } else {
+                Decimal::ZERO
+            };
+
+            ProposalTurnout {
+                voter_count: proposal.voter_count + proposal.carried_voter_count,
+                total_weight_cast: proposal.total_weight_cast,
+                eligible_weight: proposal.eligible_weight,
+                turnout_fraction,
+            }
+        }
+
+        /// Computes the tally of a proposal from its running totals
+        /// The approval threshold is met when the leading option holds at least
+        /// `approval_threshold` of the weight cast, lowered by turnout if the proposal has an
+        /// adaptive approval threshold
+        fn tally_of(proposal: &Proposal) -> ProposalTally {
+            let options: Vec<OptionTally> = proposal
+                .vote_options
+                .iter()
+                .map(|option| OptionTally {
+                    option_id: option.id,
+                    weight: proposal.option_weights[option.id.0 as usize],
+                    raw_weight: proposal.raw_option_weights[option.id.0 as usize],
+                    voter_count: proposal.option_voter_counts[option.id.0 as usize]
+                        + proposal.carried_option_voter_counts[option.id.0 as usize],
+                })
+                .collect();
+
+            let (leading_weight, winning_options) = Self::leading_options(&options);
+
+            let quorum_weight = Self::quorum_weight(proposal);
+            let quorum_met = quorum_weight.is_positive() && quorum_weight >= proposal.quorum;
+            let approval_threshold = proposal.adaptive_approval_threshold.map_or(
+                proposal.approval_threshold,
+                |adaptive| {
+                    adaptive.threshold_at(
+                        proposal.approval_threshold,
+                        proposal.quorum,
+                        proposal.total_weight_cast,
+                    )
+                },
+            );
+            let threshold_met = proposal.total_weight_cast.is_positive()
+                && leading_weight / proposal.total_weight_cast >= approval_threshold;
+
+            ProposalTally {
+                options,
+                quorum_weight,
+                quorum_met,
+                threshold_met,
+                approval_threshold,
+                winning_options,
+                turnout: Self::turnout_of(proposal),
+                weight_cap: proposal.weight_cap,
+                raw_total_weight_cast: proposal.raw_total_weight_cast,
+            }
+        }
+
+        /// Fixes the result of an extra ballot question: it passes when its weight cast reaches
+        /// the proposal's quorum and its leading option holds the question's approval threshold
+        fn finalize_question(question: &mut BallotQuestion, quorum: Decimal) {
+            let options: Vec<OptionTally> = question
+                .vote_options
+                .iter()
+                .map(|option| OptionTally {
+                    option_id: option.id,
+                    weight: question.option_weights[option.id.0 as usize],
+                    raw_weight: question.option_weights[option.id.0 as usize],
+                    voter_count: question.option_voter_counts[option.id.0 as usize],
+                })
+                .collect();
+            let (leading_weight, winning_options) = Self::leading_options(&options);
+
+            let total = question.total_weight_cast;
+            let passed = total.is_positive()
+                && total >= quorum
+                && leading_weight / total >= question.approval_threshold;
+            question.status = if passed { ProposalStatus::Passed } else { ProposalStatus::Rejected };
+            question.winning_options = winning_options;
+        }
+
+        /// Computes the tally of a two-phase proposal's signal window
+        fn signal_tally_of(proposal: &Proposal, signal: &SignalPhase) -> ProposalTally {
+            let options: Vec<OptionTally> = proposal
+                .vote_options
+                .iter()
+                .map(|option| OptionTally {
+                    option_id: option.id,
+                    weight: signal.option_weights[option.id.0 as usize],
+                    raw_weight: signal.option_weights[option.id.0 as usize],
+                    voter_count: signal.option_voter_counts[option.id.0 as usize],
+                })
+                .collect();
+            let (leading_weight, winning_options) = Self::leading_options(&options);
+
+            let total = signal.total_weight_cast;
+            let turnout_fraction = if proposal.eligible_weight > Decimal::ZERO {
+                total / proposal.eligible_weight
+            } else {
+                Decimal::ZERO
+            };
+
+            ProposalTally {
+                options,
+                quorum_weight: total,
+                quorum_met: total.is_positive() && total >= signal.quorum,
+                threshold_met: total.is_positive()
+                    && leading_weight / total >= proposal.approval_threshold,
+                approval_threshold: proposal.approval_threshold,
+                winning_options,
+                turnout: ProposalTurnout {
+                    voter_count: signal.voter_count,
+                    total_weight_cast: total,
+                    eligible_weight: proposal.eligible_weight,
+                    turnout_fraction,
+                },
+                weight_cap: None,
+                raw_total_weight_cast: total,
+            }
+        }
+
+        /// Returns the highest option weight and the option(s) holding it
+        /// (several on a tie, none if nothing was cast)
+        fn leading_options(options: &[OptionTally]) -> (Decimal, Vec<ProposalVoteOptionId>) {
+            let leading_weight = options
+                .iter()
+                .map(|o| o.weight)
+                .max()
+                .unwrap_or(Decimal::ZERO);
+            let winning_options: Vec<ProposalVoteOptionId> = if leading_weight.is_positive() {
+                options
+                    .iter()
+                    .filter(|o| o.weight == leading_weight)
+                    .map(|o| o.option_id)
+                    .collect()
+            } else {
+                Vec::new()
+            };
+
+            (leading_weight, winning_options)
+        }
+
+        /// Adds weight to every selected option
+        fn add_weight(option_weights: &mut [Decimal], votes: &[ProposalVoteOptionId], weight: Decimal) {
+            for vote in votes {
+                option_weights[vote.0 as usize] += weight;
+            }
+        }
+
+        /// Returns the option most group members selected, None while tied or without votes
+        fn group_majority(option_votes: &[u64]) -> Option<ProposalVoteOptionId> {
+            let most = option_votes.iter().copied().max().unwrap_or(0);
+            let mut leaders = option_votes.iter().enumerate().filter(|(_, votes)| **votes == most);
+            match (leaders.next(), leaders.next()) {
+                (Some((index, _)), None) if most > 0 => Some(ProposalVoteOptionId(index as u32)),
+                _ => None,
+            }
+        }
+
+        /// Panics unless a voting group has a bounded, non-empty name and a positive bloc weight
+        fn validate_voting_group(name: &str, bloc_weight: Decimal) {
+            ensure(
+                !name.trim().is_empty()
+                    && name.chars().count() <= MAX_TITLE_LENGTH
+                    && bloc_weight > Decimal::ZERO,
+                GovernanceError::InvalidVotingGroup,
+            );
+        }
+
+        /// Adds weight to every option in proportion to the points it was given
+        fn add_points_weight(
+            option_weights: &mut [Decimal],
+            points: &[(ProposalVoteOptionId, u32)],
+            weight: Decimal,
+        ) {
+            let total = Decimal::from(Self::points_total(points));
+            for (option, option_points) in points {
+                option_weights[option.0 as usize] += weight * Decimal::from(*option_points) / total;
+            }
+        }
+
+        /// Returns the points of a split: `POINTS_PER_VOTER` for points allocations, the Borda
+        /// points of a full ordering for rankings
+        fn points_total(points: &[(ProposalVoteOptionId, u32)]) -> u32 {
+            points.iter().fold(0, |total, (_, option_points)| total + option_points)
+        }
+
+        /// Removes weight from every selected option
+        fn subtract_weight(option_weights: &mut [Decimal], votes: &[ProposalVoteOptionId], weight: Decimal) {
+            for vote in votes {
+                option_weights[vote.0 as usize] -= weight;
+            }
+        }
+
+        /// Returns the total voting weight in existence: the weighted total supplies
+        fn eligible_voting_weight(&self) -> Decimal {
+            self.voting_power_config
+                .resource_weights
+                .iter()
+                .fold(Decimal::ZERO, |total, (resource, weight)| {
+                    let supply = ResourceManager::from(*resource)
+                        .total_supply()
+                        .unwrap_or(Decimal::ZERO);
+                    total + supply * *weight
+                })
         }
     }
 }
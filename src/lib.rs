@@ -1,17 +1,42 @@
 use scrypto::prelude::*;
+use std::fmt;
 
 pub mod governance;
 pub mod vote_delegation;
+pub mod balance_checkpoints;
+#[cfg(feature = "manifests")]
+pub mod manifests;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
 
 // =============================================================================
 // Shared Types
 // =============================================================================
 
-/// Vote option for temperature checks (simple for/against)
-#[derive(ScryptoSbor, ManifestSbor, Clone, Copy, Debug, PartialEq, Eq)]
+/// Vote on a temperature check
+/// `For` and `Against` are the binary votes. `Options` supports the temperature check while
+/// signaling a preference among the vote options of the future proposal, selected with the
+/// same option IDs and `max_selections` rules as proposal votes.
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
 pub enum TemperatureCheckVote {
     For,
     Against,
+    Options(Vec<ProposalVoteOptionId>),
+}
+
+impl TemperatureCheckVote {
+    /// Whether the vote counts toward the For weight
+    pub fn is_for(&self) -> bool {
+        !matches!(self, TemperatureCheckVote::Against)
+    }
+
+    /// The options the vote signals a preference for, empty for binary votes
+    pub fn options(&self) -> &[ProposalVoteOptionId] {
+        match self {
+            TemperatureCheckVote::Options(options) => options,
+            _ => &[],
+        }
+    }
 }
 
 /// Available colors for vote options
@@ -40,6 +65,49 @@ pub struct ProposalVoteOptionInput {
     pub color: VoteOptionColor,
 }
 
+/// Input for an extra question of a multi-question ballot
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug)]
+pub struct BallotQuestionInput {
+    pub label: String,
+    /// Vote options with labels and colors (IDs are auto-generated per question)
+    pub vote_options: Vec<ProposalVoteOptionInput>,
+    /// Same rules as the draft's `max_selections`
+    pub max_selections: Option<u32>,
+    /// Fraction of the question's weight cast its leading option needs for the question to pass
+    pub approval_threshold: Decimal,
+}
+
+/// An extra question of a multi-question ballot, voted with `vote_on_ballot` and tallied and
+/// finalized on its own. The proposal's own vote options form the main question (ID 0).
+#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+pub struct BallotQuestion {
+    /// 1 for the first extra question, 2 for the next, ...
+    pub id: u32,
+    pub label: String,
+    pub vote_options: Vec<ProposalVoteOption>,
+    pub max_selections: Option<u32>,
+    pub approval_threshold: Decimal,
+    /// Weight cast per option, indexed by option ID
+    pub option_weights: Vec<Decimal>,
+    pub option_voter_counts: Vec<u64>,
+    pub total_weight_cast: Decimal,
+    pub voter_count: u64,
+    /// Active until the proposal is finalized, then Passed or Rejected
+    pub status: ProposalStatus,
+    /// Option(s) with the highest weight at finalization (several on a tie)
+    pub winning_options: Vec<ProposalVoteOptionId>,
+}
+
+impl BallotQuestion {
+    /// Zeroes the running tally, for private consultations until they are finalized
+    pub fn hide_tally(&mut self) {
+        self.option_weights = vec![Decimal::ZERO; self.option_weights.len()];
+        self.option_voter_counts = vec![0; self.option_voter_counts.len()];
+        self.total_weight_cast = Decimal::ZERO;
+        self.voter_count = 0;
+    }
+}
+
 /// A vote option for proposals (e.g., "For", "Against", "Abstain")
 /// The ID is auto-generated based on the order of options (0, 1, 2, ...)
 #[derive(ScryptoSbor, ManifestSbor, Clone, Debug)]
@@ -49,12 +117,175 @@ pub struct ProposalVoteOption {
     pub color: VoteOptionColor,
 }
 
-/// Maximum number of links per temperature check / proposal
-pub const MAX_LINKS: usize = 10;
-/// Maximum number of vote options per proposal
-pub const MAX_VOTE_OPTIONS: usize = 10;
-/// Maximum number of selections in a multiple-choice vote
-pub const MAX_SELECTIONS: u32 = 5;
+/// Reference to a file stored with radix-file-storage
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub struct File {
+    pub kvs_address: String,
+    pub component_address: ComponentAddress,
+    pub file_hash: String,
+}
+
+/// Data of an authorized signer badge, which lets its holder vote on temperature checks on behalf
+/// of `account` until the account revokes it
+#[derive(ScryptoSbor, NonFungibleData)]
+pub struct AuthorizedSignerBadge {
+    pub account: Global<Account>,
+}
+
+/// Data of a storage receipt, issued for attachment storage fees paid for a proposal
+#[derive(ScryptoSbor, ManifestSbor, NonFungibleData, Clone, Debug, PartialEq, Eq)]
+pub struct StorageReceipt {
+    pub proposal_id: u64,
+    /// XRD paid for storing the attachments
+    pub amount: Decimal,
+}
+
+/// Reasoning a voter attaches to a proposal vote
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub enum VoteRationale {
+    /// Short inline text (at most `MAX_RATIONALE_LENGTH` characters)
+    Text(String),
+    /// Longer rationale stored with radix-file-storage, on a trusted storage component
+    File(File),
+}
+
+/// Response to a survey proposal
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub enum SurveyResponse {
+    /// Inline text (at most `MAX_SURVEY_RESPONSE_LENGTH` characters)
+    Text(String),
+    /// Longer response stored with radix-file-storage, on a trusted storage component
+    File(File),
+}
+
+/// Temperature check of a ranking proposal with its weighted Borda count
+#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+pub struct RankedTemperatureCheck {
+    pub temperature_check_id: u64,
+    pub borda_score: Decimal,
+}
+
+/// A survey response as stored, listable once the survey's deadline has passed
+#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+pub struct SurveyResponseRecord {
+    pub account: Global<Account>,
+    pub response: SurveyResponse,
+    pub submitted_at: Instant,
+}
+
+/// Full description kept off the component state, committed to by its content hash
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub struct DescriptionFile {
+    pub file: File,
+    /// Blake2b hash of the description content
+    pub content_hash: Hash,
+}
+
+/// File attached to a temperature check / proposal, with metadata declared by the author so
+/// front-ends can list attachments without fetching them (not verified against the file)
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub struct Attachment {
+    pub file: File,
+    /// Display name (at most `MAX_ATTACHMENT_LABEL_LENGTH` characters)
+    pub label: String,
+    /// MIME type, e.g. "application/pdf" (at most `MAX_MIME_TYPE_LENGTH` characters)
+    pub mime_type: String,
+    /// Size of the file content in bytes
+    pub size_bytes: u64,
+}
+
+/// Leaf of an eligibility allowlist Merkle tree: the hash of the SBOR-encoded `(account, weight)`
+pub fn eligibility_leaf(account: ComponentAddress, weight: Decimal) -> Hash {
+    hash(scrypto_encode(&(account, weight)).unwrap())
+}
+
+/// Parent node of two Merkle tree nodes, hashed in sorted order so proofs need no positions
+pub fn merkle_parent(a: Hash, b: Hash) -> Hash {
+    let (first, second) = if a.0 <= b.0 { (a, b) } else { (b, a) };
+    hash([first.0, second.0].concat())
+}
+
+/// Whether `proof` (the sibling nodes from the leaf up) links `leaf` to `root`
+pub fn verify_merkle_proof(root: Hash, leaf: Hash, proof: &[Hash]) -> bool {
+    proof.len() <= MAX_MERKLE_PROOF_LENGTH
+        && proof.iter().fold(leaf, |node, sibling| merkle_parent(node, *sibling)) == root
+}
+
+/// Hard maximum of the owner-configurable `GovernanceLimits::max_links`
+pub const MAX_LINKS: u32 = 25;
+/// Hard maximum of the owner-configurable `GovernanceLimits::max_vote_options`
+pub const MAX_VOTE_OPTIONS: u32 = 20;
+/// Hard maximum of the owner-configurable `GovernanceLimits::max_selections`
+pub const MAX_SELECTIONS: u32 = 10;
+/// Maximum length (in characters) of a temperature check / proposal title
+pub const MAX_TITLE_LENGTH: usize = 200;
+/// Maximum length (in characters) of a draft's external reference
+pub const MAX_EXTERNAL_REF_LENGTH: usize = 100;
+/// Maximum number of attachments per temperature check / proposal
+pub const MAX_ATTACHMENTS: usize = 10;
+/// Maximum length (in characters) of an attachment label
+pub const MAX_ATTACHMENT_LABEL_LENGTH: usize = 100;
+/// Maximum length (in characters) of an attachment MIME type
+pub const MAX_MIME_TYPE_LENGTH: usize = 100;
+/// Maximum declared size (in bytes) of all attachments of a temperature check / proposal (50 MiB)
+pub const MAX_ATTACHMENTS_TOTAL_SIZE: u64 = 50 * 1024 * 1024;
+/// Maximum length (in characters) of a short description
+pub const MAX_SHORT_DESCRIPTION_LENGTH: usize = 500;
+/// Maximum weight multiplier of early proposal votes (1.5x)
+pub const MAX_EARLY_VOTE_MULTIPLIER: &str = "1.5";
+/// Maximum length (in characters) of an inline vote rationale
+pub const MAX_RATIONALE_LENGTH: usize = 500;
+/// Maximum length (in characters) of an inline survey response
+pub const MAX_SURVEY_RESPONSE_LENGTH: usize = 2000;
+/// Number of proposal reaction kinds (0 to 7), mapped to emojis by front-ends
+pub const REACTION_KINDS: u8 = 8;
+/// Maximum length (in characters) of a vote option label
+pub const MAX_OPTION_LABEL_LENGTH: usize = 50;
+/// Only links with this scheme are accepted
+pub const REQUIRED_LINK_SCHEME: &str = "https://";
+/// Maximum number of entries kept in the governance parameter change log
+pub const MAX_PARAMETER_HISTORY: usize = 50;
+/// Maximum number of resources counted towards voting power
+pub const MAX_VOTING_POWER_RESOURCES: usize = 10;
+/// Maximum number of co-authors per temperature check / proposal
+pub const MAX_CO_AUTHORS: usize = 5;
+/// Maximum number of extra ballot questions per temperature check / proposal
+pub const MAX_EXTRA_QUESTIONS: usize = 5;
+/// Points each voter distributes across the options of a `PointsAllocation` proposal
+pub const POINTS_PER_VOTER: u32 = 100;
+/// Maximum number of co-sponsors per temperature check, and of `temperature_check_sponsor_threshold`
+pub const MAX_SPONSORS: u32 = 100;
+/// Maximum depth of an eligibility allowlist Merkle proof (trees of up to 2^32 accounts)
+pub const MAX_MERKLE_PROOF_LENGTH: usize = 32;
+/// Bounds of `temperature_check_days`, so a captured owner cannot rush or stall temperature checks
+pub const MIN_TEMPERATURE_CHECK_DAYS: u16 = 1;
+pub const MAX_TEMPERATURE_CHECK_DAYS: u16 = 30;
+/// Bounds of `proposal_length_days`, so a captured owner cannot rush or stall proposals
+pub const MIN_PROPOSAL_LENGTH_DAYS: u16 = 3;
+pub const MAX_PROPOSAL_LENGTH_DAYS: u16 = 60;
+/// Bounds of `signal_phase_days`, so the signal window of two-phase proposals is never empty
+pub const MIN_SIGNAL_PHASE_DAYS: u16 = 1;
+pub const MAX_SIGNAL_PHASE_DAYS: u16 = 30;
+/// Days after a failed challenge's window during which winning voters can claim dispute rewards;
+/// the owner can sweep whatever is left afterwards
+pub const DISPUTE_REWARD_CLAIM_DAYS: u16 = 30;
+
+/// Method called on a registered executor component: `execute(proposal_id: u64, args: ScryptoValue)`
+pub const EXECUTOR_METHOD: &str = "execute";
+
+/// Method called on the configured identity verifier component: `is_verified(Global<Account>) -> bool`
+pub const IDENTITY_VERIFIER_METHOD: &str = "is_verified";
+
+/// Method called on the account age oracle of `AccountAgeConfig`:
+/// `get_account_created_at(account: Global<Account>) -> Option<Instant>` (None for accounts it doesn't know)
+pub const ACCOUNT_AGE_ORACLE_METHOD: &str = "get_account_created_at";
+
+/// Method called on the balance history component of `VotingPowerMode::AverageBalance`:
+/// `get_average_balance(account: Global<Account>, resource: ResourceAddress, from: Instant, to: Instant) -> Decimal`
+/// (implemented by the `BalanceCheckpoints` reference blueprint)
+pub const BALANCE_HISTORY_METHOD: &str = "get_average_balance";
+/// Maximum number of days balances can be averaged over
+pub const MAX_BALANCE_AVERAGING_DAYS: u16 = 90;
 
 // =============================================================================
 // Delegation Constants
@@ -64,6 +295,1031 @@ pub const MAX_SELECTIONS: u32 = 5;
 pub const MAX_DELEGATIONS: usize = 50;
 /// Minimum delegation fraction (1% = 0.01)
 pub const MIN_DELEGATION_FRACTION: &str = "0.01";
+/// Maximum number of historical delegation checkpoints kept per delegator
+pub const MAX_DELEGATION_CHECKPOINTS: usize = 20;
+/// Maximum number of incoming power checkpoints kept per delegatee (one per epoch with changes)
+pub const MAX_INCOMING_POWER_CHECKPOINTS: usize = 100;
+/// Maximum number of balance checkpoints kept per account and resource by `BalanceCheckpoints`
+pub const MAX_BALANCE_CHECKPOINTS: usize = 100;
+
+// =============================================================================
+// Errors
+// =============================================================================
+
+/// Aborts the transaction with `error` unless `condition` holds
+pub fn ensure(condition: bool, error: impl fmt::Display) {
+    if !condition {
+        Runtime::panic(error.to_string());
+    }
+}
+
+/// Errors raised by the Governance blueprint
+/// Transactions abort with `[<code>] <message>`, e.g. `[GOV-002] Proposal not found`
+#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+pub enum GovernanceError {
+    TemperatureCheckNotFound,
+    ProposalNotFound,
+    EmptyTitle,
+    EmptyShortDescription,
+    EmptyDescription,
+    NoVoteOptions,
+    TooManyVoteOptions { max: u32 },
+    TooManyLinks { max: u32 },
+    DuplicateVoteOptionColor,
+    ZeroMaxSelections,
+    MaxSelectionsTooHigh { max: u32 },
+    MaxSelectionsExceedsOptions,
+    VotingNotStarted,
+    VotingEnded,
+    VotingNotEnded,
+    TemperatureCheckAlreadyFinalized,
+    NotAutoElevated,
+    TemperatureCheckNotPassed,
+    ReviewPeriodNotEnded,
+    TemperatureCheckAlreadyElevated,
+    AlreadyVotedOnTemperatureCheck,
+    NoOptionSelected,
+    SingleChoiceOnly,
+    TooManySelections { max: u32 },
+    DuplicateVoteOption,
+    InvalidVoteOption,
+    AlreadyVotedOnProposal,
+    ProposalAlreadyFinalized,
+    ProposalNotFinalized,
+    InvalidCarryOverFactor,
+    ProposalNotPassed,
+    ProposalAlreadyExecuted,
+    InvalidReconfirmationDelay,
+    DecisionNotStanding,
+    ProposalNotExecuted,
+    NoReconfirmationDelay,
+    ReconfirmationAlreadySpawned,
+    ReconfirmationNotDue,
+    NoDelegators,
+    DuplicateDelegator,
+    NoDelegationAtSnapshot,
+    DelegateeHasNotVoted,
+    DelegatorVotedDirectly,
+    DelegatedPowerAlreadyCast,
+    VoteDelegationNotConfigured,
+    AccountDenylisted,
+    AccountAlreadyDenylisted,
+    AccountNotDenylisted,
+    AccountNotVerified,
+    StorageReimbursementsDisabled,
+    NonPositiveAmount,
+    ReimbursementCapExceeded,
+    InvalidStorageReceipt,
+    NoVotingPowerResources,
+    TooManyVotingPowerResources,
+    NonPositiveResourceWeight,
+    DuplicateVotingPowerResource,
+    EmptyDaoName,
+    NonPositiveFeeCap,
+    NoSubsidizedVotes,
+    TitleTooLong,
+    ShortDescriptionTooLong,
+    DescriptionTooLong { max: u32 },
+    EmptyOptionLabel,
+    OptionLabelTooLong,
+    DuplicateOptionLabel,
+    InsecureLink,
+    AlreadySeconded,
+    ProposeThresholdNotReached,
+    ExecutionApprovalsNotConfigured,
+    InvalidExecutorBadge,
+    ExecutionAlreadyApproved,
+    InsufficientExecutionApprovals { required: u32 },
+    InvalidApprovalCount,
+    ExecutorNotFound,
+    InvalidActionArgs,
+    VoteBondRequired,
+    NoVoteBondRequired,
+    InsufficientVoteBond,
+    InvalidVoteBond,
+    VoteBondLocked,
+    NoVoteBond,
+    ArchiveRetentionNotElapsed,
+    ReconfirmationPending,
+    UnreclaimedVoteBonds,
+    ArchivedProposalNotFound,
+    InvalidWeightCap,
+    TooManyCoAuthors,
+    DuplicateCoAuthor,
+    NotAnAuthor,
+    NotAMember,
+    AlreadyAMember,
+    TallyHiddenUntilFinalized,
+    NotTwoPhase,
+    AlreadyVotedOnSignal,
+    TemperatureCheckLengthOutOfBounds,
+    ProposalLengthOutOfBounds,
+    InvalidChallengeThreshold,
+    ChallengeWindowClosed,
+    ChallengeWindowOpen,
+    AlreadyChallenged,
+    InsufficientChallengeBond,
+    NoChallengeBond,
+    UnreclaimedChallengeBonds,
+    AnonymousDelegatedVote,
+    EligibilityProofRequired,
+    NoEligibilityAllowlist,
+    InvalidEligibilityProof,
+    DefaultProxyNotApplicable,
+    NotVoteDelegationBlueprint,
+    VoteDelegationNotLinked,
+    InvalidGovernanceLimits,
+    InvalidSponsorThreshold,
+    AlreadySponsored,
+    TooManySponsors,
+    UntrustedStorage,
+    StorageAlreadyTrusted,
+    StorageNotTrusted,
+    RationalesDisabled,
+    EmptyRationale,
+    RationaleTooLong,
+    InvalidFundingAmount,
+    InvalidFundingRecipient,
+    NotAuthorizedSigner,
+    SignerBadgeNotFound,
+    InvalidEarlyVoteBonus,
+    TooManyQuestions,
+    InvalidQuestion,
+    QuestionNotFound,
+    DuplicateQuestionAnswer,
+    MainQuestionUnanswered,
+    WrongVotingMode,
+    InvalidPointsAllocation,
+    IntendedVoteNotAnnounced,
+    DelegateNoticePeriodNotElapsed,
+    VoteDiffersFromIntendedVote,
+    InvalidDelegateNoticePeriod,
+    BalanceBelowMinimum,
+    InvalidMinBalance,
+    InvalidBalanceAveraging,
+    NotASurvey,
+    InvalidSurveyResponse,
+    AlreadyResponded,
+    SurveyResponsesHidden,
+    InvalidReaction,
+    AlreadyReacted,
+    AccountTooNew,
+    VoteChangeNotAllowed,
+    VoteChangeAfterQuorum,
+    VoteNotFound,
+    ChallengeBondSlashed,
+    NoDisputeReward,
+    DisputeRewardAlreadyClaimed,
+    InvalidExternalRef,
+    DuplicateExternalRef,
+    TooManyAttachments,
+    InvalidAttachment,
+    AttachmentsTooLarge,
+    InvalidLargeVoteFraction,
+    InvalidAdaptiveApprovalThreshold,
+    NotRollbackable,
+    RollbackWindowClosed,
+    AlreadyRolledBack,
+    InvalidAbstainOption,
+    InvalidRankingCandidates,
+    InvalidRanking,
+    NotARanking,
+    InvalidVotingGroup,
+    VotingGroupNotFound,
+    AlreadyInVotingGroup,
+    NotInVotingGroup,
+    ReimbursementExceedsReceipt,
+    StorageReceiptAlreadyClaimed,
+    AlreadyReopened,
+    ParametersChangedSinceExecution,
+    VoteCarriesDelegatedPower,
+    DisputeRewardClaimPeriodEnded,
+    DisputeRewardClaimPeriodOpen,
+    JoinedVotingGroupAfterSnapshot,
+    InvalidBindingQuorum,
+    SignalPhaseLengthOutOfBounds,
+}
+
+impl GovernanceError {
+    /// Stable code of the error, never reused for a different error
+    pub fn code(&self) -> &'static str {
+        match self {
+            GovernanceError::TemperatureCheckNotFound => "GOV-001",
+            GovernanceError::ProposalNotFound => "GOV-002",
+            GovernanceError::EmptyTitle => "GOV-003",
+            GovernanceError::EmptyShortDescription => "GOV-004",
+            GovernanceError::EmptyDescription => "GOV-005",
+            GovernanceError::NoVoteOptions => "GOV-006",
+            GovernanceError::TooManyVoteOptions { .. } => "GOV-007",
+            GovernanceError::TooManyLinks { .. } => "GOV-008",
+            GovernanceError::DuplicateVoteOptionColor => "GOV-009",
+            GovernanceError::ZeroMaxSelections => "GOV-010",
+            GovernanceError::MaxSelectionsTooHigh { .. } => "GOV-011",
+            GovernanceError::MaxSelectionsExceedsOptions => "GOV-012",
+            GovernanceError::VotingNotStarted => "GOV-013",
+            GovernanceError::VotingEnded => "GOV-014",
+            GovernanceError::VotingNotEnded => "GOV-015",
+            GovernanceError::TemperatureCheckAlreadyFinalized => "GOV-016",
+            GovernanceError::NotAutoElevated => "GOV-017",
+            GovernanceError::TemperatureCheckNotPassed => "GOV-018",
+            GovernanceError::ReviewPeriodNotEnded => "GOV-019",
+            GovernanceError::TemperatureCheckAlreadyElevated => "GOV-020",
+            GovernanceError::AlreadyVotedOnTemperatureCheck => "GOV-021",
+            GovernanceError::NoOptionSelected => "GOV-022",
+            GovernanceError::SingleChoiceOnly => "GOV-023",
+            GovernanceError::TooManySelections { .. } => "GOV-024",
+            GovernanceError::DuplicateVoteOption => "GOV-025",
+            GovernanceError::InvalidVoteOption => "GOV-026",
+            GovernanceError::AlreadyVotedOnProposal => "GOV-027",
+            GovernanceError::ProposalAlreadyFinalized => "GOV-028",
+            GovernanceError::ProposalNotFinalized => "GOV-029",
+            GovernanceError::InvalidCarryOverFactor => "GOV-030",
+            GovernanceError::ProposalNotPassed => "GOV-031",
+            GovernanceError::ProposalAlreadyExecuted => "GOV-032",
+            GovernanceError::InvalidReconfirmationDelay => "GOV-033",
+            GovernanceError::DecisionNotStanding => "GOV-034",
+            GovernanceError::ProposalNotExecuted => "GOV-035",
+            GovernanceError::NoReconfirmationDelay => "GOV-036",
+            GovernanceError::ReconfirmationAlreadySpawned => "GOV-037",
+            GovernanceError::ReconfirmationNotDue => "GOV-038",
+            GovernanceError::NoDelegators => "GOV-039",
+            GovernanceError::DuplicateDelegator => "GOV-040",
+            GovernanceError::NoDelegationAtSnapshot => "GOV-041",
+            GovernanceError::DelegateeHasNotVoted => "GOV-042",
+            GovernanceError::DelegatorVotedDirectly => "GOV-043",
+            GovernanceError::DelegatedPowerAlreadyCast => "GOV-044",
+            GovernanceError::VoteDelegationNotConfigured => "GOV-045",
+            GovernanceError::AccountDenylisted => "GOV-046",
+            GovernanceError::AccountAlreadyDenylisted => "GOV-047",
+            GovernanceError::AccountNotDenylisted => "GOV-048",
+            GovernanceError::AccountNotVerified => "GOV-049",
+            GovernanceError::StorageReimbursementsDisabled => "GOV-050",
+            GovernanceError::NonPositiveAmount => "GOV-051",
+            GovernanceError::ReimbursementCapExceeded => "GOV-052",
+            GovernanceError::InvalidStorageReceipt => "GOV-053",
+            GovernanceError::NoVotingPowerResources => "GOV-054",
+            GovernanceError::TooManyVotingPowerResources => "GOV-055",
+            GovernanceError::NonPositiveResourceWeight => "GOV-056",
+            GovernanceError::DuplicateVotingPowerResource => "GOV-057",
+            GovernanceError::EmptyDaoName => "GOV-058",
+            GovernanceError::NonPositiveFeeCap => "GOV-059",
+            GovernanceError::NoSubsidizedVotes => "GOV-060",
+            GovernanceError::TitleTooLong => "GOV-061",
+            GovernanceError::ShortDescriptionTooLong => "GOV-062",
+            GovernanceError::DescriptionTooLong { .. } => "GOV-063",
+            GovernanceError::EmptyOptionLabel => "GOV-064",
+            GovernanceError::OptionLabelTooLong => "GOV-065",
+            GovernanceError::DuplicateOptionLabel => "GOV-066",
+            GovernanceError::InsecureLink => "GOV-067",
+            GovernanceError::AlreadySeconded => "GOV-068",
+            GovernanceError::ProposeThresholdNotReached => "GOV-069",
+            GovernanceError::ExecutionApprovalsNotConfigured => "GOV-070",
+            GovernanceError::InvalidExecutorBadge => "GOV-071",
+            GovernanceError::ExecutionAlreadyApproved => "GOV-072",
+            GovernanceError::InsufficientExecutionApprovals { .. } => "GOV-073",
+            GovernanceError::InvalidApprovalCount => "GOV-074",
+            GovernanceError::ExecutorNotFound => "GOV-075",
+            GovernanceError::InvalidActionArgs => "GOV-076",
+            GovernanceError::VoteBondRequired => "GOV-077",
+            GovernanceError::NoVoteBondRequired => "GOV-078",
+            GovernanceError::InsufficientVoteBond => "GOV-079",
+            GovernanceError::InvalidVoteBond => "GOV-080",
+            GovernanceError::VoteBondLocked => "GOV-081",
+            GovernanceError::NoVoteBond => "GOV-082",
+            GovernanceError::ArchiveRetentionNotElapsed => "GOV-083",
+            GovernanceError::ReconfirmationPending => "GOV-084",
+            GovernanceError::UnreclaimedVoteBonds => "GOV-085",
+            GovernanceError::ArchivedProposalNotFound => "GOV-086",
+            GovernanceError::InvalidWeightCap => "GOV-087",
+            GovernanceError::TooManyCoAuthors => "GOV-088",
+            GovernanceError::DuplicateCoAuthor => "GOV-089",
+            GovernanceError::NotAnAuthor => "GOV-090",
+            GovernanceError::NotAMember => "GOV-091",
+            GovernanceError::AlreadyAMember => "GOV-092",
+            GovernanceError::TallyHiddenUntilFinalized => "GOV-093",
+            GovernanceError::NotTwoPhase => "GOV-094",
+            GovernanceError::AlreadyVotedOnSignal => "GOV-095",
+            GovernanceError::TemperatureCheckLengthOutOfBounds => "GOV-096",
+            GovernanceError::ProposalLengthOutOfBounds => "GOV-097",
+            GovernanceError::InvalidChallengeThreshold => "GOV-098",
+            GovernanceError::ChallengeWindowClosed => "GOV-099",
+            GovernanceError::ChallengeWindowOpen => "GOV-100",
+            GovernanceError::AlreadyChallenged => "GOV-101",
+            GovernanceError::InsufficientChallengeBond => "GOV-102",
+            GovernanceError::NoChallengeBond => "GOV-103",
+            GovernanceError::UnreclaimedChallengeBonds => "GOV-104",
+            GovernanceError::AnonymousDelegatedVote => "GOV-105",
+            GovernanceError::EligibilityProofRequired => "GOV-106",
+            GovernanceError::NoEligibilityAllowlist => "GOV-107",
+            GovernanceError::InvalidEligibilityProof => "GOV-108",
+            GovernanceError::DefaultProxyNotApplicable => "GOV-109",
+            GovernanceError::NotVoteDelegationBlueprint => "GOV-110",
+            GovernanceError::VoteDelegationNotLinked => "GOV-111",
+            GovernanceError::InvalidGovernanceLimits => "GOV-112",
+            GovernanceError::InvalidSponsorThreshold => "GOV-113",
+            GovernanceError::AlreadySponsored => "GOV-114",
+            GovernanceError::TooManySponsors => "GOV-115",
+            GovernanceError::UntrustedStorage => "GOV-116",
+            GovernanceError::StorageAlreadyTrusted => "GOV-117",
+            GovernanceError::StorageNotTrusted => "GOV-118",
+            GovernanceError::RationalesDisabled => "GOV-119",
+            GovernanceError::EmptyRationale => "GOV-120",
+            GovernanceError::RationaleTooLong => "GOV-121",
+            GovernanceError::InvalidFundingAmount => "GOV-122",
+            GovernanceError::InvalidFundingRecipient => "GOV-123",
+            GovernanceError::NotAuthorizedSigner => "GOV-124",
+            GovernanceError::SignerBadgeNotFound => "GOV-125",
+            GovernanceError::InvalidEarlyVoteBonus => "GOV-126",
+            GovernanceError::TooManyQuestions => "GOV-127",
+            GovernanceError::InvalidQuestion => "GOV-128",
+            GovernanceError::QuestionNotFound => "GOV-129",
+            GovernanceError::DuplicateQuestionAnswer => "GOV-130",
+            GovernanceError::MainQuestionUnanswered => "GOV-131",
+            GovernanceError::WrongVotingMode => "GOV-132",
+            GovernanceError::InvalidPointsAllocation => "GOV-133",
+            GovernanceError::IntendedVoteNotAnnounced => "GOV-134",
+            GovernanceError::DelegateNoticePeriodNotElapsed => "GOV-135",
+            GovernanceError::VoteDiffersFromIntendedVote => "GOV-136",
+            GovernanceError::InvalidDelegateNoticePeriod => "GOV-137",
+            GovernanceError::BalanceBelowMinimum => "GOV-138",
+            GovernanceError::InvalidMinBalance => "GOV-139",
+            GovernanceError::InvalidBalanceAveraging => "GOV-140",
+            GovernanceError::NotASurvey => "GOV-141",
+            GovernanceError::InvalidSurveyResponse => "GOV-142",
+            GovernanceError::AlreadyResponded => "GOV-143",
+            GovernanceError::SurveyResponsesHidden => "GOV-144",
+            GovernanceError::InvalidReaction => "GOV-145",
+            GovernanceError::AlreadyReacted => "GOV-146",
+            GovernanceError::AccountTooNew => "GOV-147",
+            GovernanceError::VoteChangeNotAllowed => "GOV-148",
+            GovernanceError::VoteChangeAfterQuorum => "GOV-149",
+            GovernanceError::VoteNotFound => "GOV-150",
+            GovernanceError::ChallengeBondSlashed => "GOV-151",
+            GovernanceError::NoDisputeReward => "GOV-152",
+            GovernanceError::DisputeRewardAlreadyClaimed => "GOV-153",
+            GovernanceError::InvalidExternalRef => "GOV-154",
+            GovernanceError::DuplicateExternalRef => "GOV-155",
+            GovernanceError::TooManyAttachments => "GOV-156",
+            GovernanceError::InvalidAttachment => "GOV-157",
+            GovernanceError::AttachmentsTooLarge => "GOV-158",
+            GovernanceError::InvalidLargeVoteFraction => "GOV-159",
+            GovernanceError::InvalidAdaptiveApprovalThreshold => "GOV-160",
+            GovernanceError::NotRollbackable => "GOV-161",
+            GovernanceError::RollbackWindowClosed => "GOV-162",
+            GovernanceError::AlreadyRolledBack => "GOV-163",
+            GovernanceError::InvalidAbstainOption => "GOV-164",
+            GovernanceError::InvalidRankingCandidates => "GOV-165",
+            GovernanceError::InvalidRanking => "GOV-166",
+            GovernanceError::NotARanking => "GOV-167",
+            GovernanceError::InvalidVotingGroup => "GOV-168",
+            GovernanceError::VotingGroupNotFound => "GOV-169",
+            GovernanceError::AlreadyInVotingGroup => "GOV-170",
+            GovernanceError::NotInVotingGroup => "GOV-171",
+            GovernanceError::ReimbursementExceedsReceipt => "GOV-172",
+            GovernanceError::StorageReceiptAlreadyClaimed => "GOV-173",
+            GovernanceError::AlreadyReopened => "GOV-174",
+            GovernanceError::ParametersChangedSinceExecution => "GOV-175",
+            GovernanceError::VoteCarriesDelegatedPower => "GOV-176",
+            GovernanceError::DisputeRewardClaimPeriodEnded => "GOV-177",
+            GovernanceError::DisputeRewardClaimPeriodOpen => "GOV-178",
+            GovernanceError::JoinedVotingGroupAfterSnapshot => "GOV-179",
+            GovernanceError::InvalidBindingQuorum => "GOV-180",
+            GovernanceError::SignalPhaseLengthOutOfBounds => "GOV-181",
+        }
+    }
+
+    /// Human-readable description of the error
+    pub fn message(&self) -> String {
+        match self {
+            GovernanceError::TemperatureCheckNotFound => "Temperature check not found".to_string(),
+            GovernanceError::ProposalNotFound => "Proposal not found".to_string(),
+            GovernanceError::EmptyTitle => "Temperature check title cannot be empty".to_string(),
+            GovernanceError::EmptyShortDescription => {
+                "Temperature check short description cannot be empty".to_string()
+            }
+            GovernanceError::EmptyDescription => {
+                "Temperature check description cannot be empty".to_string()
+            }
+            GovernanceError::NoVoteOptions => {
+                "Temperature check must have at least one vote option".to_string()
+            }
+            GovernanceError::TooManyVoteOptions { max } => {
+                format!("Too many vote options (max {})", max)
+            }
+            GovernanceError::TooManyLinks { max } => format!("Too many links (max {})", max),
+            GovernanceError::DuplicateVoteOptionColor => "Duplicate vote option color".to_string(),
+            GovernanceError::ZeroMaxSelections => {
+                "max_selections must be greater than 0".to_string()
+            }
+            GovernanceError::MaxSelectionsTooHigh { max } => {
+                format!("max_selections cannot exceed {}", max)
+            }
+            GovernanceError::MaxSelectionsExceedsOptions => {
+                "max_selections cannot exceed number of vote options".to_string()
+            }
+            GovernanceError::VotingNotStarted => "Voting has not started yet".to_string(),
+            GovernanceError::VotingEnded => "Voting has ended".to_string(),
+            GovernanceError::VotingNotEnded => "Voting has not ended yet".to_string(),
+            GovernanceError::TemperatureCheckAlreadyFinalized => {
+                "Temperature check has already been finalized".to_string()
+            }
+            GovernanceError::NotAutoElevated => {
+                "Temperature check is not set to auto-elevate".to_string()
+            }
+            GovernanceError::TemperatureCheckNotPassed => {
+                "Temperature check has not passed".to_string()
+            }
+            GovernanceError::ReviewPeriodNotEnded => "Review period has not ended yet".to_string(),
+            GovernanceError::TemperatureCheckAlreadyElevated => {
+                "Temperature check has already been elevated to a proposal".to_string()
+            }
+            GovernanceError::AlreadyVotedOnTemperatureCheck => {
+                "Account has already voted on this temperature check".to_string()
+            }
+            GovernanceError::NoOptionSelected => "Must select at least one option".to_string(),
+            GovernanceError::SingleChoiceOnly => {
+                "This is a single-choice proposal, select exactly one option".to_string()
+            }
+            GovernanceError::TooManySelections { max } => {
+                format!("Cannot select more than {} options", max)
+            }
+            GovernanceError::DuplicateVoteOption => "Duplicate vote option selected".to_string(),
+            GovernanceError::InvalidVoteOption => "Invalid vote option".to_string(),
+            GovernanceError::AlreadyVotedOnProposal => {
+                "Account has already voted on this proposal".to_string()
+            }
+            GovernanceError::ProposalAlreadyFinalized => {
+                "Proposal has already been finalized".to_string()
+            }
+            GovernanceError::ProposalNotFinalized => {
+                "Only finalized proposals can be reopened".to_string()
+            }
+            GovernanceError::InvalidCarryOverFactor => {
+                "Carry-over factor must be between 0 and 1".to_string()
+            }
+            GovernanceError::ProposalNotPassed => "Proposal has not passed".to_string(),
+            GovernanceError::ProposalAlreadyExecuted => {
+                "Proposal has already been executed".to_string()
+            }
+            GovernanceError::InvalidReconfirmationDelay => {
+                "Re-confirmation delay must be at least one day".to_string()
+            }
+            GovernanceError::DecisionNotStanding => "Decision is no longer standing".to_string(),
+            GovernanceError::ProposalNotExecuted => "Proposal has not been executed".to_string(),
+            GovernanceError::NoReconfirmationDelay => {
+                "Proposal has no re-confirmation delay".to_string()
+            }
+            GovernanceError::ReconfirmationAlreadySpawned => {
+                "Re-confirmation has already been spawned".to_string()
+            }
+            GovernanceError::ReconfirmationNotDue => "Re-confirmation is not due yet".to_string(),
+            GovernanceError::NoDelegators => "Must provide at least one delegator".to_string(),
+            GovernanceError::DuplicateDelegator => "Duplicate delegator".to_string(),
+            GovernanceError::NoDelegationAtSnapshot => {
+                "No delegation from delegator to delegatee at the proposal snapshot".to_string()
+            }
+            GovernanceError::DelegateeHasNotVoted => {
+                "Delegatee must vote on the proposal before casting delegated power".to_string()
+            }
+            GovernanceError::DelegatorVotedDirectly => {
+                "Delegator has already voted directly on this proposal".to_string()
+            }
+            GovernanceError::DelegatedPowerAlreadyCast => {
+                "Delegated power has already been cast by this delegatee".to_string()
+            }
+            GovernanceError::VoteDelegationNotConfigured => {
+                "No vote delegation component configured".to_string()
+            }
+            GovernanceError::AccountDenylisted => "Account is denylisted".to_string(),
+            GovernanceError::AccountAlreadyDenylisted => {
+                "Account is already denylisted".to_string()
+            }
+            GovernanceError::AccountNotDenylisted => "Account is not denylisted".to_string(),
+            GovernanceError::AccountNotVerified => {
+                "Account is not verified by the identity verifier".to_string()
+            }
+            GovernanceError::StorageReimbursementsDisabled => {
+                "Storage reimbursements are not enabled".to_string()
+            }
+            GovernanceError::NonPositiveAmount => "Amount must be positive".to_string(),
+            GovernanceError::ReimbursementCapExceeded => {
+                "Reimbursement exceeds the cap for this proposal".to_string()
+            }
+            GovernanceError::InvalidStorageReceipt => "Invalid storage receipt".to_string(),
+            GovernanceError::NoVotingPowerResources => {
+                "At least one voting power resource is required".to_string()
+            }
+            GovernanceError::TooManyVotingPowerResources => {
+                format!("Too many voting power resources (max {})", MAX_VOTING_POWER_RESOURCES)
+            }
+            GovernanceError::NonPositiveResourceWeight => {
+                "Resource weight must be positive".to_string()
+            }
+            GovernanceError::DuplicateVotingPowerResource => {
+                "Duplicate voting power resource".to_string()
+            }
+            GovernanceError::EmptyDaoName => "DAO name cannot be empty".to_string(),
+            GovernanceError::NonPositiveFeeCap => {
+                "Maximum fee per vote must be positive".to_string()
+            }
+            GovernanceError::NoSubsidizedVotes => {
+                "At least one subsidized vote per day is required".to_string()
+            }
+            GovernanceError::TitleTooLong => {
+                format!("Title cannot exceed {} characters", MAX_TITLE_LENGTH)
+            }
+            GovernanceError::ShortDescriptionTooLong => {
+                format!("Short description cannot exceed {} characters", MAX_SHORT_DESCRIPTION_LENGTH)
+            }
+            GovernanceError::DescriptionTooLong { max } => {
+                format!("Description cannot exceed {} characters", max)
+            }
+            GovernanceError::EmptyOptionLabel => "Vote option label cannot be empty".to_string(),
+            GovernanceError::OptionLabelTooLong => {
+                format!("Vote option label cannot exceed {} characters", MAX_OPTION_LABEL_LENGTH)
+            }
+            GovernanceError::DuplicateOptionLabel => "Duplicate vote option label".to_string(),
+            GovernanceError::InsecureLink => "Links must use https".to_string(),
+            GovernanceError::AlreadySeconded => {
+                "Account has already seconded this temperature check".to_string()
+            }
+            GovernanceError::ProposeThresholdNotReached => {
+                "Endorsements have not reached the propose or sponsor threshold".to_string()
+            }
+            GovernanceError::ExecutionApprovalsNotConfigured => {
+                "Execution approvals are not configured".to_string()
+            }
+            GovernanceError::InvalidExecutorBadge => "Invalid executor badge".to_string(),
+            GovernanceError::ExecutionAlreadyApproved => {
+                "Executor has already approved this execution".to_string()
+            }
+            GovernanceError::InsufficientExecutionApprovals { required } => {
+                format!("Execution requires {} executor approvals", required)
+            }
+            GovernanceError::InvalidApprovalCount => {
+                "At least one execution approval is required".to_string()
+            }
+            GovernanceError::ExecutorNotFound => "Executor not found".to_string(),
+            GovernanceError::InvalidActionArgs => {
+                "Action arguments must be an SBOR-encoded ScryptoValue".to_string()
+            }
+            GovernanceError::VoteBondRequired => {
+                "This proposal requires a vote bond, use vote_on_proposal_with_bond".to_string()
+            }
+            GovernanceError::NoVoteBondRequired => {
+                "This proposal does not take vote bonds".to_string()
+            }
+            GovernanceError::InsufficientVoteBond => {
+                "Vote bond must be at least the required XRD amount".to_string()
+            }
+            GovernanceError::InvalidVoteBond => "Vote bond must be positive".to_string(),
+            GovernanceError::VoteBondLocked => {
+                "Vote bonds can be reclaimed once the proposal is finalized".to_string()
+            }
+            GovernanceError::NoVoteBond => "No vote bond to reclaim".to_string(),
+            GovernanceError::ArchiveRetentionNotElapsed => {
+                "Proposal can be archived once the retention window after finalization has elapsed".to_string()
+            }
+            GovernanceError::ReconfirmationPending => {
+                "Proposal still awaits its re-confirmation".to_string()
+            }
+            GovernanceError::UnreclaimedVoteBonds => {
+                "Proposal still holds unreclaimed vote bonds".to_string()
+            }
+            GovernanceError::ArchivedProposalNotFound => "Archived proposal not found".to_string(),
+            GovernanceError::InvalidWeightCap => {
+                "Weight cap must be a positive amount or a fraction between 0 and 1".to_string()
+            }
+            GovernanceError::TooManyCoAuthors => {
+                format!("Maximum {} co-authors allowed", MAX_CO_AUTHORS)
+            }
+            GovernanceError::DuplicateCoAuthor => {
+                "Co-authors must be unique and different from the author".to_string()
+            }
+            GovernanceError::NotAnAuthor => {
+                "Only the author or a co-author can do this".to_string()
+            }
+            GovernanceError::NotAMember => {
+                "Account is not a member of this private consultation".to_string()
+            }
+            GovernanceError::AlreadyAMember => "Account is already a member".to_string(),
+            GovernanceError::TallyHiddenUntilFinalized => {
+                "The tally of a private consultation is public once finalized".to_string()
+            }
+            GovernanceError::NotTwoPhase => "Proposal has no signal phase".to_string(),
+            GovernanceError::AlreadyVotedOnSignal => {
+                "Account has already voted in the signal phase".to_string()
+            }
+            GovernanceError::TemperatureCheckLengthOutOfBounds => format!(
+                "Temperature check length must be between {} and {} days",
+                MIN_TEMPERATURE_CHECK_DAYS, MAX_TEMPERATURE_CHECK_DAYS
+            ),
+            GovernanceError::ProposalLengthOutOfBounds => format!(
+                "Proposal length must be between {} and {} days",
+                MIN_PROPOSAL_LENGTH_DAYS, MAX_PROPOSAL_LENGTH_DAYS
+            ),
+            GovernanceError::InvalidChallengeThreshold => {
+                "Challenge threshold must be a fraction between 0 and 1".to_string()
+            }
+            GovernanceError::ChallengeWindowClosed => {
+                "Proposal result is not open to challenges".to_string()
+            }
+            GovernanceError::ChallengeWindowOpen => {
+                "The challenge window of this proposal has not ended yet".to_string()
+            }
+            GovernanceError::AlreadyChallenged => {
+                "Account has already challenged this result".to_string()
+            }
+            GovernanceError::InsufficientChallengeBond => {
+                "Challenge bond must be at least the required XRD amount".to_string()
+            }
+            GovernanceError::NoChallengeBond => "No challenge bond to reclaim".to_string(),
+            GovernanceError::UnreclaimedChallengeBonds => {
+                "Proposal still holds unreclaimed challenge bonds".to_string()
+            }
+            GovernanceError::AnonymousDelegatedVote => {
+                "Delegated power cannot be cast on an anonymous proposal".to_string()
+            }
+            GovernanceError::EligibilityProofRequired => {
+                "Proposal has an eligibility allowlist, vote with a Merkle proof".to_string()
+            }
+            GovernanceError::NoEligibilityAllowlist => {
+                "Proposal has no eligibility allowlist".to_string()
+            }
+            GovernanceError::InvalidEligibilityProof => {
+                "Merkle proof does not match the eligibility allowlist".to_string()
+            }
+            GovernanceError::DefaultProxyNotApplicable => {
+                "Delegator has other delegations, so its default proxy does not apply".to_string()
+            }
+            GovernanceError::NotVoteDelegationBlueprint => {
+                "Component is not a VoteDelegation of this package".to_string()
+            }
+            GovernanceError::VoteDelegationNotLinked => {
+                "VoteDelegation has not authorized this governance component".to_string()
+            }
+            GovernanceError::InvalidGovernanceLimits => format!(
+                "Limits must be positive and at most {} links, {} vote options and {} selections, \
+                 with max_selections not above max_vote_options",
+                MAX_LINKS, MAX_VOTE_OPTIONS, MAX_SELECTIONS
+            ),
+            GovernanceError::InvalidSponsorThreshold => {
+                format!("Sponsor threshold cannot exceed {}", MAX_SPONSORS)
+            }
+            GovernanceError::AlreadySponsored => {
+                "Account already sponsors this temperature check".to_string()
+            }
+            GovernanceError::TooManySponsors => {
+                format!("Temperature check cannot have more than {} sponsors", MAX_SPONSORS)
+            }
+            GovernanceError::UntrustedStorage => {
+                "File storage component is not on the trusted storage allowlist".to_string()
+            }
+            GovernanceError::StorageAlreadyTrusted => {
+                "Storage component is already trusted".to_string()
+            }
+            GovernanceError::StorageNotTrusted => "Storage component is not trusted".to_string(),
+            GovernanceError::RationalesDisabled => {
+                "Vote rationales are disabled for this proposal".to_string()
+            }
+            GovernanceError::EmptyRationale => "Vote rationale cannot be empty".to_string(),
+            GovernanceError::RationaleTooLong => {
+                format!("Vote rationale exceeds maximum length of {}", MAX_RATIONALE_LENGTH)
+            }
+            GovernanceError::InvalidFundingAmount => {
+                "Funding amount must be greater than zero".to_string()
+            }
+            GovernanceError::InvalidFundingRecipient => {
+                "Funding recipient must be an existing account".to_string()
+            }
+            GovernanceError::NotAuthorizedSigner => {
+                "Proof is not an authorized signer badge of this account".to_string()
+            }
+            GovernanceError::SignerBadgeNotFound => {
+                "Signer badge was not issued by this account or was already revoked".to_string()
+            }
+            GovernanceError::InvalidEarlyVoteBonus => format!(
+                "Early vote bonus needs a window fraction between 0 and 1 and a multiplier above 1 and at most {}",
+                MAX_EARLY_VOTE_MULTIPLIER
+            ),
+            GovernanceError::TooManyQuestions => {
+                format!("Maximum {} extra questions allowed", MAX_EXTRA_QUESTIONS)
+            }
+            GovernanceError::InvalidQuestion => {
+                "Questions need a label and an approval threshold between 0 and 1".to_string()
+            }
+            GovernanceError::QuestionNotFound => "Question not found".to_string(),
+            GovernanceError::DuplicateQuestionAnswer => {
+                "Each question can only be answered once".to_string()
+            }
+            GovernanceError::MainQuestionUnanswered => {
+                "The ballot must answer the main question (ID 0)".to_string()
+            }
+            GovernanceError::WrongVotingMode => {
+                "Not supported in the proposal's voting mode".to_string()
+            }
+            GovernanceError::InvalidPointsAllocation => format!(
+                "Points must go to distinct options of the proposal and sum to {}",
+                POINTS_PER_VOTER
+            ),
+            GovernanceError::IntendedVoteNotAnnounced => {
+                "The delegatee must announce its intended vote before casting delegated power".to_string()
+            }
+            GovernanceError::DelegateNoticePeriodNotElapsed => {
+                "The notice period after the intended vote announcement has not elapsed".to_string()
+            }
+            GovernanceError::VoteDiffersFromIntendedVote => {
+                "The delegatee's vote differs from its announced intended vote".to_string()
+            }
+            GovernanceError::InvalidDelegateNoticePeriod => {
+                "The delegate notice period must be shorter than the proposal length".to_string()
+            }
+            GovernanceError::BalanceBelowMinimum => {
+                "The account's voting power is below the proposal's minimum balance to vote".to_string()
+            }
+            GovernanceError::InvalidMinBalance => {
+                "The minimum balance to vote must be positive".to_string()
+            }
+            GovernanceError::InvalidBalanceAveraging => format!(
+                "Balances must be averaged over 1 to {} days",
+                MAX_BALANCE_AVERAGING_DAYS
+            ),
+            GovernanceError::NotASurvey => "The proposal is not a survey".to_string(),
+            GovernanceError::InvalidSurveyResponse => format!(
+                "A survey response must be non-empty text of at most {} characters or a file on trusted storage",
+                MAX_SURVEY_RESPONSE_LENGTH
+            ),
+            GovernanceError::AlreadyResponded => {
+                "The account already responded to this survey".to_string()
+            }
+            GovernanceError::SurveyResponsesHidden => {
+                "Survey responses can only be listed after the deadline".to_string()
+            }
+            GovernanceError::InvalidReaction => {
+                format!("Reactions must be below {}", REACTION_KINDS)
+            }
+            GovernanceError::AlreadyReacted => {
+                "The account already reacted with this reaction".to_string()
+            }
+            GovernanceError::AccountTooNew => {
+                "Account was created after the proposal's snapshot".to_string()
+            }
+            GovernanceError::VoteChangeNotAllowed => {
+                "Votes on this proposal cannot be changed".to_string()
+            }
+            GovernanceError::VoteChangeAfterQuorum => {
+                "Votes cannot be changed once the proposal reached quorum".to_string()
+            }
+            GovernanceError::VoteNotFound => {
+                "Account has not voted directly on this proposal".to_string()
+            }
+            GovernanceError::ChallengeBondSlashed => {
+                "The challenge failed and its bonds were slashed to the winning voters".to_string()
+            }
+            GovernanceError::NoDisputeReward => {
+                "No dispute reward for this account on this proposal".to_string()
+            }
+            GovernanceError::DisputeRewardAlreadyClaimed => {
+                "Dispute reward already claimed".to_string()
+            }
+            GovernanceError::InvalidExternalRef => format!(
+                "External reference must be non-empty and at most {} characters",
+                MAX_EXTERNAL_REF_LENGTH
+            ),
+            GovernanceError::DuplicateExternalRef => {
+                "External reference already used by another temperature check".to_string()
+            }
+            GovernanceError::TooManyAttachments => {
+                format!("Too many attachments (max {})", MAX_ATTACHMENTS)
+            }
+            GovernanceError::InvalidAttachment => format!(
+                "Attachment label and MIME type must be non-empty and at most {} and {} characters",
+                MAX_ATTACHMENT_LABEL_LENGTH, MAX_MIME_TYPE_LENGTH
+            ),
+            GovernanceError::AttachmentsTooLarge => format!(
+                "Attachments exceed the total declared size of {} bytes",
+                MAX_ATTACHMENTS_TOTAL_SIZE
+            ),
+            GovernanceError::InvalidLargeVoteFraction => {
+                "The large vote fraction must be positive".to_string()
+            }
+            GovernanceError::InvalidAdaptiveApprovalThreshold => {
+                "The adaptive approval threshold needs a quorum multiple above 1 and a positive threshold at most the proposal approval threshold".to_string()
+            }
+            GovernanceError::NotRollbackable => {
+                "Proposal is not an executed parameter change with a rollback window".to_string()
+            }
+            GovernanceError::RollbackWindowClosed => {
+                "The rollback window of this execution has ended".to_string()
+            }
+            GovernanceError::AlreadyRolledBack => {
+                "Execution already rolled back".to_string()
+            }
+            GovernanceError::InvalidAbstainOption => {
+                "The abstain option must be one of the vote options".to_string()
+            }
+            GovernanceError::InvalidRankingCandidates => {
+                "A ranking needs two or more distinct existing temperature checks, one vote \
+                 option per temperature check and the points allocation voting mode"
+                    .to_string()
+            }
+            GovernanceError::InvalidRanking => {
+                "The ranking must order every temperature check of the proposal once".to_string()
+            }
+            GovernanceError::NotARanking => "The proposal is not a ranking".to_string(),
+            GovernanceError::InvalidVotingGroup => format!(
+                "A voting group needs a name of at most {} characters and a positive bloc weight",
+                MAX_TITLE_LENGTH
+            ),
+            GovernanceError::VotingGroupNotFound => "Voting group not found".to_string(),
+            GovernanceError::AlreadyInVotingGroup => {
+                "The account is already in a voting group".to_string()
+            }
+            GovernanceError::NotInVotingGroup => "The account is not in a voting group".to_string(),
+            GovernanceError::ReimbursementExceedsReceipt => {
+                "Amount exceeds the fees paid on the storage receipt".to_string()
+            }
+            GovernanceError::StorageReceiptAlreadyClaimed => {
+                "Storage receipt was already used for a reimbursement".to_string()
+            }
+            GovernanceError::AlreadyReopened => "Proposal was already re-opened".to_string(),
+            GovernanceError::ParametersChangedSinceExecution => {
+                "Parameters changed since the execution".to_string()
+            }
+            GovernanceError::VoteCarriesDelegatedPower => {
+                "Vote carries delegated power cast under a delegate notice period".to_string()
+            }
+            GovernanceError::DisputeRewardClaimPeriodEnded => {
+                "The dispute reward claim period has ended".to_string()
+            }
+            GovernanceError::DisputeRewardClaimPeriodOpen => format!(
+                "Dispute rewards can be claimed for {} days after the challenge window",
+                DISPUTE_REWARD_CLAIM_DAYS
+            ),
+            GovernanceError::JoinedVotingGroupAfterSnapshot => {
+                "The account joined its voting group after the proposal was created".to_string()
+            }
+            GovernanceError::InvalidBindingQuorum => {
+                "The binding quorum must be above the proposal quorum".to_string()
+            }
+            GovernanceError::SignalPhaseLengthOutOfBounds => format!(
+                "Signal phase length must be between {} and {} days",
+                MIN_SIGNAL_PHASE_DAYS, MAX_SIGNAL_PHASE_DAYS
+            ),
+        }
+    }
+
+    /// Aborts the transaction with this error
+    pub fn raise(&self) -> ! {
+        Runtime::panic(self.to_string())
+    }
+}
+
+impl fmt::Display for GovernanceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}] {}", self.code(), self.message())
+    }
+}
+
+/// Errors raised by the VoteDelegation blueprint
+/// Transactions abort with `[<code>] <message>`, e.g. `[DEL-006] No delegations found for this account`
+#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+pub enum DelegationError {
+    InvalidFraction,
+    SelfDelegation,
+    ExpiryNotInFuture,
+    TotalDelegationExceeded,
+    TooManyDelegations,
+    NoDelegations,
+    DelegationNotFound,
+    SameAccountMigration,
+    MigrationToDelegator,
+    NotDelegatingToOldAccount,
+    AlreadyDelegatingToNewAccount,
+    IncompleteMigration,
+    MixedDelegationModes,
+    DefaultProxyAlreadySet,
+    DefaultProxyFractionFixed,
+    NoMigrationSource,
+    DelegationsAlreadyStored,
+    DelegateeAtCapacity,
+    DelegateeFractionLimitExceeded,
+    IncompleteRevocation,
+    InvalidDelegationSignature,
+    InvalidNonce,
+    InstantBeforeRetainedHistory,
+}
+
+impl DelegationError {
+    /// Stable code of the error, never reused for a different error
+    pub fn code(&self) -> &'static str {
+        match self {
+            DelegationError::InvalidFraction => "DEL-001",
+            DelegationError::SelfDelegation => "DEL-002",
+            DelegationError::ExpiryNotInFuture => "DEL-003",
+            DelegationError::TotalDelegationExceeded => "DEL-004",
+            DelegationError::TooManyDelegations => "DEL-005",
+            DelegationError::NoDelegations => "DEL-006",
+            DelegationError::DelegationNotFound => "DEL-007",
+            DelegationError::SameAccountMigration => "DEL-008",
+            DelegationError::MigrationToDelegator => "DEL-009",
+            DelegationError::NotDelegatingToOldAccount => "DEL-010",
+            DelegationError::AlreadyDelegatingToNewAccount => "DEL-011",
+            DelegationError::IncompleteMigration => "DEL-012",
+            DelegationError::MixedDelegationModes => "DEL-013",
+            DelegationError::DefaultProxyAlreadySet => "DEL-014",
+            DelegationError::DefaultProxyFractionFixed => "DEL-015",
+            DelegationError::NoMigrationSource => "DEL-016",
+            DelegationError::DelegationsAlreadyStored => "DEL-017",
+            DelegationError::DelegateeAtCapacity => "DEL-018",
+            DelegationError::DelegateeFractionLimitExceeded => "DEL-019",
+            DelegationError::IncompleteRevocation => "DEL-020",
+            DelegationError::InvalidDelegationSignature => "DEL-021",
+            DelegationError::InvalidNonce => "DEL-022",
+            DelegationError::InstantBeforeRetainedHistory => "DEL-023",
+        }
+    }
+
+    /// Human-readable description of the error
+    pub fn message(&self) -> String {
+        match self {
+            DelegationError::InvalidFraction => {
+                format!("Fraction must be between {} and 1 (inclusive)", MIN_DELEGATION_FRACTION)
+            }
+            DelegationError::SelfDelegation => "Cannot delegate to yourself".to_string(),
+            DelegationError::ExpiryNotInFuture => {
+                "Delegation must be valid for some time in the future".to_string()
+            }
+            DelegationError::TotalDelegationExceeded => {
+                "Total delegation cannot exceed 100%".to_string()
+            }
+            DelegationError::TooManyDelegations => {
+                format!("Cannot have more than {} delegations", MAX_DELEGATIONS)
+            }
+            DelegationError::NoDelegations => "No delegations found for this account".to_string(),
+            DelegationError::DelegationNotFound => {
+                "No delegation found to the specified delegatee".to_string()
+            }
+            DelegationError::SameAccountMigration => {
+                "Cannot migrate to the same account".to_string()
+            }
+            DelegationError::MigrationToDelegator => {
+                "New account cannot receive its own delegation".to_string()
+            }
+            DelegationError::NotDelegatingToOldAccount => {
+                "Account does not delegate to the old account".to_string()
+            }
+            DelegationError::AlreadyDelegatingToNewAccount => {
+                "Delegator already delegates to the new account".to_string()
+            }
+            DelegationError::IncompleteMigration => {
+                "Not all incoming delegations were migrated".to_string()
+            }
+            DelegationError::MixedDelegationModes => {
+                "All delegations of a delegator must use the same mode".to_string()
+            }
+            DelegationError::DefaultProxyAlreadySet => {
+                "Delegator already has a default proxy, remove it first".to_string()
+            }
+            DelegationError::DefaultProxyFractionFixed => {
+                "The fraction of a default proxy is always 1".to_string()
+            }
+            DelegationError::NoMigrationSource => "No migration source is set".to_string(),
+            DelegationError::DelegationsAlreadyStored => {
+                "Delegator already has delegations stored in this component".to_string()
+            }
+            DelegationError::DelegateeAtCapacity => {
+                "Delegatee does not accept more delegators".to_string()
+            }
+            DelegationError::DelegateeFractionLimitExceeded => {
+                "Fraction exceeds the maximum the delegatee accepts per delegator".to_string()
+            }
+            DelegationError::IncompleteRevocation => {
+                "Not all delegators of the delegatee were provided".to_string()
+            }
+            DelegationError::InvalidDelegationSignature => {
+                "Authorization is not signed by the delegator's account key for this component".to_string()
+            }
+            DelegationError::InvalidNonce => {
+                "Nonce is not the account's next nonce".to_string()
+            }
+            DelegationError::InstantBeforeRetainedHistory => {
+                "Delegations at this instant are no longer retained".to_string()
+            }
+        }
+    }
+
+    /// Aborts the transaction with this error
+    pub fn raise(&self) -> ! {
+        Runtime::panic(self.to_string())
+    }
+}
+
+impl fmt::Display for DelegationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}] {}", self.code(), self.message())
+    }
+}
 
 // =============================================================================
 // Governance Types
@@ -75,27 +1331,445 @@ pub struct TemperatureCheckDraft {
     pub title: String,
     /// Short summary of the proposal
     pub short_description: String,
-    /// Full description in markdown format
+    /// Full description in markdown format, may be empty when `description_file` is set
     pub description: String,
+    /// Full description stored as a file instead of inline, with its content hash
+    pub description_file: Option<DescriptionFile>,
+    /// Attached files on trusted storage, with their declared metadata
+    pub attachments: Vec<Attachment>,
+    /// Kind of the proposal, with the structured fields it requires
+    pub kind: ProposalKind,
+    /// Action to carry out when the resulting proposal passes and is executed
+    pub action: Option<ProposalAction>,
+    /// If set, each direct vote on the resulting proposal locks this much XRD, returned after
+    /// finalization (anti-bot measure for one-account-one-vote consultations)
+    pub vote_bond: Option<Decimal>,
     /// Vote options with labels and colors (IDs are auto-generated)
     pub vote_options: Vec<ProposalVoteOptionInput>,
     /// External links related to the proposal
     pub links: Vec<Url>,
+    /// Blake2b hash of the originating discussion post (e.g. the RFC text), so the post can be
+    /// shown unedited since creation (see `verify_discussion_hash`)
+    pub discussion_hash: Option<Hash>,
     /// Maximum number of options a voter can select in the proposal.
     /// If None, only one option can be selected (single choice).
     /// If Some(n), up to n options can be selected (multiple choice).
     pub max_selections: Option<u32>,
+    /// If true, anyone can elevate the temperature check to a proposal once it passed
+    /// and the review delay has elapsed, without the owner
+    pub auto_elevate: bool,
+    /// Accounts sharing the author's rights; each must prove its presence to consent
+    pub co_authors: Vec<ComponentAddress>,
+    /// If true, only members at creation of the temperature check (and later of the
+    /// proposal) can vote, and tallies stay hidden until finalization
+    pub members_only: bool,
+    /// If true, the resulting proposal only records that an account voted and the per-option
+    /// aggregates, never which options an account selected
+    pub anonymous: bool,
+    /// Merkle root of an off-chain curated `(account, weight)` allowlist (see `eligibility_leaf`).
+    /// If set, the resulting proposal can only be voted on with a proof, at the listed weight.
+    pub eligibility_root: Option<Hash>,
+    /// If true, voters on the resulting proposal can attach a rationale to their vote
+    pub allow_rationales: bool,
+    /// Extra questions voted alongside the main one with `vote_on_ballot` (at most
+    /// `MAX_EXTRA_QUESTIONS`)
+    pub extra_questions: Vec<BallotQuestionInput>,
+    /// How votes on the resulting proposal are cast and tallied
+    pub voting_mode: VotingMode,
+    /// ID of the vote option meaning abstention, if any; accounts selecting only it count towards
+    /// the quorum as the `abstain_quorum_weight` parameter says
+    pub abstain_option: Option<ProposalVoteOptionId>,
+    /// Voting power an account needs to vote on the resulting proposal, overriding the
+    /// `min_balance_to_vote` parameter. None keeps the parameter.
+    pub min_balance_to_vote: Option<Decimal>,
+    /// Reference in the DAO's own numbering scheme (e.g. "GP-2025-07" or an issue URL), unique
+    /// across temperature checks
+    pub external_ref: Option<String>,
 }
 
 /// Governance parameters that control voting behavior
-#[derive(ScryptoSbor, ManifestSbor, Clone, Debug)]
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
 pub struct GovernanceParameters {
     pub temperature_check_days: u16,
     pub temperature_check_quorum: Decimal,
     pub temperature_check_approval_threshold: Decimal,
+    /// Endorsed voting power a passed temperature check needs to be elevated by anyone
+    pub temperature_check_propose_threshold: Decimal,
+    /// Number of co-sponsors that lets anyone elevate a passed temperature check regardless of
+    /// the endorsed power (at most `MAX_SPONSORS`); 0 disables it
+    pub temperature_check_sponsor_threshold: u32,
     pub proposal_length_days: u16,
     pub proposal_quorum: Decimal,
     pub proposal_approval_threshold: Decimal,
+    /// Maximum XRD reimbursed for attachment storage fees per passed proposal
+    pub max_storage_reimbursement: Decimal,
+    /// Days after a passed temperature check's deadline before it can be auto-elevated
+    pub auto_elevation_review_days: u16,
+    /// Days after a temperature check's deadline before it can be elevated to a proposal by any path
+    /// other than `force_elevate`, leaving time to review the final RFC
+    pub elevation_delay_days: u16,
+    /// Factor (between 0 and 1) applied to the weight of votes carried over when a
+    /// finalized proposal is reopened for re-confirmation
+    pub reopen_carry_over_factor: Decimal,
+    /// Maximum length (in characters) of a temperature check's full description
+    pub max_description_length: u32,
+    /// Days after finalization before a proposal can be archived
+    pub archive_retention_days: u16,
+    /// Cap on the weight a single account (own plus delegated power) counts for in a
+    /// proposal's tally. None disables the cap.
+    pub max_weight_per_account: Option<WeightCap>,
+    /// Length of the signal window that precedes the binding window of two-phase proposals
+    pub signal_phase_days: u16,
+    /// Quorum of the binding window of two-phase proposals (the signal window uses `proposal_quorum`)
+    pub binding_quorum: Decimal,
+    /// Quorum of `VotingMode::Groups` proposals, in bloc weight (their tally counts bloc weights)
+    pub group_quorum: Decimal,
+    /// Days after a proposal passes during which its result can be challenged; 0 disables challenges
+    pub challenge_period_days: u16,
+    /// Fraction (between 0 and 1) of the eligible weight challengers must represent to dispute a result
+    pub challenge_threshold: Decimal,
+    /// XRD each challenger locks until the challenge window ends
+    pub challenge_bond: Decimal,
+    /// Weight multiplier for direct proposal votes cast early in the voting window. None disables it.
+    pub early_vote_bonus: Option<EarlyVoteBonus>,
+    /// Lowers the proposal approval threshold as turnout rises above the quorum. None disables it.
+    pub adaptive_approval_threshold: Option<AdaptiveApprovalThreshold>,
+    /// Boundary voting windows are aligned to: starts are rounded down and deadlines up
+    pub deadline_rounding: DeadlineRounding,
+    /// Hours a delegatee's intended vote must be announced before it can cast delegated power on
+    /// a proposal, so delegators can react; 0 disables the requirement
+    pub delegate_notice_hours: u16,
+    /// Voting power an account needs to vote on a proposal, filtering dust accounts; drafts can
+    /// override it. None disables it.
+    pub min_balance_to_vote: Option<Decimal>,
+    /// Whether and until when direct voters can change their selection on a proposal
+    pub vote_change_policy: VoteChangePolicy,
+    /// Fraction of a proposal's quorum above which a single vote emits `LargeVoteEvent`;
+    /// None disables the event
+    pub large_vote_fraction: Option<Decimal>,
+    /// Days after executing a parameter change in which the security role can roll it back;
+    /// 0 disables rollbacks
+    pub rollback_window_days: u16,
+    /// How much accounts voting only for a proposal's abstain option count towards the quorum
+    pub abstain_quorum_weight: AbstainQuorumWeight,
+}
+
+/// When direct voters can change their selection on a proposal with `change_vote`
+#[derive(ScryptoSbor, ManifestSbor, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VoteChangePolicy {
+    /// Votes are final
+    Never,
+    /// Votes can be changed while voting is open
+    UntilDeadline,
+    /// Votes can be changed while voting is open and the weight cast is below the quorum
+    UntilQuorum,
+}
+
+/// How much the weight of accounts selecting only a proposal's abstain option counts towards
+/// the quorum
+#[derive(ScryptoSbor, ManifestSbor, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AbstainQuorumWeight {
+    Full,
+    Half,
+    Zero,
+}
+
+impl AbstainQuorumWeight {
+    /// Fraction of the abstain-only weight counted towards the quorum
+    pub fn factor(&self) -> Decimal {
+        match self {
+            AbstainQuorumWeight::Full => Decimal::ONE,
+            AbstainQuorumWeight::Half => dec!("0.5"),
+            AbstainQuorumWeight::Zero => Decimal::ZERO,
+        }
+    }
+}
+
+/// Boundary (in UTC) the start and deadline of temperature checks and proposals are rounded to
+#[derive(ScryptoSbor, ManifestSbor, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeadlineRounding {
+    /// No rounding: the window starts at the second the creating transaction landed
+    Second,
+    Hour,
+    /// Midnight UTC
+    Day,
+}
+
+impl DeadlineRounding {
+    fn seconds(&self) -> i64 {
+        match self {
+            DeadlineRounding::Second => 1,
+            DeadlineRounding::Hour => 60 * 60,
+            DeadlineRounding::Day => 24 * 60 * 60,
+        }
+    }
+
+    /// The latest boundary at or before `instant`
+    pub fn round_down(&self, instant: Instant) -> Instant {
+        let seconds = self.seconds();
+        Instant::new(instant.seconds_since_unix_epoch.div_euclid(seconds) * seconds)
+    }
+
+    /// The earliest boundary at or after `instant`
+    pub fn round_up(&self, instant: Instant) -> Instant {
+        let rounded = self.round_down(instant);
+        if rounded == instant {
+            rounded
+        } else {
+            Instant::new(rounded.seconds_since_unix_epoch + self.seconds())
+        }
+    }
+}
+
+/// How voters express their preference on a proposal
+#[derive(ScryptoSbor, ManifestSbor, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VotingMode {
+    /// Voters select one option, or up to `max_selections`, each counting their full weight
+    Selection,
+    /// Voters distribute `POINTS_PER_VOTER` points across the options with
+    /// `vote_on_proposal_with_points` (or Borda points with `vote_on_ranking` on ranking
+    /// proposals); each option counts the weight times its share of points
+    PointsAllocation,
+    /// Members of registered voting groups vote with `vote_in_group`; each group's bloc weight
+    /// counts for the option most of its voting members selected
+    Groups,
+}
+
+/// Bonus on the weight of direct proposal votes cast in the first part of the voting window,
+/// to counter last-minute voting
+#[derive(ScryptoSbor, ManifestSbor, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EarlyVoteBonus {
+    /// Fraction (between 0 and 1) of the voting window, from its start, in which votes get the bonus
+    pub window_fraction: Decimal,
+    /// Multiplier applied to the weight of early votes (above 1, at most `MAX_EARLY_VOTE_MULTIPLIER`)
+    pub multiplier: Decimal,
+}
+
+impl EarlyVoteBonus {
+    pub fn is_valid(&self) -> bool {
+        self.window_fraction > Decimal::ZERO
+            && self.window_fraction <= Decimal::ONE
+            && self.multiplier > Decimal::ONE
+            && self.multiplier <= Decimal::try_from(MAX_EARLY_VOTE_MULTIPLIER).unwrap()
+    }
+}
+
+/// Approval threshold lowered as turnout rises: the proposal's approval threshold applies at the
+/// quorum, `threshold` at `quorum_multiple` times the quorum and above, linearly in between
+#[derive(ScryptoSbor, ManifestSbor, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AdaptiveApprovalThreshold {
+    /// Multiple of the quorum (above 1) from which `threshold` applies
+    pub quorum_multiple: Decimal,
+    /// Approval threshold at high turnout (between 0 and 1, at most the proposal's threshold)
+    pub threshold: Decimal,
+}
+
+impl AdaptiveApprovalThreshold {
+    pub fn is_valid(&self) -> bool {
+        self.quorum_multiple > Decimal::ONE
+            && self.threshold > Decimal::ZERO
+            && self.threshold <= Decimal::ONE
+    }
+
+    /// Returns the approval threshold that applies when `total_weight_cast` was cast
+    pub fn threshold_at(
+        &self,
+        approval_threshold: Decimal,
+        quorum: Decimal,
+        total_weight_cast: Decimal,
+    ) -> Decimal {
+        let full_turnout = quorum * self.quorum_multiple;
+        if total_weight_cast >= full_turnout {
+            self.threshold
+        } else if total_weight_cast <= quorum {
+            approval_threshold
+        } else {
+            approval_threshold
+                - (approval_threshold - self.threshold) * (total_weight_cast - quorum)
+                    / (full_turnout - quorum)
+        }
+    }
+}
+
+/// Limit on the effective weight of a single account in a proposal tally
+#[derive(ScryptoSbor, ManifestSbor, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WeightCap {
+    /// Fixed amount of voting weight
+    Fixed(Decimal),
+    /// Fraction (between 0 and 1) of the eligible weight, resolved at proposal creation
+    SupplyFraction(Decimal),
+}
+
+impl WeightCap {
+    pub fn is_valid(&self) -> bool {
+        match self {
+            WeightCap::Fixed(amount) => *amount > Decimal::ZERO,
+            WeightCap::SupplyFraction(fraction) => {
+                *fraction > Decimal::ZERO && *fraction <= Decimal::ONE
+            }
+        }
+    }
+
+    /// Returns the cap as an amount of weight
+    pub fn resolve(&self, eligible_weight: Decimal) -> Decimal {
+        match self {
+            WeightCap::Fixed(amount) => *amount,
+            WeightCap::SupplyFraction(fraction) => *fraction * eligible_weight,
+        }
+    }
+}
+
+/// On-ledger action carried out when a passed proposal is executed
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub enum ProposalAction {
+    /// Calls `EXECUTOR_METHOD` on a registered executor component with the proposal ID and
+    /// `args`, which holds an SBOR-encoded `ScryptoValue` (bytes so drafts can be built in manifests)
+    CallExecutor { executor_id: u64, args: Vec<u8> },
+}
+
+/// Kind of a proposal, with the fields it requires and what executing it does
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug)]
+pub enum ProposalKind {
+    /// Non-binding or off-ledger decision; executing it only records the execution
+    Textual,
+    /// Payout of `amount` XRD to the `recipient` account; executing it emits `FundingApprovedEvent`
+    /// for the treasury to act on
+    Funding { amount: Decimal, recipient: ComponentAddress },
+    /// New governance parameters, applied when the proposal is executed
+    ParameterChange { parameters: GovernanceParameters },
+    /// Open-ended consultation (e.g. an RFC feedback round): besides voting, each account can
+    /// submit one text or file response with `respond_to_survey`, listable after the deadline
+    Survey,
+    /// Ranking of competing temperature checks (e.g. RFCs to elevate first): option `i` stands
+    /// for `temperature_check_ids[i]`, voters order all of them with `vote_on_ranking` and the
+    /// tally is a Borda count. Requires `VotingMode::PointsAllocation`.
+    Ranking { temperature_check_ids: Vec<u64> },
+}
+
+/// Multi-signature safety net between a passed vote and its execution
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionApprovalConfig {
+    /// Non-fungible badge held by executors; each badge ID counts as one executor
+    pub executor_badge: ResourceAddress,
+    /// Number of distinct executors that must approve before a proposal can be executed
+    pub required_approvals: u32,
+}
+
+/// Limits of the fee subsidy that pays the fees of successful votes
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub struct FeeSubsidyConfig {
+    /// Maximum XRD of fees locked from the subsidy vault for a single vote
+    pub max_fee_per_vote: Decimal,
+    /// Maximum number of subsidized votes per account per day
+    pub max_subsidized_votes_per_day: u32,
+}
+
+/// Subsidized votes of an account within the current daily window
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct FeeSubsidyUsage {
+    pub window_start: Instant,
+    pub votes: u32,
+}
+
+/// Timestamped entry of the governance parameter change log
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct ParameterChange {
+    pub changed_at: Instant,
+    pub old_params: GovernanceParameters,
+    pub new_params: GovernanceParameters,
+}
+
+/// Owner-configurable limits on temperature check drafts, each bounded by its hard maximum
+/// (`MAX_LINKS`, `MAX_VOTE_OPTIONS`, `MAX_SELECTIONS`)
+#[derive(ScryptoSbor, ManifestSbor, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GovernanceLimits {
+    /// Maximum number of links (attachments) per temperature check / proposal
+    pub max_links: u32,
+    /// Maximum number of vote options per proposal
+    pub max_vote_options: u32,
+    /// Maximum number of selections in a multiple-choice vote
+    pub max_selections: u32,
+}
+
+impl Default for GovernanceLimits {
+    fn default() -> Self {
+        Self {
+            max_links: 10,
+            max_vote_options: 10,
+            max_selections: 5,
+        }
+    }
+}
+
+impl GovernanceLimits {
+    pub fn is_valid(&self) -> bool {
+        self.max_links > 0
+            && self.max_links <= MAX_LINKS
+            && self.max_vote_options > 0
+            && self.max_vote_options <= MAX_VOTE_OPTIONS
+            && self.max_selections > 0
+            && self.max_selections <= MAX_SELECTIONS
+            && self.max_selections <= self.max_vote_options
+    }
+}
+
+/// Account age oracle used to deter sybil accounts: when enforced, accounts created after a
+/// proposal's snapshot (or unknown to the oracle) cannot vote on it
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub struct AccountAgeConfig {
+    /// Component exposing `ACCOUNT_AGE_ORACLE_METHOD`
+    pub oracle: ComponentAddress,
+    /// Whether votes are checked against the oracle; lets the owner keep the oracle configured
+    /// while pausing the check
+    pub enforced: bool,
+}
+
+/// Determines how an account's voting power is computed from its balances
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub struct VotingPowerConfig {
+    /// Resources counted towards voting power, with the multiplier applied to each balance
+    /// (e.g. team tokens at 0.5). Voting power = sum(balance * weight).
+    pub resource_weights: Vec<(ResourceAddress, Decimal)>,
+    /// Which balance of each resource counts
+    pub mode: VotingPowerMode,
+}
+
+/// Balance counted towards voting power
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub enum VotingPowerMode {
+    /// The balance at the time of the vote
+    CurrentBalance,
+    /// The average balance over the `days` before the temperature check or proposal opened,
+    /// read from a component implementing `BALANCE_HISTORY_METHOD`, so balances borrowed
+    /// for the vote do not count
+    AverageBalance {
+        balance_history: ComponentAddress,
+        days: u16,
+    },
+}
+
+/// Branding of a governance component, mirrored into its metadata
+/// (`name`, `icon_url`, `info_url`, `forum_url`) so explorers and wallets can render it
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub struct GovernanceBranding {
+    /// Name of the DAO running this consultation instance
+    pub dao_name: String,
+    pub logo_url: Url,
+    /// Governance forum where RFCs are discussed
+    pub forum_url: Url,
+    /// Documentation of the DAO's governance process
+    pub docs_url: Url,
+}
+
+/// Lifecycle status of a temperature check
+#[derive(ScryptoSbor, ManifestSbor, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TemperatureCheckStatus {
+    /// Voting is open, or the vote ended but has not been finalized yet
+    Active,
+    /// Finalized with quorum and approval threshold met
+    Passed,
+    /// Finalized without meeting quorum or approval threshold
+    Rejected,
 }
 
 /// Struct used to hold submitted temperature check data
@@ -106,23 +1780,92 @@ pub struct TemperatureCheck {
     pub short_description: String,
     /// Full description in markdown format
     pub description: String,
+    /// Full description stored as a file, committed to by its content hash
+    pub description_file: Option<DescriptionFile>,
+    /// Attached files with their declared metadata
+    pub attachments: Vec<Attachment>,
+    /// Kind of the proposal, validated at creation
+    pub kind: ProposalKind,
+    /// Action to carry out on execution
+    pub action: Option<ProposalAction>,
+    /// XRD locked per direct vote on the proposal, returned after finalization
+    pub vote_bond: Option<Decimal>,
     pub vote_options: Vec<ProposalVoteOption>,
     /// External links related to the proposal
     pub links: Vec<Url>,
+    /// Hash of the originating discussion post committed at creation
+    pub discussion_hash: Option<Hash>,
     pub quorum: Decimal,
     /// Maximum number of options a voter can select in the proposal.
     /// If None, only one option can be selected (single choice).
     /// If Some(n), up to n options can be selected (multiple choice).
     pub max_selections: Option<u32>,
-    pub votes: KeyValueStore<Global<Account>, TemperatureCheckVote>,
+    pub votes: KeyValueStore<Global<Account>, TemperatureCheckVoteRecord>,
+    /// Key: delegator whose power was cast by one or more delegatees
+    /// Value: the delegated votes cast with that delegator's power
+    pub delegated_votes: KeyValueStore<Global<Account>, Vec<DelegatedVote>>,
     pub approval_threshold: Decimal,
     pub start: Instant,
     pub deadline: Instant,
     pub elevated_proposal_id: Option<u64>,
     /// The account that created this temperature check
     pub author: Global<Account>,
+    /// Accounts sharing the author's rights
+    pub co_authors: Vec<Global<Account>>,
+    /// Only accounts that were members at `start` can vote
+    pub members_only: bool,
+    /// The proposal built from this temperature check does not record each account's selection
+    pub anonymous: bool,
+    /// Merkle root of the voter allowlist of the proposal built from this temperature check
+    pub eligibility_root: Option<Hash>,
+    /// Voters on the proposal built from this temperature check can attach a rationale
+    pub allow_rationales: bool,
+    /// Extra ballot questions of the proposal built from this temperature check (no tallies here)
+    pub questions: Vec<BallotQuestion>,
+    /// Voting mode of the proposal built from this temperature check
+    pub voting_mode: VotingMode,
+    /// Abstain option of the proposal built from this temperature check
+    pub abstain_option: Option<ProposalVoteOptionId>,
+    /// Minimum balance override of the proposal built from this temperature check
+    pub min_balance_to_vote: Option<Decimal>,
+    /// External reference given in the draft (see `get_temperature_check_by_external_ref`)
+    pub external_ref: Option<String>,
     /// Timestamp of the last vote cast, initialized at creation (useful for cache invalidation)
     pub last_vote_at: Instant,
+    /// Voting weight cast For
+    pub for_weight: Decimal,
+    /// Voting weight cast Against
+    pub against_weight: Decimal,
+    /// For weight that signaled a preference for each vote option, indexed by option ID
+    pub option_weights: Vec<Decimal>,
+    pub status: TemperatureCheckStatus,
+    /// Whether anyone may elevate this temperature check once it passed
+    pub auto_elevate: bool,
+    /// Voting power each account staked as endorsement (see `second_temperature_check`)
+    pub endorsements: KeyValueStore<Global<Account>, Decimal>,
+    /// Sum of the endorsed voting power
+    pub endorsement_weight: Decimal,
+    /// Endorsed power needed for permissionless elevation, captured at creation
+    pub propose_threshold: Decimal,
+    /// Accounts that co-sponsored the temperature check (see `co_sponsor`), in order
+    pub sponsors: Vec<Global<Account>>,
+    /// Co-sponsors needed for permissionless elevation, captured at creation (0 disables it)
+    pub sponsor_threshold: u32,
+}
+
+/// Lifecycle status of a proposal
+#[derive(ScryptoSbor, ManifestSbor, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProposalStatus {
+    /// Voting is open, or the vote ended but has not been finalized yet
+    Active,
+    /// Finalized with quorum and approval threshold met
+    Passed,
+    /// Finalized without meeting quorum or approval threshold
+    Rejected,
+    /// Passed and executed, but its re-confirmation proposal failed
+    Lapsed,
+    /// Passed, but challenged by enough voting power during the challenge window; needs a re-vote
+    Disputed,
 }
 
 /// Struct for a proposal (GP - Governance Proposal)
@@ -133,6 +1876,16 @@ pub struct Proposal {
     pub short_description: String,
     /// Full description in markdown format
     pub description: String,
+    /// Full description stored as a file, committed to by its content hash
+    pub description_file: Option<DescriptionFile>,
+    /// Attached files with their declared metadata
+    pub attachments: Vec<Attachment>,
+    /// Kind of the proposal, validated at creation
+    pub kind: ProposalKind,
+    /// Action to carry out on execution
+    pub action: Option<ProposalAction>,
+    /// XRD locked per direct vote on the proposal, returned after finalization
+    pub vote_bond: Option<Decimal>,
     pub vote_options: Vec<ProposalVoteOption>,
     /// External links related to the proposal
     pub links: Vec<Url>,
@@ -141,87 +1894,1477 @@ pub struct Proposal {
     /// If None, only one option can be selected (single choice).
     /// If Some(n), up to n options can be selected (multiple choice).
     pub max_selections: Option<u32>,
-    /// Stores selected option IDs for each voter
-    pub votes: KeyValueStore<Global<Account>, Vec<ProposalVoteOptionId>>,
+    /// Stores the direct vote of each voter, with the weight it was counted at
+    pub votes: KeyValueStore<Global<Account>, ProposalVoteRecord>,
+    /// Direct voters in voting order (0 to `voter_count - 1`), so they can be enumerated
+    pub voter_index: KeyValueStore<u64, Global<Account>>,
+    /// Uncapped effective weight of each direct voter: own weight plus the delegated power it cast
+    pub effective_weights: KeyValueStore<Global<Account>, Decimal>,
+    /// Weight cap per account resolved at creation, None if uncapped
+    pub weight_cap: Option<Decimal>,
+    /// Early vote bonus captured at creation, None if disabled
+    pub early_vote_bonus: Option<EarlyVoteBonus>,
+    /// Turnout-adaptive approval threshold captured at creation, None if disabled
+    pub adaptive_approval_threshold: Option<AdaptiveApprovalThreshold>,
+    /// Delegate notice period captured at creation, 0 if disabled
+    pub delegate_notice_hours: u16,
+    /// Voting power needed to vote (directly or through a delegatee), resolved at creation from
+    /// the draft override or the parameter; None if disabled
+    pub min_balance_to_vote: Option<Decimal>,
+    /// Vote change policy captured at creation
+    pub vote_change_policy: VoteChangePolicy,
+    /// Option meaning abstention, if any
+    pub abstain_option: Option<ProposalVoteOptionId>,
+    /// Share of the abstain-only weight counted towards the quorum, captured at creation
+    pub abstain_quorum_weight: AbstainQuorumWeight,
+    /// Weight cast (after the weight cap) by accounts selecting only the abstain option
+    pub abstain_only_weight: Decimal,
+    /// Voting intention announced by each delegatee (see `announce_intended_vote`)
+    pub intended_votes: KeyValueStore<Global<Account>, IntendedVote>,
+    /// Multiplier applied to each direct voter that voted within the early vote window
+    /// (the vote record holds the multiplied weight)
+    pub early_vote_multipliers: KeyValueStore<Global<Account>, Decimal>,
     pub approval_threshold: Decimal,
     pub start: Instant,
     pub deadline: Instant,
     pub temperature_check_id: u64,
+    /// The finalized proposal this one re-opens, whose votes were carried over
+    pub reopened_from: Option<u64>,
+    /// Factor applied to the carried-over votes
+    pub carry_over_factor: Decimal,
+    /// Accounts whose carried-over contribution was replaced by casting their power again
+    pub carried_over_replaced: KeyValueStore<Global<Account>, ()>,
+    /// Direct voters whose votes were carried over and not yet replaced, counted next to
+    /// `voter_count` in the turnout and tally
+    pub carried_voter_count: u64,
+    /// Carried-over direct voters selecting each option, indexed by option ID
+    pub carried_option_voter_counts: Vec<u64>,
+    /// Carried-over weight still counted for each option, indexed by option ID
+    pub carried_option_weights: Vec<Decimal>,
+    /// The proposal that re-opened this one, if any (a proposal can be re-opened once)
+    pub reopened_as: Option<u64>,
     /// The account that created the original temperature check
     pub author: Global<Account>,
+    /// Accounts sharing the author's rights
+    pub co_authors: Vec<Global<Account>>,
+    /// Only accounts that were members at `delegation_snapshot` can vote
+    pub members_only: bool,
+    /// Only per-option aggregates are kept, not each account's selection (no delegated voting)
+    pub anonymous: bool,
+    /// Merkle root of the `(account, weight)` allowlist; if set, only direct votes with a proof
+    /// count, at the listed weight instead of the current voting power
+    pub eligibility_root: Option<Hash>,
+    /// Voters can attach a rationale to their vote
+    pub allow_rationales: bool,
+    /// Rationale attached by each direct voter that gave one
+    pub rationales: KeyValueStore<Global<Account>, VoteRationale>,
+    /// Survey responses in submission order (0 to `response_count - 1`)
+    pub responses: KeyValueStore<u64, SurveyResponseRecord>,
+    /// Accounts that responded to the survey
+    pub respondents: KeyValueStore<Global<Account>, ()>,
+    pub response_count: u64,
+    /// Non-binding reaction of each account that reacted
+    pub reactions: KeyValueStore<Global<Account>, u8>,
+    /// Number of accounts per reaction, indexed by reaction
+    pub reaction_counts: Vec<u64>,
+    /// Extra ballot questions, each tallied and finalized on its own
+    pub questions: Vec<BallotQuestion>,
+    /// Answers each voter gave to the extra questions (not recorded for anonymous proposals)
+    pub question_votes: KeyValueStore<Global<Account>, Vec<(u32, Vec<ProposalVoteOptionId>)>>,
+    pub voting_mode: VotingMode,
+    /// Points each direct voter gave each option, for `PointsAllocation` proposals (kept so
+    /// delegated power follows the same split; dropped after tallying for anonymous proposals)
+    pub point_allocations: KeyValueStore<Global<Account>, Vec<(ProposalVoteOptionId, u32)>>,
+    /// Votes of group members, for `Groups` proposals
+    pub group_votes: KeyValueStore<Global<Account>, Vec<ProposalVoteOptionId>>,
+    /// Intra-group tallies by voting group ID, for `Groups` proposals
+    pub group_tallies: KeyValueStore<u64, GroupTally>,
+    /// Share of the tally per option (summing to 1), recorded at finalization of
+    /// `PointsAllocation` proposals and empty otherwise
+    pub allocation: Vec<Decimal>,
     /// Timestamp of the last vote cast, initialized at creation (useful for cache invalidation)
     pub last_vote_at: Instant,
+    /// Instant at which delegations and memberships are resolved for this proposal (its creation,
+    /// which is also the start unless the proposal is two-phase).
+    /// Delegation changes made after this instant are ignored for this proposal.
+    pub delegation_snapshot: Instant,
+    /// Number of distinct accounts that have voted
+    pub voter_count: u64,
+    /// Sum of the voting weight of all votes cast
+    pub total_weight_cast: Decimal,
+    /// Whether the weight cast has crossed the quorum, so `QuorumReachedEvent` is emitted once
+    pub quorum_reached: bool,
+    /// Total voting weight that could be cast, captured at creation
+    pub eligible_weight: Decimal,
+    /// Weight cast per option after the weight cap, indexed by `ProposalVoteOptionId`
+    pub option_weights: Vec<Decimal>,
+    /// Weight cast per option before the weight cap
+    pub raw_option_weights: Vec<Decimal>,
+    /// Sum of the voting weight of all votes cast before the weight cap
+    pub raw_total_weight_cast: Decimal,
+    /// Number of direct voters per option, indexed by `ProposalVoteOptionId`
+    pub option_voter_counts: Vec<u64>,
+    pub status: ProposalStatus,
+    /// When the proposal was finalized, if it has been
+    pub finalized_at: Option<Instant>,
+    /// Key: delegator whose power was cast by one or more delegatees
+    /// Value: the delegated votes cast with that delegator's power
+    pub delegated_votes: KeyValueStore<Global<Account>, Vec<DelegatedVote>>,
+    /// Key: delegatee that cast delegated power
+    /// Value: the delegators whose power it cast, with the weight of each, in casting order.
+    /// Contributions replaced by the delegator's direct vote are removed.
+    pub delegated_vote_breakdown: KeyValueStore<Global<Account>, Vec<(Global<Account>, Decimal)>>,
+    /// Cap on attachment storage fee reimbursement, captured at creation
+    pub max_storage_reimbursement: Decimal,
+    /// Attachment storage fees already reimbursed to the author
+    pub storage_reimbursed: Decimal,
+    /// When the owner marked the passed decision as executed, if it has been
+    pub executed_at: Option<Instant>,
+    /// Days after execution at which the decision must be re-confirmed (sunset)
+    pub reconfirm_after_days: Option<u16>,
+    /// The re-confirmation proposal spawned for this decision, if any
+    pub reconfirmation_id: Option<u64>,
+    /// The executed decision this proposal re-confirms
+    pub reconfirmation_of: Option<u64>,
+    /// Executor badge IDs that approved executing this proposal
+    pub execution_approvals: Vec<NonFungibleLocalId>,
+    /// Vote bonds locked by voters and not reclaimed yet
+    pub bonds: KeyValueStore<Global<Account>, Decimal>,
+    /// Sum of the vote bonds not reclaimed yet
+    pub outstanding_bonds: Decimal,
+    /// Non-binding signal window preceding `start`, for two-phase proposals
+    pub signal: Option<SignalPhase>,
+    /// Challenge window opened when the proposal passed, if challenges are enabled
+    pub challenge: Option<ResultChallenge>,
+    /// Parameters replaced by executing this parameter change, if rollbacks were enabled
+    pub rollback: Option<ExecutionRollback>,
 }
 
-// =============================================================================
-// Delegation Types
-// =============================================================================
-
-/// Represents a delegation from one account to another
+/// Rollback facility of an executed parameter change
 #[derive(ScryptoSbor, Clone, Debug)]
-pub struct Delegation {
-    pub delegatee: Global<Account>,
-    pub fraction: Decimal,
-    pub valid_until: Instant,
+pub struct ExecutionRollback {
+    /// Parameters in force before the execution, restored by a rollback
+    pub previous_parameters: GovernanceParameters,
+    /// Parameters the execution applied; a rollback requires them to still be in force
+    pub applied_parameters: GovernanceParameters,
+    /// End of the rollback window
+    pub deadline: Instant,
+    /// When the change was rolled back, if it was
+    pub rolled_back_at: Option<Instant>,
 }
 
-// =============================================================================
-// Events
-// =============================================================================
+/// Signal window of a two-phase proposal, with its own vote store and tally
+/// Signal votes are direct only (no delegation, bonds or weight cap) and do not decide the outcome
+#[derive(ScryptoSbor)]
+pub struct SignalPhase {
+    /// End of the signal window, which is also the start of the binding window
+    pub deadline: Instant,
+    pub quorum: Decimal,
+    pub votes: KeyValueStore<Global<Account>, Vec<ProposalVoteOptionId>>,
+    pub option_weights: Vec<Decimal>,
+    pub option_voter_counts: Vec<u64>,
+    pub voter_count: u64,
+    pub total_weight_cast: Decimal,
+}
 
-/// Emitted when a temperature check is created
-#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
-pub struct TemperatureCheckCreatedEvent {
+/// Challenge window of a passed proposal
+/// The proposal cannot be executed before the window ends
+#[derive(ScryptoSbor)]
+pub struct ResultChallenge {
+    pub window_end: Instant,
+    /// Challenged voting power needed to dispute the result, resolved when the window opens
+    pub required_weight: Decimal,
+    /// XRD each challenger locks, captured when the window opens
+    pub bond: Decimal,
+    /// Voting power of the challengers so far
+    pub weight: Decimal,
+    /// Accounts that challenged the result
+    pub challengers: KeyValueStore<Global<Account>, ()>,
+    /// Challenge bonds not reclaimed yet
+    pub bonds: KeyValueStore<Global<Account>, Decimal>,
+    /// Sum of the challenge bonds not reclaimed yet
+    pub outstanding_bonds: Decimal,
+    /// Options that won the vote, captured at finalization
+    pub winning_options: Vec<ProposalVoteOptionId>,
+    /// Weight direct voters counted for on the winning options, the denominator of the dispute
+    /// rewards (carried-over and bloc weight can't claim, so they are left out)
+    pub winning_weight: Decimal,
+    /// Bonds slashed from a failed challenge, moved to the dispute rewards vault by the first claim
+    pub reward_pool: Decimal,
+    /// Dispute rewards paid out of `reward_pool` so far
+    pub rewards_paid: Decimal,
+    /// Whether the bonds of a failed challenge were slashed into `reward_pool`
+    pub slashed: bool,
+    /// Voters that claimed their dispute reward
+    pub reward_claims: KeyValueStore<Global<Account>, ()>,
+}
+
+/// Condensed record of a finalized proposal moved out of the active store
+/// Votes and delegations are dropped; the final tally is kept for reference
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct ArchivedProposal {
+    pub title: String,
     pub temperature_check_id: u64,
+    pub author: Global<Account>,
+    pub kind: ProposalKind,
+    pub action: Option<ProposalAction>,
+    pub status: ProposalStatus,
+    pub tally: ProposalTally,
+    pub voter_count: u64,
+    pub eligible_weight: Decimal,
+    pub start: Instant,
+    pub deadline: Instant,
+    pub finalized_at: Instant,
+    pub executed_at: Option<Instant>,
+    pub archived_at: Instant,
+    /// Unclaimed dispute rewards of a failed challenge, left for the owner to sweep
+    pub unswept_dispute_rewards: Decimal,
+}
+
+/// Returnable snapshot of a temperature check, without its vote store
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct TemperatureCheckView {
     pub title: String,
+    pub short_description: String,
+    pub description: String,
+    pub description_file: Option<DescriptionFile>,
+    pub attachments: Vec<Attachment>,
+    pub kind: ProposalKind,
+    pub action: Option<ProposalAction>,
+    pub vote_bond: Option<Decimal>,
+    pub vote_options: Vec<ProposalVoteOption>,
+    pub links: Vec<Url>,
+    pub discussion_hash: Option<Hash>,
+    pub quorum: Decimal,
+    pub max_selections: Option<u32>,
+    pub approval_threshold: Decimal,
     pub start: Instant,
     pub deadline: Instant,
+    pub elevated_proposal_id: Option<u64>,
+    pub author: Global<Account>,
+    pub co_authors: Vec<Global<Account>>,
+    pub members_only: bool,
+    pub anonymous: bool,
+    pub eligibility_root: Option<Hash>,
+    pub allow_rationales: bool,
+    pub questions: Vec<BallotQuestion>,
+    pub voting_mode: VotingMode,
+    pub abstain_option: Option<ProposalVoteOptionId>,
+    pub min_balance_to_vote: Option<Decimal>,
+    pub external_ref: Option<String>,
+    pub last_vote_at: Instant,
+    pub for_weight: Decimal,
+    pub against_weight: Decimal,
+    pub option_weights: Vec<Decimal>,
+    pub status: TemperatureCheckStatus,
+    pub auto_elevate: bool,
+    pub endorsement_weight: Decimal,
+    pub propose_threshold: Decimal,
+    pub sponsor_count: u32,
+    pub sponsor_threshold: u32,
 }
 
-/// Emitted when a vote is cast on a temperature check
-#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
-pub struct TemperatureCheckVotedEvent {
-    pub temperature_check_id: u64,
-    pub account: Global<Account>,
-    pub vote: TemperatureCheckVote,
+impl From<&TemperatureCheck> for TemperatureCheckView {
+    fn from(tc: &TemperatureCheck) -> Self {
+        Self {
+            title: tc.title.clone(),
+            short_description: tc.short_description.clone(),
+            description: tc.description.clone(),
+            description_file: tc.description_file.clone(),
+            attachments: tc.attachments.clone(),
+            kind: tc.kind.clone(),
+            action: tc.action.clone(),
+            vote_bond: tc.vote_bond,
+            vote_options: tc.vote_options.clone(),
+            links: tc.links.clone(),
+            discussion_hash: tc.discussion_hash,
+            quorum: tc.quorum,
+            max_selections: tc.max_selections,
+            approval_threshold: tc.approval_threshold,
+            start: tc.start,
+            deadline: tc.deadline,
+            elevated_proposal_id: tc.elevated_proposal_id,
+            author: tc.author,
+            co_authors: tc.co_authors.clone(),
+            members_only: tc.members_only,
+            anonymous: tc.anonymous,
+            eligibility_root: tc.eligibility_root,
+            allow_rationales: tc.allow_rationales,
+            questions: tc.questions.clone(),
+            voting_mode: tc.voting_mode,
+            abstain_option: tc.abstain_option,
+            min_balance_to_vote: tc.min_balance_to_vote,
+            external_ref: tc.external_ref.clone(),
+            last_vote_at: tc.last_vote_at,
+            for_weight: tc.for_weight,
+            against_weight: tc.against_weight,
+            option_weights: tc.option_weights.clone(),
+            status: tc.status,
+            auto_elevate: tc.auto_elevate,
+            endorsement_weight: tc.endorsement_weight,
+            propose_threshold: tc.propose_threshold,
+            sponsor_count: tc.sponsors.len() as u32,
+            sponsor_threshold: tc.sponsor_threshold,
+        }
+    }
 }
 
-/// Emitted when a temperature check is elevated to a proposal
-#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
-pub struct ProposalCreatedEvent {
-    pub proposal_id: u64,
-    pub temperature_check_id: u64,
+impl TemperatureCheckView {
+    /// Clears the running tally, for private consultations still in progress
+    pub fn hide_tally(&mut self) {
+        self.for_weight = Decimal::ZERO;
+        self.against_weight = Decimal::ZERO;
+        self.option_weights = vec![Decimal::ZERO; self.option_weights.len()];
+    }
+}
+
+/// Result of a temperature check, readable after it was elevated
+#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+pub struct TemperatureCheckResult {
+    pub status: TemperatureCheckStatus,
+    pub passed: bool,
+    pub for_weight: Decimal,
+    pub against_weight: Decimal,
+    /// For weight that signaled a preference for each vote option, indexed by option ID
+    pub option_weights: Vec<Decimal>,
+    /// The proposal the temperature check was elevated to, if any
+    pub elevated_proposal_id: Option<u64>,
+}
+
+/// Live tally of a temperature check, read from the weights each vote updates, so UIs get
+/// results without finalizing it or indexing events
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub struct TemperatureCheckTally {
+    pub for_weight: Decimal,
+    pub against_weight: Decimal,
+    /// For weight that signaled a preference for each vote option, indexed by option ID
+    pub option_weights: Vec<Decimal>,
+    pub quorum_met: bool,
+    /// Whether For holds at least the approval threshold of the weight cast
+    pub threshold_met: bool,
+    /// When the weights last changed (the creation time if nobody voted)
+    pub last_vote_at: Instant,
+    /// When the tally was read
+    pub as_of: Instant,
+    /// Whether the voting window is over, so the weights can no longer change
+    pub voting_ended: bool,
+    /// Whether the result was finalized; until then it is provisional
+    pub finalized: bool,
+}
+
+/// Returnable snapshot of a proposal, without its vote and delegation stores
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct ProposalView {
     pub title: String,
+    pub short_description: String,
+    pub description: String,
+    pub description_file: Option<DescriptionFile>,
+    pub attachments: Vec<Attachment>,
+    pub kind: ProposalKind,
+    pub action: Option<ProposalAction>,
+    pub vote_bond: Option<Decimal>,
+    pub vote_options: Vec<ProposalVoteOption>,
+    pub links: Vec<Url>,
+    pub quorum: Decimal,
+    pub max_selections: Option<u32>,
+    pub approval_threshold: Decimal,
     pub start: Instant,
     pub deadline: Instant,
+    pub temperature_check_id: u64,
+    pub reopened_from: Option<u64>,
+    pub reopened_as: Option<u64>,
+    pub carry_over_factor: Decimal,
+    pub author: Global<Account>,
+    pub co_authors: Vec<Global<Account>>,
+    pub members_only: bool,
+    pub anonymous: bool,
+    pub eligibility_root: Option<Hash>,
+    pub allow_rationales: bool,
+    pub questions: Vec<BallotQuestion>,
+    pub voting_mode: VotingMode,
+    /// Normalized allocation, set at finalization of points allocation proposals
+    pub allocation: Vec<Decimal>,
+    pub last_vote_at: Instant,
+    pub delegation_snapshot: Instant,
+    /// End of the signal window, None unless the proposal is two-phase
+    pub signal_deadline: Option<Instant>,
+    /// End of the challenge window, None unless the proposal passed with challenges enabled
+    pub challenge_window_end: Option<Instant>,
+    /// Voting power of the accounts that challenged the result
+    pub challenge_weight: Decimal,
+    pub voter_count: u64,
+    pub total_weight_cast: Decimal,
+    pub quorum_reached: bool,
+    pub eligible_weight: Decimal,
+    pub weight_cap: Option<Decimal>,
+    pub early_vote_bonus: Option<EarlyVoteBonus>,
+    pub adaptive_approval_threshold: Option<AdaptiveApprovalThreshold>,
+    pub delegate_notice_hours: u16,
+    pub min_balance_to_vote: Option<Decimal>,
+    pub vote_change_policy: VoteChangePolicy,
+    pub abstain_option: Option<ProposalVoteOptionId>,
+    pub abstain_quorum_weight: AbstainQuorumWeight,
+    pub abstain_only_weight: Decimal,
+    pub option_weights: Vec<Decimal>,
+    pub raw_option_weights: Vec<Decimal>,
+    pub raw_total_weight_cast: Decimal,
+    pub option_voter_counts: Vec<u64>,
+    pub status: ProposalStatus,
+    pub finalized_at: Option<Instant>,
+    pub max_storage_reimbursement: Decimal,
+    pub storage_reimbursed: Decimal,
+    pub executed_at: Option<Instant>,
+    pub reconfirm_after_days: Option<u16>,
+    pub reconfirmation_id: Option<u64>,
+    pub reconfirmation_of: Option<u64>,
+    pub execution_approvals: Vec<NonFungibleLocalId>,
+    pub rollback_deadline: Option<Instant>,
+    pub rolled_back_at: Option<Instant>,
+    pub response_count: u64,
+    pub reaction_counts: Vec<u64>,
 }
 
-/// Emitted when a vote is cast on a proposal
-#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
-pub struct ProposalVotedEvent {
-    pub proposal_id: u64,
-    pub account: Global<Account>,
-    pub votes: Vec<ProposalVoteOptionId>,
+impl From<&Proposal> for ProposalView {
+    fn from(proposal: &Proposal) -> Self {
+        Self {
+            title: proposal.title.clone(),
+            short_description: proposal.short_description.clone(),
+            description: proposal.description.clone(),
+            description_file: proposal.description_file.clone(),
+            attachments: proposal.attachments.clone(),
+            kind: proposal.kind.clone(),
+            action: proposal.action.clone(),
+            vote_bond: proposal.vote_bond,
+            vote_options: proposal.vote_options.clone(),
+            links: proposal.links.clone(),
+            quorum: proposal.quorum,
+            max_selections: proposal.max_selections,
+            approval_threshold: proposal.approval_threshold,
+            start: proposal.start,
+            deadline: proposal.deadline,
+            temperature_check_id: proposal.temperature_check_id,
+            reopened_from: proposal.reopened_from,
+            reopened_as: proposal.reopened_as,
+            carry_over_factor: proposal.carry_over_factor,
+            author: proposal.author,
+            co_authors: proposal.co_authors.clone(),
+            members_only: proposal.members_only,
+            anonymous: proposal.anonymous,
+            eligibility_root: proposal.eligibility_root,
+            allow_rationales: proposal.allow_rationales,
+            questions: proposal.questions.clone(),
+            voting_mode: proposal.voting_mode,
+            allocation: proposal.allocation.clone(),
+            last_vote_at: proposal.last_vote_at,
+            delegation_snapshot: proposal.delegation_snapshot,
+            signal_deadline: proposal.signal.as_ref().map(|signal| signal.deadline),
+            challenge_window_end: proposal.challenge.as_ref().map(|challenge| challenge.window_end),
+            challenge_weight: proposal
+                .challenge
+                .as_ref()
+                .map_or(Decimal::ZERO, |challenge| challenge.weight),
+            voter_count: proposal.voter_count,
+            total_weight_cast: proposal.total_weight_cast,
+            quorum_reached: proposal.quorum_reached,
+            eligible_weight: proposal.eligible_weight,
+            weight_cap: proposal.weight_cap,
+            early_vote_bonus: proposal.early_vote_bonus,
+            adaptive_approval_threshold: proposal.adaptive_approval_threshold,
+            delegate_notice_hours: proposal.delegate_notice_hours,
+            min_balance_to_vote: proposal.min_balance_to_vote,
+            vote_change_policy: proposal.vote_change_policy,
+            abstain_option: proposal.abstain_option,
+            abstain_quorum_weight: proposal.abstain_quorum_weight,
+            abstain_only_weight: proposal.abstain_only_weight,
+            option_weights: proposal.option_weights.clone(),
+            raw_option_weights: proposal.raw_option_weights.clone(),
+            raw_total_weight_cast: proposal.raw_total_weight_cast,
+            option_voter_counts: proposal.option_voter_counts.clone(),
+            status: proposal.status,
+            finalized_at: proposal.finalized_at,
+            max_storage_reimbursement: proposal.max_storage_reimbursement,
+            storage_reimbursed: proposal.storage_reimbursed,
+            executed_at: proposal.executed_at,
+            reconfirm_after_days: proposal.reconfirm_after_days,
+            reconfirmation_id: proposal.reconfirmation_id,
+            reconfirmation_of: proposal.reconfirmation_of,
+            execution_approvals: proposal.execution_approvals.clone(),
+            rollback_deadline: proposal.rollback.as_ref().map(|rollback| rollback.deadline),
+            rolled_back_at: proposal.rollback.as_ref().and_then(|rollback| rollback.rolled_back_at),
+            response_count: proposal.response_count,
+            reaction_counts: proposal.reaction_counts.clone(),
+        }
+    }
 }
 
-/// Emitted when governance parameters are updated
-#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
-pub struct GovernanceParametersUpdatedEvent {
-    pub new_params: GovernanceParameters,
+impl ProposalView {
+    /// Clears the running tally, for private consultations still in progress
+    pub fn hide_tally(&mut self) {
+        self.voter_count = 0;
+        self.total_weight_cast = Decimal::ZERO;
+        self.quorum_reached = false;
+        self.raw_total_weight_cast = Decimal::ZERO;
+        self.option_weights = vec![Decimal::ZERO; self.option_weights.len()];
+        self.raw_option_weights = vec![Decimal::ZERO; self.raw_option_weights.len()];
+        self.option_voter_counts = vec![0; self.option_voter_counts.len()];
+        for question in self.questions.iter_mut() {
+            question.hide_tally();
+        }
+    }
 }
 
-/// Emitted when a delegation is created or updated
-#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
-pub struct DelegationCreatedEvent {
-    pub delegator: Global<Account>,
-    pub delegatee: Global<Account>,
-    pub fraction: Decimal,
-    pub valid_until: Instant,
+/// Membership of the allowlist used by private consultations
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct Membership {
+    pub added_at: Instant,
+    /// Set when the member is removed; the record is kept so past snapshots stay valid
+    pub removed_at: Option<Instant>,
 }
 
-/// Emitted when a delegation is removed
-#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
-pub struct DelegationRemovedEvent {
-    pub delegator: Global<Account>,
-    pub delegatee: Global<Account>,
+/// Voting group of the registry, e.g. a regional chapter with an allocated voting bloc
+#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+pub struct VotingGroup {
+    pub name: String,
+    /// Weight the group's majority casts on `VotingMode::Groups` proposals
+    pub bloc_weight: Decimal,
+    pub member_count: u64,
+}
+
+/// An account's membership of a voting group
+#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+pub struct VotingGroupMembership {
+    pub group_id: u64,
+    /// Members only vote on `Groups` proposals created after they joined
+    pub added_at: Instant,
+}
+
+/// Intra-group tally of a voting group on a `VotingMode::Groups` proposal
+#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+pub struct GroupTally {
+    /// Bloc weight of the group, captured at its first vote on the proposal
+    pub bloc_weight: Decimal,
+    /// Number of members selecting each option, indexed by option ID
+    pub option_votes: Vec<u64>,
+    pub voter_count: u64,
+    /// Option the bloc weight counts for: the one most members selected, None while tied
+    pub majority: Option<ProposalVoteOptionId>,
+}
+
+/// Running counters of governance activity, kept because the stores cannot be iterated
+#[derive(ScryptoSbor, Clone, Debug, Default)]
+pub struct GovernanceActivity {
+    pub passed_proposals: u64,
+    pub rejected_proposals: u64,
+    pub lapsed_proposals: u64,
+    pub disputed_proposals: u64,
+    /// Distinct accounts that ever voted directly on a temperature check or proposal
+    pub unique_voters: u64,
+    /// Weight ever cast on proposals, directly or by delegatees (overridden delegated power excluded)
+    pub total_weight_cast: Decimal,
+}
+
+/// One-call summary of the component for dashboards
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct GovernanceStats {
+    pub temperature_check_count: u64,
+    pub proposal_count: u64,
+    /// Proposals not finalized yet
+    pub active_proposals: u64,
+    pub passed_proposals: u64,
+    pub rejected_proposals: u64,
+    pub lapsed_proposals: u64,
+    pub disputed_proposals: u64,
+    pub unique_voters: u64,
+    pub total_weight_cast: Decimal,
+    pub parameters: GovernanceParameters,
+}
+
+/// A direct vote on a temperature check as stored, with the weight it was counted at
+#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+pub struct TemperatureCheckVoteRecord {
+    pub vote: TemperatureCheckVote,
+    /// Voting power of the account when it voted
+    pub weight: Decimal,
+    pub cast_at: Instant,
+}
+
+/// A direct vote on a proposal as stored, with the weight it was counted at
+#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+pub struct ProposalVoteRecord {
+    /// Selected options, empty for anonymous proposals where the record only marks that the
+    /// account voted
+    pub votes: Vec<ProposalVoteOptionId>,
+    /// The account's own weight as counted, after the early vote multiplier
+    pub weight: Decimal,
+    pub cast_at: Instant,
+}
+
+/// Voting intention a delegatee announced on a proposal before casting delegated power
+#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+pub struct IntendedVote {
+    pub votes: Vec<ProposalVoteOptionId>,
+    /// Statement explaining the intention, stored on a trusted storage component
+    pub statement_file: Option<File>,
+    pub announced_at: Instant,
+}
+
+/// Raw record of a direct vote on a proposal, returned to auditors by `dump_votes`
+#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+pub struct VoteRecord {
+    pub account: Global<Account>,
+    /// Selected options, empty for anonymous proposals
+    pub votes: Vec<ProposalVoteOptionId>,
+    /// The account's own weight as counted, after the early vote multiplier
+    pub weight: Decimal,
+    /// Early vote multiplier the vote got (1 outside the early vote window)
+    pub early_vote_multiplier: Decimal,
+    /// Own weight plus the delegated power the account cast, before the weight cap
+    pub effective_weight: Decimal,
+    /// Delegators whose power the account cast, with the weight of each
+    pub delegators: Vec<(Global<Account>, Decimal)>,
+    /// Vote bond the account locked and has not reclaimed yet
+    pub bond: Decimal,
+    pub rationale: Option<VoteRationale>,
+}
+
+/// Outcome of a vote that has not been submitted, see `preview_vote`
+#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+pub struct VotePreview {
+    /// Why the vote would be rejected, None if it would succeed
+    pub failure_reason: Option<GovernanceError>,
+    /// Voting power the vote would be counted with
+    pub weight: Decimal,
+    /// Delegated weight of the account already cast by delegatees, which the vote would replace
+    pub overridden_delegated_weight: Decimal,
+    /// Weight carried over from a reopened proposal, which the vote would replace
+    pub replaced_carried_over_weight: Decimal,
+}
+
+/// Part of a delegator's voting power cast by a delegatee on a proposal
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct DelegatedVote {
+    pub delegatee: Global<Account>,
+    pub weight: Decimal,
+}
+
+/// Contribution carried over into a reopened proposal from the proposal it reopens
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct CarriedOverVote {
+    pub votes: Vec<ProposalVoteOptionId>,
+    /// Decayed weight counted in the tally, after the origin's weight cap
+    pub weight: Decimal,
+    /// Decayed weight before the weight cap
+    pub raw_weight: Decimal,
+    /// Whether this is the account's own direct vote, counted in the carried voter counts
+    pub direct: bool,
+}
+
+/// Participation figures for a proposal, maintained incrementally as votes are cast
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub struct ProposalTurnout {
+    pub voter_count: u64,
+    pub total_weight_cast: Decimal,
+    pub eligible_weight: Decimal,
+    /// total_weight_cast / eligible_weight (zero if nothing is eligible)
+    pub turnout_fraction: Decimal,
+}
+
+/// Weight and direct voter count of a single proposal option
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub struct OptionTally {
+    pub option_id: ProposalVoteOptionId,
+    /// Weight counted towards the result, after the weight cap
+    pub weight: Decimal,
+    /// Weight cast before the weight cap
+    pub raw_weight: Decimal,
+    pub voter_count: u64,
+}
+
+/// Structured result of a proposal vote
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub struct ProposalTally {
+    pub options: Vec<OptionTally>,
+    /// Weight counted towards the quorum: the weight cast, with abstain-only weight counted
+    /// as the proposal's `abstain_quorum_weight` says
+    pub quorum_weight: Decimal,
+    pub quorum_met: bool,
+    /// Whether the leading option holds at least the approval threshold of the weight cast
+    pub threshold_met: bool,
+    /// Approval threshold applied, after the turnout adjustment
+    pub approval_threshold: Decimal,
+    /// Option(s) with the highest weight (several on a tie, none if nothing was cast)
+    pub winning_options: Vec<ProposalVoteOptionId>,
+    pub turnout: ProposalTurnout,
+    /// Weight cap per account applied to the tally, None if uncapped
+    pub weight_cap: Option<Decimal>,
+    /// Total weight cast before the weight cap (`turnout.total_weight_cast` is after it)
+    pub raw_total_weight_cast: Decimal,
+}
+
+/// Version of the `ResultAttestation` encoding, bumped whenever its fields change
+pub const RESULT_ATTESTATION_VERSION: u8 = 1;
+
+/// Result of a finalized proposal for off-chain oracles to sign, returned SBOR-encoded by
+/// `get_result_attestation`
+/// The encoding is the Scrypto SBOR encoding of this struct, fields in declaration order;
+/// decode it with `scrypto_decode::<ResultAttestation>` after checking `version`.
+#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+pub struct ResultAttestation {
+    /// `RESULT_ATTESTATION_VERSION` at the time of encoding
+    pub version: u8,
+    /// The governance component the proposal belongs to
+    pub governance: ComponentAddress,
+    pub proposal_id: u64,
+    /// Status at the time of the call (a passed result can later be disputed or lapse)
+    pub status: ProposalStatus,
+    /// Tallied weight per option, indexed by option ID
+    pub option_weights: Vec<Decimal>,
+    pub total_weight_cast: Decimal,
+    pub winning_options: Vec<ProposalVoteOptionId>,
+    pub finalized_at: Instant,
+}
+
+// =============================================================================
+// Delegation Types
+// =============================================================================
+
+/// Represents a delegation from one account to another
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct Delegation {
+    pub delegatee: Global<Account>,
+    pub fraction: Decimal,
+    pub valid_until: Instant,
+    pub mode: DelegationMode,
+    pub scope: DelegationScope,
+}
+
+/// Delegation a delegator authorizes off-ledger by signing `delegation_authorization_hash`,
+/// submitted by anyone with `make_delegation_signed`
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub struct DelegationAuthorization {
+    /// VoteDelegation component the authorization is for, so it cannot be replayed elsewhere
+    pub component: ComponentAddress,
+    pub delegator: ComponentAddress,
+    pub delegatee: ComponentAddress,
+    pub fraction: Decimal,
+    pub valid_until: Instant,
+    /// Must equal the delegator's next nonce (`get_nonce`), so each signature is used once
+    pub nonce: u64,
+}
+
+/// Signature of a delegator's account key over a `DelegationAuthorization`
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub enum DelegationSignature {
+    Secp256k1 {
+        public_key: Secp256k1PublicKey,
+        signature: Secp256k1Signature,
+    },
+    Ed25519 {
+        public_key: Ed25519PublicKey,
+        signature: Ed25519Signature,
+    },
+}
+
+/// Message signed for a `DelegationAuthorization`: the hash of its SBOR encoding
+pub fn delegation_authorization_hash(authorization: &DelegationAuthorization) -> Hash {
+    hash(scrypto_encode(authorization).unwrap())
+}
+
+/// Per-account nonces of the signature-based entry points, so relayed messages cannot be
+/// replayed: each account's nonces are consumed strictly in order, starting at 0
+#[derive(ScryptoSbor)]
+pub struct NonceRegistry {
+    /// Key: account
+    /// Value: the account's next nonce (absent means 0)
+    pub next_nonces: KeyValueStore<Global<Account>, u64>,
+}
+
+impl Default for NonceRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NonceRegistry {
+    pub fn new() -> Self {
+        Self {
+            next_nonces: KeyValueStore::new(),
+        }
+    }
+
+    /// The nonce the account's next signed message must use
+    pub fn next_nonce(&self, account: Global<Account>) -> u64 {
+        self.next_nonces.get(&account).map_or(0, |nonce| *nonce)
+    }
+
+    /// Consumes `nonce` if it is the account's next nonce; returns false (consuming nothing)
+    /// otherwise
+    pub fn consume(&mut self, account: Global<Account>, nonce: u64) -> bool {
+        let next = self.next_nonce(account);
+        if nonce != next {
+            return false;
+        }
+        self.next_nonces.insert(account, next + 1);
+        true
+    }
+}
+
+/// Limits a delegatee sets on the delegations it accepts (`None` means unlimited)
+#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq, Default)]
+pub struct DelegateeLimits {
+    /// Maximum number of delegators, counting expired delegations until they are pruned
+    pub max_delegators: Option<u64>,
+    /// Maximum fraction a single delegator can delegate
+    pub max_fraction_per_delegator: Option<Decimal>,
+}
+
+/// Delegations of a delegator, one entry per delegatee so a change only writes that entry
+#[derive(ScryptoSbor)]
+pub struct DelegatorDelegations {
+    pub by_delegatee: KeyValueStore<Global<Account>, Delegation>,
+    /// Delegatees with a stored delegation, in the order they were first delegated to
+    /// (at most `MAX_DELEGATIONS`)
+    pub delegatees: Vec<Global<Account>>,
+}
+
+/// Which votes a delegation applies to
+#[derive(ScryptoSbor, ManifestSbor, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DelegationScope {
+    TemperatureChecksOnly,
+    ProposalsOnly,
+    Both,
+}
+
+impl DelegationScope {
+    pub fn covers_temperature_checks(&self) -> bool {
+        *self != DelegationScope::ProposalsOnly
+    }
+
+    pub fn covers_proposals(&self) -> bool {
+        *self != DelegationScope::TemperatureChecksOnly
+    }
+
+    /// Whether two scopes apply to a common kind of vote, so their fractions add up
+    pub fn overlaps(&self, other: DelegationScope) -> bool {
+        (self.covers_temperature_checks() && other.covers_temperature_checks())
+            || (self.covers_proposals() && other.covers_proposals())
+    }
+}
+
+/// Which part of the delegator's voting power a delegation's fraction applies to
+#[derive(ScryptoSbor, ManifestSbor, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DelegationMode {
+    /// The fraction applies to the delegator's full voting power; a direct vote by the
+    /// delegator replaces the delegated contribution
+    Full,
+    /// The fraction applies only to the power above a proposal's weight cap, which the
+    /// delegator could not use itself. The delegator keeps voting directly with the rest.
+    ExcessAboveCap,
+    /// "Proxy everything I don't vote on": the full power (fraction 1), used only on proposals
+    /// where the delegator has no other delegation. At most one per delegator; it does not count
+    /// towards the 100% total and can sit alongside delegations of either other mode.
+    DefaultProxy,
+}
+
+/// A balance recorded by `BalanceCheckpoints`
+#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+pub struct BalanceCheckpoint {
+    pub balance: Decimal,
+    pub recorded_at: Instant,
+}
+
+/// A delegator's full list of delegations as of a point in time
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct DelegationCheckpoint {
+    /// When this list of delegations came into effect
+    pub at: Instant,
+    pub delegations: Vec<Delegation>,
+}
+
+/// Sum of the fractions delegated to a delegatee at the end of an epoch
+#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+pub struct IncomingPowerCheckpoint {
+    pub epoch: Epoch,
+    pub total_fraction: Decimal,
+}
+
+/// Aggregate statistics about the delegation graph, maintained incrementally.
+/// Expired delegations are counted until they are pruned.
+#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+pub struct DelegationStats {
+    /// Number of stored delegator -> delegatee links
+    pub total_active_delegations: u64,
+    /// Number of accounts with at least one outgoing delegation
+    pub unique_delegators: u64,
+    /// Number of accounts with at least one incoming delegation
+    pub unique_delegatees: u64,
+    /// Delegatee with the most incoming delegations and its incoming count (any one on ties)
+    pub largest_delegatee: Option<(Global<Account>, u64)>,
+}
+
+/// Delegatees sharing the same incoming delegation count, so the largest delegatee can be
+/// found again after its count drops
+#[derive(ScryptoSbor)]
+pub struct IncomingCountBucket {
+    /// Key: position in the bucket, Value: delegatee
+    pub delegatees: KeyValueStore<u64, Global<Account>>,
+    /// Number of delegatees in the bucket
+    pub size: u64,
+}
+
+// =============================================================================
+// Events
+// =============================================================================
+
+/// Emitted when a temperature check is created
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct TemperatureCheckCreatedEvent {
+    pub temperature_check_id: u64,
+    pub title: String,
+    /// The vote options with their assigned IDs
+    pub vote_options: Vec<ProposalVoteOption>,
+    pub max_selections: Option<u32>,
+    pub voting_mode: VotingMode,
+    /// External links related to the proposal, such as the RFC
+    pub links: Vec<Url>,
+    pub quorum: Decimal,
+    pub approval_threshold: Decimal,
+    pub start: Instant,
+    pub deadline: Instant,
+}
+
+/// Emitted when an account stakes its voting power as endorsement of a temperature check
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct TemperatureCheckSecondedEvent {
+    pub temperature_check_id: u64,
+    pub account: Global<Account>,
+    pub weight: Decimal,
+}
+
+/// Emitted when an account co-sponsors a temperature check
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct TemperatureCheckSponsoredEvent {
+    pub temperature_check_id: u64,
+    pub account: Global<Account>,
+    pub sponsor_count: u32,
+}
+
+/// Emitted when a vote is cast on a temperature check
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct TemperatureCheckVotedEvent {
+    pub temperature_check_id: u64,
+    pub account: Global<Account>,
+    pub vote: TemperatureCheckVote,
+    /// Voting weight of the vote
+    pub weight: Decimal,
+    /// Running totals after the vote (zero for members-only temperature checks)
+    pub for_weight: Decimal,
+    pub against_weight: Decimal,
+}
+
+/// Emitted when a delegatee casts the delegated power of one or more delegators on a temperature check
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct TemperatureCheckDelegatedVotesCastEvent {
+    pub temperature_check_id: u64,
+    pub delegatee: Global<Account>,
+    /// Each delegator with the weight cast on their behalf
+    pub delegators: Vec<(Global<Account>, Decimal)>,
+}
+
+/// Emitted when a temperature check is finalized
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct TemperatureCheckFinalizedEvent {
+    pub temperature_check_id: u64,
+    pub status: TemperatureCheckStatus,
+    pub for_weight: Decimal,
+    pub against_weight: Decimal,
+}
+
+/// Emitted when a temperature check is elevated to a proposal
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct ProposalCreatedEvent {
+    pub proposal_id: u64,
+    pub temperature_check_id: u64,
+    pub title: String,
+    /// The vote options with their assigned IDs
+    pub vote_options: Vec<ProposalVoteOption>,
+    pub max_selections: Option<u32>,
+    pub voting_mode: VotingMode,
+    /// External links related to the proposal, such as the RFC
+    pub links: Vec<Url>,
+    pub quorum: Decimal,
+    pub approval_threshold: Decimal,
+    pub start: Instant,
+    pub deadline: Instant,
+}
+
+/// Emitted when the owner elevates a passed temperature check after its deadline
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct TemperatureCheckElevatedEvent {
+    pub temperature_check_id: u64,
+    pub proposal_id: u64,
+}
+
+/// Emitted when the owner elevates a temperature check without the deadline and result checks
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct ProposalForceElevatedEvent {
+    pub temperature_check_id: u64,
+    pub proposal_id: u64,
+    /// Status of the temperature check at the time it was force elevated
+    pub temperature_check_status: TemperatureCheckStatus,
+}
+
+/// Emitted when a finalized proposal is reopened with its votes carried over at a decayed weight
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct ProposalReopenedEvent {
+    pub proposal_id: u64,
+    pub reopened_from: u64,
+    pub carry_over_factor: Decimal,
+}
+
+/// Emitted when a passed proposal is marked as executed
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct ProposalExecutedEvent {
+    pub proposal_id: u64,
+    pub executed_at: Instant,
+    pub reconfirm_after_days: Option<u16>,
+}
+
+/// Emitted when the security role rolls back an executed parameter change
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct ExecutionRolledBackEvent {
+    pub proposal_id: u64,
+    pub rolled_back_at: Instant,
+}
+
+/// Emitted when a passed funding proposal is executed, for the treasury to pay out
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct FundingApprovedEvent {
+    pub proposal_id: u64,
+    pub amount: Decimal,
+    pub recipient: ComponentAddress,
+}
+
+/// Emitted when a component is registered as executor
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct ExecutorRegisteredEvent {
+    pub executor_id: u64,
+    pub component: ComponentAddress,
+}
+
+/// Emitted when an executor is removed from the registry
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct ExecutorRemovedEvent {
+    pub executor_id: u64,
+}
+
+/// Emitted when executing a proposal dispatches its action to an executor
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct ExecutorCalledEvent {
+    pub proposal_id: u64,
+    pub executor_id: u64,
+    pub component: ComponentAddress,
+}
+
+/// Emitted when an executor approves executing a passed proposal
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct ExecutionApprovedEvent {
+    pub proposal_id: u64,
+    pub executor: NonFungibleLocalId,
+    pub approvals: u32,
+}
+
+/// Emitted when the execution approval requirement is changed
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct ExecutionApprovalConfigUpdatedEvent {
+    pub config: Option<ExecutionApprovalConfig>,
+}
+
+/// Emitted when a re-confirmation proposal is spawned for an executed decision
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct ReconfirmationSpawnedEvent {
+    pub proposal_id: u64,
+    pub reconfirmation_of: u64,
+}
+
+/// Emitted when an executed decision lapses because its re-confirmation failed
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct ProposalLapsedEvent {
+    pub proposal_id: u64,
+    pub reconfirmation_id: u64,
+}
+
+/// Emitted when a finalized proposal is moved to the archive
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct ProposalArchivedEvent {
+    pub proposal_id: u64,
+    pub status: ProposalStatus,
+}
+
+/// Emitted by the vote that first brings a proposal's weight cast to its quorum
+/// Not emitted for private consultations, whose tallies are hidden until finalization
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct QuorumReachedEvent {
+    pub proposal_id: u64,
+    pub quorum: Decimal,
+    pub total_weight_cast: Decimal,
+    pub reached_at: Instant,
+}
+
+/// Emitted when a vote is cast in the signal window of a two-phase proposal
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct ProposalSignalVotedEvent {
+    pub proposal_id: u64,
+    pub account: Global<Account>,
+    /// Empty for anonymous proposals
+    pub votes: Vec<ProposalVoteOptionId>,
+}
+
+/// Emitted when an account challenges the result of a passed proposal
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct ResultChallengedEvent {
+    pub proposal_id: u64,
+    pub account: Global<Account>,
+    pub weight: Decimal,
+    /// Voting power of all challengers so far
+    pub challenge_weight: Decimal,
+}
+
+/// Emitted when challenges reach the threshold and a passed proposal becomes Disputed
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct ProposalDisputedEvent {
+    pub proposal_id: u64,
+    pub challenge_weight: Decimal,
+}
+
+/// Emitted when a winning-side voter claims their share of a failed challenge's slashed bonds
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct DisputeRewardClaimedEvent {
+    pub proposal_id: u64,
+    pub account: Global<Account>,
+    pub amount: Decimal,
+}
+
+/// Emitted when the owner sweeps the dispute rewards left unclaimed after the claim period
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct DisputeRewardsSweptEvent {
+    pub proposal_id: u64,
+    pub amount: Decimal,
+}
+
+/// Emitted when a challenger reclaims their challenge bond
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct ChallengeBondReclaimedEvent {
+    pub proposal_id: u64,
+    pub account: Global<Account>,
+    pub amount: Decimal,
+}
+
+/// Emitted when a voter reclaims their vote bond after finalization
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct VoteBondReclaimedEvent {
+    pub proposal_id: u64,
+    pub account: Global<Account>,
+    pub amount: Decimal,
+}
+
+/// Emitted when a direct voter changes its selection on a proposal
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct ProposalVoteChangedEvent {
+    pub proposal_id: u64,
+    pub account: Global<Account>,
+    pub previous_votes: Vec<ProposalVoteOptionId>,
+    pub votes: Vec<ProposalVoteOptionId>,
+}
+
+/// Emitted when a vote is cast on a proposal
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct ProposalVotedEvent {
+    pub proposal_id: u64,
+    pub account: Global<Account>,
+    /// Empty for anonymous proposals
+    pub votes: Vec<ProposalVoteOptionId>,
+    /// Points given to each option, for points allocation proposals (empty otherwise and for
+    /// anonymous proposals)
+    pub points: Vec<(ProposalVoteOptionId, u32)>,
+    /// The account's own voting weight, after the early vote multiplier and before the weight cap
+    pub weight: Decimal,
+    /// The account's own voting weight before the early vote multiplier
+    pub base_weight: Decimal,
+    /// Early vote multiplier the vote got (1 outside the early vote window)
+    pub early_vote_multiplier: Decimal,
+    /// Delegated power already cast through the account, before the weight cap. Informational:
+    /// it was attributed by the `DelegatedVotesCastEvent` that cast it
+    pub delegated_weight: Decimal,
+    /// Running weight per option after the vote, after the weight cap
+    /// (empty for anonymous and members-only proposals)
+    pub option_weights: Vec<Decimal>,
+    /// Rationale attached to the vote, if any
+    pub rationale: Option<VoteRationale>,
+}
+
+/// Emitted alongside `ProposalVotedEvent` / `DelegatedVotesCastEvent` when a single direct vote or
+/// a single delegator's cast power exceeds the `large_vote_fraction` of the proposal's quorum
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct LargeVoteEvent {
+    pub proposal_id: u64,
+    /// The direct voter, or the delegator whose power was cast
+    pub account: Global<Account>,
+    /// The delegatee that cast the power, None for direct votes
+    pub delegatee: Option<Global<Account>>,
+    pub weight: Decimal,
+    /// Selected options (empty for anonymous proposals)
+    pub votes: Vec<ProposalVoteOptionId>,
+}
+
+/// Emitted when a delegatee casts the delegated power of one or more delegators on a proposal
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct DelegatedVotesCastEvent {
+    pub proposal_id: u64,
+    pub delegatee: Global<Account>,
+    /// Each delegator with the weight cast on their behalf
+    pub delegators: Vec<(Global<Account>, Decimal)>,
+}
+
+/// Emitted when an account responds to a survey (the response is listable after the deadline)
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct SurveyResponseSubmittedEvent {
+    pub proposal_id: u64,
+    pub account: Global<Account>,
+    pub response_count: u64,
+}
+
+/// Emitted when an account reacts to a proposal, or changes its reaction
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct ProposalReactionEvent {
+    pub proposal_id: u64,
+    pub account: Global<Account>,
+    pub reaction: u8,
+    /// The account's replaced reaction, if it had reacted before
+    pub previous: Option<u8>,
+}
+
+/// Emitted when a delegatee announces how it intends to vote on a proposal
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct IntendedVoteAnnouncedEvent {
+    pub proposal_id: u64,
+    pub delegatee: Global<Account>,
+    pub votes: Vec<ProposalVoteOptionId>,
+    pub statement_file: Option<File>,
+    /// When the delegatee can start casting delegated power
+    pub castable_from: Instant,
+}
+
+/// Emitted when a delegator votes directly after a delegatee already cast their power,
+/// replacing the delegated contribution with the direct vote
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct DelegatedVoteOverriddenEvent {
+    pub proposal_id: u64,
+    pub delegator: Global<Account>,
+    pub delegatee: Global<Account>,
+    /// Weight removed from the delegatee's choices
+    pub weight: Decimal,
+}
+
+/// Emitted when a proposal is finalized, carrying the full tally so off-chain
+/// consumers can act on the outcome without further queries
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct ProposalFinalizedEvent {
+    pub proposal_id: u64,
+    pub status: ProposalStatus,
+    pub tally: ProposalTally,
+    /// Results of the extra ballot questions, each finalized on its own
+    pub questions: Vec<BallotQuestion>,
+    /// Normalized allocation of points allocation proposals, empty otherwise
+    pub allocation: Vec<Decimal>,
+    pub finalized_at: Instant,
+}
+
+/// Emitted when governance parameters are updated
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct GovernanceParametersUpdatedEvent {
+    pub old_params: GovernanceParameters,
+    pub new_params: GovernanceParameters,
+}
+
+/// Emitted when the author of a passed proposal is reimbursed for attachment storage fees
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct StorageReimbursementClaimedEvent {
+    pub proposal_id: u64,
+    /// The author or co-author that claimed the reimbursement
+    pub author: Global<Account>,
+    pub amount: Decimal,
+}
+
+/// Emitted when the voting power configuration is updated
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct VotingPowerConfigUpdatedEvent {
+    pub config: VotingPowerConfig,
+}
+
+/// Emitted when the draft limits are updated
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct GovernanceLimitsUpdatedEvent {
+    pub limits: GovernanceLimits,
+}
+
+/// Emitted when the fee subsidy limits are updated
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct FeeSubsidyConfigUpdatedEvent {
+    pub config: Option<FeeSubsidyConfig>,
+}
+
+/// Emitted when an account is barred from voting and creating temperature checks
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct AccountDenylistedEvent {
+    pub account: Global<Account>,
+    pub reason: String,
+}
+
+/// Emitted when a file storage component is added to the trusted storage allowlist
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct TrustedStorageAddedEvent {
+    pub component: ComponentAddress,
+}
+
+/// Emitted when a file storage component is removed from the trusted storage allowlist
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct TrustedStorageRemovedEvent {
+    pub component: ComponentAddress,
+}
+
+/// Emitted when an account issues an authorized signer badge
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct SignerBadgeIssuedEvent {
+    pub account: Global<Account>,
+    pub badge_id: NonFungibleLocalId,
+}
+
+/// Emitted when an account revokes an authorized signer badge
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct SignerBadgeRevokedEvent {
+    pub account: Global<Account>,
+    pub badge_id: NonFungibleLocalId,
+}
+
+/// Emitted when an account is added to the member allowlist
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct MemberAddedEvent {
+    pub account: Global<Account>,
+}
+
+/// Emitted when an account is removed from the member allowlist
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct MemberRemovedEvent {
+    pub account: Global<Account>,
+}
+
+/// Emitted when a voting group is registered or its bloc weight is changed
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct VotingGroupUpdatedEvent {
+    pub group_id: u64,
+    pub name: String,
+    pub bloc_weight: Decimal,
+}
+
+/// Emitted when an account joins a voting group
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct VotingGroupMemberAddedEvent {
+    pub group_id: u64,
+    pub account: Global<Account>,
+}
+
+/// Emitted when an account leaves a voting group
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct VotingGroupMemberRemovedEvent {
+    pub group_id: u64,
+    pub account: Global<Account>,
+}
+
+/// Emitted when a group member votes on a `VotingMode::Groups` proposal
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct GroupVoteCastEvent {
+    pub proposal_id: u64,
+    pub group_id: u64,
+    pub account: Global<Account>,
+    pub votes: Vec<ProposalVoteOptionId>,
+    /// Option the group's bloc weight counts for after this vote
+    pub majority: Option<ProposalVoteOptionId>,
+}
+
+/// Emitted when an account is removed from the denylist
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct AccountRemovedFromDenylistEvent {
+    pub account: Global<Account>,
+}
+
+/// Emitted when the account age oracle configuration is set or cleared
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct AccountAgeConfigUpdatedEvent {
+    pub config: Option<AccountAgeConfig>,
+}
+
+/// Emitted when the identity verifier component is set or cleared
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct IdentityVerifierUpdatedEvent {
+    pub identity_verifier: Option<ComponentAddress>,
+}
+
+/// Emitted when the component branding is updated
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct GovernanceBrandingUpdatedEvent {
+    pub branding: GovernanceBranding,
+}
+
+/// Emitted when a delegation is created or updated
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct DelegationCreatedEvent {
+    pub delegator: Global<Account>,
+    pub delegatee: Global<Account>,
+    pub fraction: Decimal,
+    pub valid_until: Instant,
+    pub mode: DelegationMode,
+    pub scope: DelegationScope,
+}
+
+/// Emitted when the fraction of an existing delegation is changed in place
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct DelegationUpdatedEvent {
+    pub delegator: Global<Account>,
+    pub delegatee: Global<Account>,
+    pub old_fraction: Decimal,
+    pub new_fraction: Decimal,
+    /// The original expiry, which is kept
+    pub valid_until: Instant,
+}
+
+/// Emitted when a delegation is removed
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct DelegationRemovedEvent {
+    pub delegator: Global<Account>,
+    pub delegatee: Global<Account>,
+}
+
+/// Emitted when a delegatee changes the limits on its incoming delegations
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct DelegateeLimitsUpdatedEvent {
+    pub delegatee: Global<Account>,
+    pub limits: DelegateeLimits,
+}
+
+/// Emitted when the owner or governance voids every delegation to an account
+/// (each voided delegation also emits `DelegationRemovedEvent`)
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct IncomingDelegationsRevokedEvent {
+    pub delegatee: Global<Account>,
+    pub delegators: Vec<Global<Account>>,
+    pub reason: String,
+}
+
+/// Emitted when a delegatee moves all incoming delegations to a new account
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct DelegateeMigratedEvent {
+    pub old_delegatee: Global<Account>,
+    pub new_delegatee: Global<Account>,
+    pub delegators: Vec<Global<Account>>,
 }
@@ -0,0 +1,93 @@
+//! Manifest builders for the common Governance and VoteDelegation flows, for off-chain tooling
+//! and tests (enabled with the `manifests` feature).
+//!
+//! The manifests lock no fee: wallets add the fee lock, and a vote relying on the fee subsidy
+//! must be the first call of its manifest. Accounts prove their presence through the
+//! transaction's signatures.
+
+use radix_transactions::prelude::*;
+
+use crate::{DelegationScope, ProposalVoteOptionId, TemperatureCheckVote};
+
+/// Builds a manifest voting on a temperature check with `vote_on_temperature_check`
+pub fn build_temperature_check_vote_manifest(
+    governance: ComponentAddress,
+    account: ComponentAddress,
+    temperature_check_id: u64,
+    vote: TemperatureCheckVote,
+) -> TransactionManifestV1 {
+    ManifestBuilder::new()
+        .call_method(
+            governance,
+            "vote_on_temperature_check",
+            manifest_args!(account, temperature_check_id, vote),
+        )
+        .build()
+}
+
+/// Builds a manifest voting on a proposal with `vote_on_proposal`
+pub fn build_vote_manifest(
+    governance: ComponentAddress,
+    account: ComponentAddress,
+    proposal_id: u64,
+    votes: Vec<ProposalVoteOptionId>,
+) -> TransactionManifestV1 {
+    ManifestBuilder::new()
+        .call_method(
+            governance,
+            "vote_on_proposal",
+            manifest_args!(account, proposal_id, votes),
+        )
+        .build()
+}
+
+/// Builds a manifest casting the power of `delegators` on a proposal the delegatee already
+/// voted on, with `vote_on_proposal_as_delegatee`
+pub fn build_delegatee_vote_manifest(
+    governance: ComponentAddress,
+    delegatee: ComponentAddress,
+    proposal_id: u64,
+    delegators: Vec<ComponentAddress>,
+) -> TransactionManifestV1 {
+    ManifestBuilder::new()
+        .call_method(
+            governance,
+            "vote_on_proposal_as_delegatee",
+            manifest_args!(delegatee, proposal_id, delegators),
+        )
+        .build()
+}
+
+/// Builds a manifest delegating `fraction` of the delegator's power with `make_scoped_delegation`
+/// (`DelegationScope::Both` is what `make_delegation` uses)
+pub fn build_delegation_manifest(
+    vote_delegation: ComponentAddress,
+    delegator: ComponentAddress,
+    delegatee: ComponentAddress,
+    fraction: Decimal,
+    valid_until: Instant,
+    scope: DelegationScope,
+) -> TransactionManifestV1 {
+    ManifestBuilder::new()
+        .call_method(
+            vote_delegation,
+            "make_scoped_delegation",
+            manifest_args!(delegator, delegatee, fraction, valid_until, scope),
+        )
+        .build()
+}
+
+/// Builds a manifest removing a delegation with `remove_delegation`
+pub fn build_remove_delegation_manifest(
+    vote_delegation: ComponentAddress,
+    delegator: ComponentAddress,
+    delegatee: ComponentAddress,
+) -> TransactionManifestV1 {
+    ManifestBuilder::new()
+        .call_method(
+            vote_delegation,
+            "remove_delegation",
+            manifest_args!(delegator, delegatee),
+        )
+        .build()
+}
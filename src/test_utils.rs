@@ -0,0 +1,367 @@
+//! Ledger simulator helpers for testing Governance and VoteDelegation, shared with downstream
+//! blueprints integrating with them (enabled with the `test-utils` feature)
+
+use scrypto::prelude::Url;
+use scrypto_test::prelude::*;
+
+use crate::{
+    DeadlineRounding, GovernanceBranding, GovernanceParameters, ProposalKind, ProposalVoteOptionId, ProposalVoteOptionInput,
+    TemperatureCheckDraft, TemperatureCheckVote, VoteChangePolicy, VoteOptionColor, VotingMode,
+};
+
+/// Creates an owner badge and deposits it to a new account
+/// Returns (badge_address, owner_account, owner_public_key)
+pub fn create_owner_badge_with_account(
+    ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>
+) -> (ResourceAddress, ComponentAddress, Secp256k1PublicKey) {
+    let (public_key, _private_key, owner_account) = ledger.new_allocated_account();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_fungible_resource(
+            OwnerRole::None,
+            false,
+            0,
+            FungibleResourceRoles::default(),
+            metadata!(),
+            Some(dec!(1)),
+        )
+        .try_deposit_entire_worktop_or_abort(owner_account, None)
+        .build();
+
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    receipt.expect_commit_success();
+    let owner_badge = receipt.expect_commit(true).new_resource_addresses()[0];
+
+    (owner_badge, owner_account, public_key)
+}
+
+pub fn create_governance_parameters() -> GovernanceParameters {
+    GovernanceParameters {
+        temperature_check_days: 7,
+        temperature_check_quorum: dec!(1000),
+        temperature_check_approval_threshold: dec!("0.5"),
+        temperature_check_propose_threshold: dec!(1000),
+        temperature_check_sponsor_threshold: 0,
+        proposal_length_days: 14,
+        proposal_quorum: dec!(5000),
+        proposal_approval_threshold: dec!("0.5"),
+        max_storage_reimbursement: dec!(100),
+        auto_elevation_review_days: 2,
+        elevation_delay_days: 0,
+        reopen_carry_over_factor: dec!("0.5"),
+        max_description_length: 10_000,
+        archive_retention_days: 30,
+        max_weight_per_account: None,
+        signal_phase_days: 3,
+        binding_quorum: dec!(8000),
+        group_quorum: dec!(5000),
+        challenge_period_days: 0,
+        challenge_threshold: dec!("0.1"),
+        challenge_bond: dec!(100),
+        early_vote_bonus: None,
+        adaptive_approval_threshold: None,
+        deadline_rounding: DeadlineRounding::Second,
+        delegate_notice_hours: 0,
+        min_balance_to_vote: None,
+        vote_change_policy: VoteChangePolicy::Never,
+        large_vote_fraction: None,
+        rollback_window_days: 0,
+        abstain_quorum_weight: AbstainQuorumWeight::Full,
+    }
+}
+
+pub fn create_branding() -> GovernanceBranding {
+    GovernanceBranding {
+        dao_name: "Test DAO".to_string(),
+        logo_url: Url::of("https://example.com/logo.png"),
+        forum_url: Url::of("https://radixtalk.com"),
+        docs_url: Url::of("https://example.com/docs"),
+    }
+}
+
+pub fn create_temp_check_draft() -> TemperatureCheckDraft {
+    TemperatureCheckDraft {
+        title: "Test Proposal".to_string(),
+        short_description: "A short summary of the test proposal".to_string(),
+        description: "# Test Proposal\n\nA full markdown description of the test proposal.".to_string(),
+        description_file: None,
+        attachments: vec![],
+        kind: ProposalKind::Textual,
+        action: None,
+        vote_bond: None,
+        vote_options: vec![
+            ProposalVoteOptionInput {
+                label: "For".to_string(),
+                color: VoteOptionColor::Green,
+            },
+            ProposalVoteOptionInput {
+                label: "Against".to_string(),
+                color: VoteOptionColor::Red,
+            },
+        ],
+        links: vec![Url::of("https://radixtalk.com/proposal/123")],
+        discussion_hash: None,
+        max_selections: None, // Single choice
+        auto_elevate: false,
+        co_authors: vec![],
+        members_only: false,
+        anonymous: false,
+        eligibility_root: None,
+        allow_rationales: false,
+        extra_questions: vec![],
+        voting_mode: VotingMode::Selection,
+        abstain_option: None,
+        min_balance_to_vote: None,
+        external_ref: None,
+    }
+}
+
+pub fn create_multi_choice_temp_check_draft() -> TemperatureCheckDraft {
+    TemperatureCheckDraft {
+        title: "Multi-Choice Test Proposal".to_string(),
+        short_description: "A short summary of the multi-choice proposal".to_string(),
+        description: "# Multi-Choice Proposal\n\nA full markdown description with multiple choice voting.".to_string(),
+        description_file: None,
+        attachments: vec![],
+        kind: ProposalKind::Textual,
+        action: None,
+        vote_bond: None,
+        vote_options: vec![
+            ProposalVoteOptionInput {
+                label: "Option A".to_string(),
+                color: VoteOptionColor::Blue,
+            },
+            ProposalVoteOptionInput {
+                label: "Option B".to_string(),
+                color: VoteOptionColor::Green,
+            },
+            ProposalVoteOptionInput {
+                label: "Option C".to_string(),
+                color: VoteOptionColor::Yellow,
+            },
+        ],
+        links: vec![Url::of("https://radixtalk.com/proposal/456")],
+        discussion_hash: None,
+        max_selections: Some(2), // Can select up to 2 options
+        auto_elevate: false,
+        co_authors: vec![],
+        members_only: false,
+        anonymous: false,
+        eligibility_root: None,
+        allow_rationales: false,
+        extra_questions: vec![],
+        voting_mode: VotingMode::Selection,
+        abstain_option: None,
+        min_balance_to_vote: None,
+        external_ref: None,
+    }
+}
+
+/// Instantiates a Governance component with the default test parameters
+pub fn instantiate_governance(
+    ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+    package_address: PackageAddress,
+    owner_badge: ResourceAddress,
+) -> ComponentAddress {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "Governance",
+            "instantiate",
+            manifest_args!(owner_badge, create_governance_parameters(), create_branding(), RoyaltyAmount::Free),
+        )
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    receipt.expect_commit(true).new_component_addresses()[0]
+}
+
+/// Instantiates a VoteDelegation component
+pub fn instantiate_vote_delegation(
+    ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+    package_address: PackageAddress,
+    owner_badge: ResourceAddress,
+) -> ComponentAddress {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "VoteDelegation",
+            "instantiate",
+            manifest_args!(owner_badge, RoyaltyAmount::Free),
+        )
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    receipt.expect_commit(true).new_component_addresses()[0]
+}
+
+/// Authorizes the governance component on the VoteDelegation component and links them
+pub fn link_vote_delegation(
+    ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+    governance_component: ComponentAddress,
+    delegation_component: ComponentAddress,
+    owner: (ResourceAddress, ComponentAddress, Secp256k1PublicKey),
+) {
+    call_as_owner(
+        ledger,
+        delegation_component,
+        owner,
+        "set_governance",
+        manifest_args!(governance_component),
+    )
+    .expect_commit_success();
+    call_as_owner(
+        ledger,
+        governance_component,
+        owner,
+        "set_vote_delegation",
+        manifest_args!(delegation_component),
+    )
+    .expect_commit_success();
+}
+
+/// Creates a temperature check from the given draft with a fresh author account
+/// Returns the temperature check ID
+pub fn create_temperature_check(
+    ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+    governance_component: ComponentAddress,
+    draft: TemperatureCheckDraft,
+) -> u64 {
+    submit_temperature_check(ledger, governance_component, draft)
+        .expect_commit_success()
+        .output(1)
+}
+
+/// Submits a temperature check draft with a fresh author account
+pub fn submit_temperature_check(
+    ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+    governance_component: ComponentAddress,
+    draft: TemperatureCheckDraft,
+) -> TransactionReceipt {
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "make_temperature_check",
+            manifest_args!(author_account, draft),
+        )
+        .build();
+
+    ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&author_pk)],
+    )
+}
+
+/// Calls an owner-only governance method, presenting the owner badge
+pub fn call_as_owner(
+    ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+    governance_component: ComponentAddress,
+    owner: (ResourceAddress, ComponentAddress, Secp256k1PublicKey),
+    method_name: &str,
+    args: ManifestArgs,
+) -> TransactionReceipt {
+    let (owner_badge, owner_account, owner_pk) = owner;
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(governance_component, method_name, args)
+        .build();
+
+    ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+    )
+}
+
+/// Creates a temperature check from the default draft and force elevates it to a proposal
+/// Returns the proposal ID
+pub fn create_proposal(
+    ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+    governance_component: ComponentAddress,
+    owner: (ResourceAddress, ComponentAddress, Secp256k1PublicKey),
+) -> u64 {
+    let temperature_check_id =
+        create_temperature_check(ledger, governance_component, create_temp_check_draft());
+
+    let receipt = call_as_owner(
+        ledger,
+        governance_component,
+        owner,
+        "force_elevate",
+        manifest_args!(temperature_check_id),
+    );
+    receipt.expect_commit_success().output(2)
+}
+
+/// Votes on a proposal with the given account
+pub fn vote_on_proposal(
+    ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+    governance_component: ComponentAddress,
+    voter: (Secp256k1PublicKey, ComponentAddress),
+    proposal_id: u64,
+    votes: Vec<ProposalVoteOptionId>,
+) -> TransactionReceipt {
+    let (voter_pk, voter_account) = voter;
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_proposal",
+            manifest_args!(voter_account, proposal_id, votes),
+        )
+        .build();
+
+    ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+    )
+}
+
+/// Votes on a temperature check with the given account
+pub fn vote_on_temperature_check(
+    ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+    governance_component: ComponentAddress,
+    voter: (Secp256k1PublicKey, ComponentAddress),
+    temperature_check_id: u64,
+    vote: TemperatureCheckVote,
+) -> TransactionReceipt {
+    let (voter_pk, voter_account) = voter;
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_temperature_check",
+            manifest_args!(voter_account, temperature_check_id, vote),
+        )
+        .build();
+
+    ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+    )
+}
+
+/// Moves the ledger clock forward by the given number of seconds
+pub fn advance_time(
+    ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+    seconds: i64,
+) {
+    let current_ms = ledger.get_current_proposer_timestamp_ms();
+    let next_round = Round::of(ledger.get_consensus_manager_state().round.number() + 1);
+    ledger.advance_to_round_at_timestamp(next_round, current_ms + seconds * 1000);
+}
+
+/// Asserts that the transaction failed with the given blueprint error
+pub fn expect_error(receipt: TransactionReceipt, error: impl std::fmt::Display) {
+    let expected = error.to_string();
+    receipt.expect_specific_failure(|e| format!("{:?}", e).contains(&expected));
+}
@@ -1,11 +1,13 @@
 use scrypto::prelude::*;
 use crate::{
-    Delegation, DelegationCreatedEvent, DelegationRemovedEvent,
-    MAX_DELEGATIONS, MIN_DELEGATION_FRACTION,
+    Delegation, DelegationAuthorization, DelegationCheckpoint, IncomingCountBucket, IncomingPowerCheckpoint, DelegationSignature, DelegatorDelegations, DelegationMode, DelegationScope, DelegationStats, DelegationCreatedEvent, DelegationRemovedEvent,
+    DelegationUpdatedEvent, DelegateeMigratedEvent, NonceRegistry, DelegateeLimits, DelegateeLimitsUpdatedEvent, IncomingDelegationsRevokedEvent,
+    DelegationError, delegation_authorization_hash, ensure,
+    MAX_DELEGATIONS, MAX_DELEGATION_CHECKPOINTS, MAX_INCOMING_POWER_CHECKPOINTS, MIN_DELEGATION_FRACTION,
 };
 
 #[blueprint]
-#[events(DelegationCreatedEvent, DelegationRemovedEvent)]
+#[events(DelegationCreatedEvent, DelegationUpdatedEvent, DelegationRemovedEvent, DelegateeMigratedEvent, DelegateeLimitsUpdatedEvent, IncomingDelegationsRevokedEvent)]
 mod vote_delegation {
     use super::*;
 
@@ -16,9 +18,40 @@ mod vote_delegation {
         methods {
             // Public methods
             make_delegation => PUBLIC;
+            make_excess_delegation => PUBLIC;
+            make_scoped_delegation => PUBLIC;
+            make_default_proxy => PUBLIC;
+            make_delegation_signed => PUBLIC;
+            update_delegation_fraction => PUBLIC;
             remove_delegation => PUBLIC;
+            remove_all_delegations => PUBLIC;
+            migrate_delegatee => PUBLIC;
+            prune_expired_delegations => PUBLIC;
+            import_delegations => PUBLIC;
+            set_max_incoming_delegators => PUBLIC;
+            set_max_incoming_per_delegator => PUBLIC;
+            get_delegatee_limits => PUBLIC;
+            get_nonce => PUBLIC;
+            // Owner or governance, checked in the method
+            revoke_all_incoming => PUBLIC;
             get_delegations => PUBLIC;
             get_delegatee_delegators => PUBLIC;
+            get_delegation_fraction_at => PUBLIC;
+            get_delegations_at => PUBLIC;
+            get_incoming_power_at_epoch => PUBLIC;
+            get_delegation_stats => PUBLIC;
+            get_incoming_delegation_count => PUBLIC;
+            export_delegation_graph => PUBLIC;
+            get_indexed_delegator_count => PUBLIC;
+            get_governance => PUBLIC;
+            // Owner methods
+            set_governance => restrict_to: [owner];
+            set_migration_source => restrict_to: [owner];
+            set_delegation_royalty => restrict_to: [owner];
+            claim_royalties => restrict_to: [owner];
+            has_active_delegation => PUBLIC;
+            get_remaining_delegation_capacity => PUBLIC;
+            has_any_incoming => PUBLIC;
         }
     }
 
@@ -28,26 +61,151 @@ mod vote_delegation {
         pub delegatees: KeyValueStore<Global<Account>, KeyValueStore<Global<Account>, Decimal>>,
 
         /// Key: delegator (person that has delegated their voting power to another)
-        /// Value: Delegation struct, holds all the user's delegations
-        pub delegators: KeyValueStore<Global<Account>, Vec<Delegation>>,
+        /// Value: the delegator's delegations, one entry per delegatee
+        pub delegators: KeyValueStore<Global<Account>, DelegatorDelegations>,
+
+        /// Key: delegatee
+        /// Value: number of delegators currently delegating to this delegatee
+        pub incoming_counts: KeyValueStore<Global<Account>, u64>,
+
+        /// Key: incoming delegation count
+        /// Value: delegatees with exactly that count, used to keep `stats.largest_delegatee`
+        /// accurate when the largest delegatee loses delegations
+        pub count_buckets: KeyValueStore<u64, IncomingCountBucket>,
+
+        /// Key: delegatee
+        /// Value: position of the delegatee in the bucket of its current incoming count
+        pub count_bucket_positions: KeyValueStore<Global<Account>, u64>,
+
+        /// Key: delegatee
+        /// Value: limits the delegatee set on its incoming delegations (absent means unlimited)
+        pub delegatee_limits: KeyValueStore<Global<Account>, DelegateeLimits>,
+
+        /// Aggregate statistics, updated on every make/remove/prune
+        pub stats: DelegationStats,
+
+        /// Key: delegator
+        /// Value: the delegator's most recent delegation lists, oldest first
+        /// (bounded by MAX_DELEGATION_CHECKPOINTS), used to resolve delegations at a past instant
+        pub delegation_history: KeyValueStore<Global<Account>, Vec<DelegationCheckpoint>>,
+
+        /// Key: delegatee
+        /// Value: total incoming fraction after each epoch with changes, oldest first
+        /// (bounded by MAX_INCOMING_POWER_CHECKPOINTS)
+        pub incoming_power_history: KeyValueStore<Global<Account>, Vec<IncomingPowerCheckpoint>>,
+
+        /// Governance component authorized to use this component; write methods meant for
+        /// governance only accept calls from it (`global_caller`)
+        pub governance: Option<ComponentAddress>,
+
+        /// Key: position, in the order accounts first delegated
+        /// Value: delegator; makes the delegation graph iterable (see `export_delegation_graph`)
+        pub delegator_index: KeyValueStore<u64, Global<Account>>,
+
+        /// Number of accounts in `delegator_index`
+        pub indexed_delegator_count: u64,
+
+        /// Component `import_delegations` copies delegations from, set by the owner to migrate
+        /// from an earlier deployment
+        pub migration_source: Option<ComponentAddress>,
+
+        /// Owner badge, so methods open to the owner or governance can check either
+        pub owner_badge: ResourceAddress,
+
+        /// Nonces of signed authorizations (`make_delegation_signed`), per delegator
+        pub nonces: NonceRegistry,
     }
 
     impl VoteDelegation {
         /// Instantiates the vote delegation component with the given owner badge
-        pub fn instantiate(owner_badge: ResourceAddress) -> Global<VoteDelegation> {
+        /// `delegation_royalty` is charged on every call creating a delegation (`make_delegation`,
+        /// `make_scoped_delegation`, `make_excess_delegation`, `make_default_proxy`,
+        /// `make_delegation_signed`);
+        /// `RoyaltyAmount::Free` disables it. It can be changed with `set_delegation_royalty`.
+        pub fn instantiate(
+            owner_badge: ResourceAddress,
+            delegation_royalty: RoyaltyAmount,
+        ) -> Global<VoteDelegation> {
+            let (address_reservation, component_address) =
+                Runtime::allocate_component_address(VoteDelegation::blueprint_id());
+
             Self {
                 delegatees: KeyValueStore::new(),
                 delegators: KeyValueStore::new(),
+                incoming_counts: KeyValueStore::new(),
+                count_buckets: KeyValueStore::new(),
+                count_bucket_positions: KeyValueStore::new(),
+                delegatee_limits: KeyValueStore::new(),
+                stats: DelegationStats {
+                    total_active_delegations: 0,
+                    unique_delegators: 0,
+                    unique_delegatees: 0,
+                    largest_delegatee: None,
+                },
+                delegation_history: KeyValueStore::new(),
+                incoming_power_history: KeyValueStore::new(),
+                governance: None,
+                delegator_index: KeyValueStore::new(),
+                indexed_delegator_count: 0,
+                migration_source: None,
+                owner_badge,
+                nonces: NonceRegistry::new(),
             }
             .instantiate()
             .prepare_to_globalize(OwnerRole::Fixed(rule!(require(owner_badge))))
             .roles(roles! {
                 owner => rule!(require(owner_badge));
             })
+            .enable_component_royalties(component_royalties! {
+                roles {
+                    royalty_setter => rule!(require(global_caller(component_address)));
+                    royalty_setter_updater => rule!(deny_all);
+                    royalty_locker => rule!(deny_all);
+                    royalty_locker_updater => rule!(deny_all);
+                    royalty_claimer => rule!(require(global_caller(component_address)));
+                    royalty_claimer_updater => rule!(deny_all);
+                },
+                init {
+                    make_delegation => delegation_royalty, updatable;
+                    make_excess_delegation => delegation_royalty, updatable;
+                    make_scoped_delegation => delegation_royalty, updatable;
+                    make_default_proxy => delegation_royalty, updatable;
+                    make_delegation_signed => delegation_royalty, updatable;
+                    update_delegation_fraction => RoyaltyAmount::Free, locked;
+                    remove_delegation => RoyaltyAmount::Free, locked;
+                    remove_all_delegations => RoyaltyAmount::Free, locked;
+                    migrate_delegatee => RoyaltyAmount::Free, locked;
+                    prune_expired_delegations => RoyaltyAmount::Free, locked;
+                    import_delegations => RoyaltyAmount::Free, locked;
+                    set_max_incoming_delegators => RoyaltyAmount::Free, locked;
+                    set_max_incoming_per_delegator => RoyaltyAmount::Free, locked;
+                    get_delegatee_limits => RoyaltyAmount::Free, locked;
+                    get_nonce => RoyaltyAmount::Free, locked;
+                    revoke_all_incoming => RoyaltyAmount::Free, locked;
+                    get_delegations => RoyaltyAmount::Free, locked;
+                    get_delegatee_delegators => RoyaltyAmount::Free, locked;
+                    get_delegation_fraction_at => RoyaltyAmount::Free, locked;
+                    get_delegations_at => RoyaltyAmount::Free, locked;
+                    get_incoming_power_at_epoch => RoyaltyAmount::Free, locked;
+                    get_delegation_stats => RoyaltyAmount::Free, locked;
+                    get_incoming_delegation_count => RoyaltyAmount::Free, locked;
+                    export_delegation_graph => RoyaltyAmount::Free, locked;
+                    get_indexed_delegator_count => RoyaltyAmount::Free, locked;
+                    get_governance => RoyaltyAmount::Free, locked;
+                    set_governance => RoyaltyAmount::Free, locked;
+                    set_migration_source => RoyaltyAmount::Free, locked;
+                    set_delegation_royalty => RoyaltyAmount::Free, locked;
+                    claim_royalties => RoyaltyAmount::Free, locked;
+                    has_active_delegation => RoyaltyAmount::Free, locked;
+                    get_remaining_delegation_capacity => RoyaltyAmount::Free, locked;
+                    has_any_incoming => RoyaltyAmount::Free, locked;
+                }
+            })
+            .with_address(address_reservation)
             .globalize()
         }
 
-        /// Delegate voting power from delegator to delegatee
+        /// Delegate voting power from delegator to delegatee, on temperature checks and proposals
         /// The delegator must prove their presence
         pub fn make_delegation(
             &mut self,
@@ -55,84 +213,232 @@ mod vote_delegation {
             delegatee: Global<Account>,
             fraction: Decimal,
             valid_until: Instant,
+        ) {
+            self.make_scoped_delegation(delegator, delegatee, fraction, valid_until, DelegationScope::Both);
+        }
+
+        /// Delegate voting power on temperature checks only, proposals only, or both
+        /// The delegator must prove their presence. Fractions only add up within overlapping
+        /// scopes, so e.g. 100% of temperature checks and 50% of proposals can be delegated.
+        pub fn make_scoped_delegation(
+            &mut self,
+            delegator: Global<Account>,
+            delegatee: Global<Account>,
+            fraction: Decimal,
+            valid_until: Instant,
+            scope: DelegationScope,
         ) {
             // Verify the delegator is present in the transaction
             Runtime::assert_access_rule(delegator.get_owner_role().rule);
 
+            self.store_delegation(
+                delegator,
+                delegatee,
+                fraction,
+                valid_until,
+                DelegationMode::Full,
+                scope,
+            );
+        }
+
+        /// Delegate a fraction of only the voting power above a proposal's weight cap
+        /// The delegator must prove their presence and cannot mix this with full delegations
+        pub fn make_excess_delegation(
+            &mut self,
+            delegator: Global<Account>,
+            delegatee: Global<Account>,
+            fraction: Decimal,
+            valid_until: Instant,
+        ) {
+            // Verify the delegator is present in the transaction
+            Runtime::assert_access_rule(delegator.get_owner_role().rule);
+
+            self.store_delegation(
+                delegator,
+                delegatee,
+                fraction,
+                valid_until,
+                DelegationMode::ExcessAboveCap,
+                DelegationScope::Both,
+            );
+        }
+
+        /// Set a default proxy: the delegatee can cast the delegator's full voting power on
+        /// proposals where the delegator has no other delegation and does not vote itself
+        /// The delegator must prove their presence and can have at most one default proxy
+        pub fn make_default_proxy(
+            &mut self,
+            delegator: Global<Account>,
+            delegatee: Global<Account>,
+            valid_until: Instant,
+        ) {
+            // Verify the delegator is present in the transaction
+            Runtime::assert_access_rule(delegator.get_owner_role().rule);
+
+            self.store_delegation(
+                delegator,
+                delegatee,
+                Decimal::ONE,
+                valid_until,
+                DelegationMode::DefaultProxy,
+                DelegationScope::Both,
+            );
+        }
+
+        /// Delegate voting power like `make_delegation`, authorized by the delegator's signature
+        /// instead of its presence, so a relayer can submit (and pay for) the transaction
+        /// The signature must be made with the key the delegator's account is owned by, over
+        /// `delegation_authorization_hash(authorization)`
+        pub fn make_delegation_signed(
+            &mut self,
+            authorization: DelegationAuthorization,
+            signature: DelegationSignature,
+        ) {
+            let delegator: Global<Account> = Global::from(authorization.delegator);
+            ensure(
+                authorization.component == Runtime::global_address(),
+                DelegationError::InvalidDelegationSignature,
+            );
+
+            let message_hash = delegation_authorization_hash(&authorization);
+            let (verified, signer) = match signature {
+                DelegationSignature::Secp256k1 { public_key, signature } => (
+                    CryptoUtils::secp256k1_ecdsa_verify(message_hash, public_key, signature),
+                    NonFungibleGlobalId::from_public_key(&public_key),
+                ),
+                DelegationSignature::Ed25519 { public_key, signature } => (
+                    CryptoUtils::ed25519_verify(message_hash.0, public_key, signature),
+                    NonFungibleGlobalId::from_public_key(&public_key),
+                ),
+            };
+            ensure(
+                verified && delegator.get_owner_role().rule == rule!(require(signer)),
+                DelegationError::InvalidDelegationSignature,
+            );
+
+            ensure(
+                self.nonces.consume(delegator, authorization.nonce),
+                DelegationError::InvalidNonce,
+            );
+
+            self.store_delegation(
+                delegator,
+                Global::from(authorization.delegatee),
+                authorization.fraction,
+                authorization.valid_until,
+                DelegationMode::Full,
+                DelegationScope::Both,
+            );
+        }
+
+        /// Returns the nonce the account's next signed message must use, for wallets and relayers
+        pub fn get_nonce(&self, account: Global<Account>) -> u64 {
+            self.nonces.next_nonce(account)
+        }
+
+        /// Stores (or replaces) a delegation from delegator to delegatee
+        /// Callers verify the delegator authorized it
+        fn store_delegation(
+            &mut self,
+            delegator: Global<Account>,
+            delegatee: Global<Account>,
+            fraction: Decimal,
+            valid_until: Instant,
+            mode: DelegationMode,
+            scope: DelegationScope,
+        ) {
             // Validate minimum fraction
             let min_fraction = Decimal::try_from(MIN_DELEGATION_FRACTION).unwrap();
-            assert!(
+            ensure(
                 fraction >= min_fraction && fraction <= Decimal::ONE,
-                "Fraction must be between {} and 1 (inclusive)",
-                MIN_DELEGATION_FRACTION
-            );
-            assert!(
-                delegator != delegatee,
-                "Cannot delegate to yourself"
+                DelegationError::InvalidFraction,
             );
+            ensure(delegator != delegatee, DelegationError::SelfDelegation);
 
             let now = Clock::current_time_rounded_to_seconds();
-            assert!(
+            ensure(
                 valid_until.compare(now, TimeComparisonOperator::Gt),
-                "Delegation must be valid for some time in the future"
+                DelegationError::ExpiryNotInFuture,
             );
 
-            // Clean up expired delegations and calculate totals
+            let previous_count = self.outgoing_count(delegator);
+            if self.delegators.get(&delegator).is_none() {
+                self.delegators.insert(
+                    delegator,
+                    DelegatorDelegations {
+                        by_delegatee: KeyValueStore::new(),
+                        delegatees: Vec::new(),
+                    },
+                );
+            }
+
+            // Find expired delegations and total the other valid ones in a single pass
             let mut total_delegated = Decimal::ZERO;
-            let mut valid_delegations: Vec<Delegation> = Vec::new();
+            let mut other_count = 0;
+            let mut has_default_proxy = false;
+            let mut has_other_mode = false;
+            let mut replaces_existing = false;
             let mut expired_delegatees: Vec<Global<Account>> = Vec::new();
 
-            if let Some(existing_delegations) = self.delegators.get(&delegator) {
-                for delegation in existing_delegations.iter() {
-                    if delegation.valid_until.compare(now, TimeComparisonOperator::Gt) {
-                        // Still valid - skip if updating existing delegation to same delegatee
-                        if delegation.delegatee != delegatee {
+            let delegations = self.delegators.get(&delegator).unwrap();
+            for existing_delegatee in delegations.delegatees.iter() {
+                let delegation = delegations.by_delegatee.get(existing_delegatee).unwrap();
+                if !delegation.valid_until.compare(now, TimeComparisonOperator::Gt) {
+                    expired_delegatees.push(*existing_delegatee);
+                } else if *existing_delegatee == delegatee {
+                    replaces_existing = true;
+                } else {
+                    other_count += 1;
+                    // A default proxy does not count towards the total, nor do
+                    // delegations covering other kinds of votes
+                    if delegation.mode == DelegationMode::DefaultProxy {
+                        has_default_proxy = true;
+                    } else {
+                        has_other_mode |= delegation.mode != mode;
+                        if delegation.scope.overlaps(scope) {
                             total_delegated = total_delegated + delegation.fraction;
-                            valid_delegations.push(delegation.clone());
                         }
-                    } else {
-                        // Expired - track for cleanup from delegatees KVS
-                        expired_delegatees.push(delegation.delegatee);
                     }
                 }
             }
+            drop(delegations);
 
-            assert!(
-                total_delegated + fraction <= Decimal::ONE,
-                "Total delegation cannot exceed 100%"
-            );
+            if mode == DelegationMode::DefaultProxy {
+                ensure(!has_default_proxy, DelegationError::DefaultProxyAlreadySet);
+            } else {
+                ensure(
+                    total_delegated + fraction <= Decimal::ONE,
+                    DelegationError::TotalDelegationExceeded,
+                );
+                ensure(!has_other_mode, DelegationError::MixedDelegationModes);
+            }
 
             // Check max delegations (counting the new one)
-            let final_count = valid_delegations.len() + 1;
-            assert!(
-                final_count <= MAX_DELEGATIONS,
-                "Cannot have more than {} delegations",
-                MAX_DELEGATIONS
-            );
+            let final_count = other_count + 1;
+            ensure(final_count <= MAX_DELEGATIONS, DelegationError::TooManyDelegations);
+            self.assert_within_delegatee_limits(delegatee, fraction, replaces_existing);
 
-            // Create the new delegation
+            // Clean up expired delegations, then store the new one in its own entry
+            self.drop_delegations(delegator, &expired_delegatees);
             let new_delegation = Delegation {
                 delegatee,
                 fraction,
                 valid_until,
+                mode,
+                scope,
             };
-            valid_delegations.push(new_delegation);
-
-            // Update delegators map with cleaned-up list
-            let has_existing = self.delegators.get(&delegator).is_some();
-            if has_existing {
-                let mut delegations = self.delegators.get_mut(&delegator).unwrap();
-                *delegations = valid_delegations;
-            } else {
-                self.delegators.insert(delegator, valid_delegations);
+            let mut delegations = self.delegators.get_mut(&delegator).unwrap();
+            delegations.by_delegatee.insert(delegatee, new_delegation);
+            if !replaces_existing {
+                delegations.delegatees.push(delegatee);
             }
+            drop(delegations);
 
-            // Clean up expired delegations from delegatees KVS
-            for expired_delegatee in expired_delegatees {
-                if let Some(delegatee_map) = self.delegatees.get(&expired_delegatee) {
-                    delegatee_map.remove(&delegator);
-                }
+            if !replaces_existing {
+                self.record_delegation_added(delegatee);
             }
+            self.record_outgoing_count_change(previous_count, final_count);
+            self.record_checkpoint(delegator, now);
 
             // Update delegatees map for the new/updated delegation
             let delegatee_exists = self.delegatees.get(&delegatee).is_some();
@@ -140,13 +446,97 @@ mod vote_delegation {
                 self.delegatees.insert(delegatee, KeyValueStore::new());
             }
             let delegatee_map = self.delegatees.get(&delegatee).unwrap();
+            let previous_fraction = delegatee_map.get(&delegator).map_or(Decimal::ZERO, |f| *f);
             delegatee_map.insert(delegator, fraction);
+            drop(delegatee_map);
+            self.record_incoming_change(delegatee, fraction - previous_fraction);
 
             Runtime::emit_event(DelegationCreatedEvent {
                 delegator,
                 delegatee,
                 fraction,
                 valid_until,
+                mode,
+                scope,
+            });
+        }
+
+        /// Change the fraction of an existing, unexpired delegation, keeping its expiry and mode
+        /// The delegator must prove their presence
+        pub fn update_delegation_fraction(
+            &mut self,
+            delegator: Global<Account>,
+            delegatee: Global<Account>,
+            new_fraction: Decimal,
+        ) {
+            // Verify the delegator is present in the transaction
+            Runtime::assert_access_rule(delegator.get_owner_role().rule);
+
+            let min_fraction = Decimal::try_from(MIN_DELEGATION_FRACTION).unwrap();
+            ensure(
+                new_fraction >= min_fraction && new_fraction <= Decimal::ONE,
+                DelegationError::InvalidFraction,
+            );
+            if let Some(max_fraction) = self.get_delegatee_limits(delegatee).max_fraction_per_delegator {
+                ensure(new_fraction <= max_fraction, DelegationError::DelegateeFractionLimitExceeded);
+            }
+
+            let now = Clock::current_time_rounded_to_seconds();
+            let delegations = self
+                .delegators
+                .get(&delegator)
+                .unwrap_or_else(|| DelegationError::NoDelegations.raise());
+            let is_active = |d: &Delegation| d.valid_until.compare(now, TimeComparisonOperator::Gt);
+
+            let delegation = delegations
+                .by_delegatee
+                .get(&delegatee)
+                .map(|d| d.clone())
+                .filter(is_active)
+                .unwrap_or_else(|| DelegationError::DelegationNotFound.raise());
+
+            // Validate the new aggregate against the other unexpired delegations in overlapping scopes
+            let others_total = delegations
+                .delegatees
+                .iter()
+                .filter(|other| **other != delegatee)
+                .map(|other| delegations.by_delegatee.get(other).unwrap().clone())
+                .filter(|d| {
+                    d.mode != DelegationMode::DefaultProxy
+                        && d.scope.overlaps(delegation.scope)
+                        && is_active(d)
+                })
+                .fold(Decimal::ZERO, |total, d| total + d.fraction);
+            ensure(
+                others_total + new_fraction <= Decimal::ONE,
+                DelegationError::TotalDelegationExceeded,
+            );
+            ensure(
+                delegation.mode != DelegationMode::DefaultProxy,
+                DelegationError::DefaultProxyFractionFixed,
+            );
+
+            drop(delegations);
+
+            // Only the delegation's own entry is rewritten
+            let mut delegations = self.delegators.get_mut(&delegator).unwrap();
+            let mut stored = delegations.by_delegatee.get_mut(&delegatee).unwrap();
+            stored.fraction = new_fraction;
+            drop(stored);
+            drop(delegations);
+
+            let delegatee_map = self.delegatees.get(&delegatee).unwrap();
+            delegatee_map.insert(delegator, new_fraction);
+            drop(delegatee_map);
+            self.record_incoming_change(delegatee, new_fraction - delegation.fraction);
+            self.record_checkpoint(delegator, now);
+
+            Runtime::emit_event(DelegationUpdatedEvent {
+                delegator,
+                delegatee,
+                old_fraction: delegation.fraction,
+                new_fraction,
+                valid_until: delegation.valid_until,
             });
         }
 
@@ -162,57 +552,324 @@ mod vote_delegation {
             Runtime::assert_access_rule(delegator.get_owner_role().rule);
 
             let now = Clock::current_time_rounded_to_seconds();
-            let mut found_target = false;
-            let mut valid_delegations: Vec<Delegation> = Vec::new();
-            let mut expired_delegatees: Vec<Global<Account>> = Vec::new();
+            let previous_count = self.outgoing_count(delegator);
 
-            // Process delegations, keeping valid ones except the target
-            if let Some(existing_delegations) = self.delegators.get(&delegator) {
-                for delegation in existing_delegations.iter() {
-                    if delegation.delegatee == delegatee {
-                        found_target = true;
-                        // Don't add to valid_delegations (removing it)
-                    } else if delegation.valid_until.compare(now, TimeComparisonOperator::Gt) {
-                        // Still valid and not the target
-                        valid_delegations.push(delegation.clone());
-                    } else {
-                        // Expired - track for cleanup from delegatees KVS
-                        expired_delegatees.push(delegation.delegatee);
+            let delegations = self
+                .delegators
+                .get(&delegator)
+                .unwrap_or_else(|| DelegationError::NoDelegations.raise());
+            ensure(
+                delegations.by_delegatee.get(&delegatee).is_some(),
+                DelegationError::DelegationNotFound,
+            );
+            let mut removed_delegatees = Self::expired_delegatees(&delegations, now);
+            drop(delegations);
+
+            // Remove the target along with the expired delegations
+            removed_delegatees.retain(|d| *d != delegatee);
+            removed_delegatees.push(delegatee);
+            self.drop_delegations(delegator, &removed_delegatees);
+
+            let remaining_count = self.outgoing_count(delegator);
+            self.record_outgoing_count_change(previous_count, remaining_count);
+            self.record_checkpoint(delegator, now);
+
+            Runtime::emit_event(DelegationRemovedEvent {
+                delegator,
+                delegatee,
+            });
+        }
+
+        /// Remove every delegation made by a delegator, reclaiming their full voting power
+        /// The delegator must prove their presence
+        pub fn remove_all_delegations(&mut self, delegator: Global<Account>) {
+            // Verify the delegator is present in the transaction
+            Runtime::assert_access_rule(delegator.get_owner_role().rule);
+
+            // The list is bounded by MAX_DELEGATIONS, so this loop is bounded as well
+            let delegatees = self
+                .delegators
+                .get(&delegator)
+                .map(|d| d.delegatees.clone())
+                .filter(|delegatees| !delegatees.is_empty())
+                .unwrap_or_else(|| DelegationError::NoDelegations.raise());
+            self.drop_delegations(delegator, &delegatees);
+
+            for delegatee in delegatees.iter() {
+                Runtime::emit_event(DelegationRemovedEvent {
+                    delegator,
+                    delegatee: *delegatee,
+                });
+            }
+            self.record_outgoing_count_change(delegatees.len(), 0);
+            self.record_checkpoint(delegator, Clock::current_time_rounded_to_seconds());
+        }
+
+        /// Moves every incoming delegation of `old_account` to `new_account`
+        /// Both accounts must prove their presence
+        ///
+        /// # Arguments
+        /// * `old_account` - The delegatee being rotated out
+        /// * `new_account` - The delegatee taking over the delegations
+        /// * `delegators` - All accounts currently delegating to `old_account`.
+        ///   Incoming delegations are not enumerable on-ledger, so the caller provides them;
+        ///   the call fails if any incoming delegation is left behind.
+        pub fn migrate_delegatee(
+            &mut self,
+            old_account: Global<Account>,
+            new_account: Global<Account>,
+            delegators: Vec<Global<Account>>,
+        ) {
+            // Verify both accounts are present in the transaction
+            Runtime::assert_access_rule(old_account.get_owner_role().rule);
+            Runtime::assert_access_rule(new_account.get_owner_role().rule);
+
+            ensure(old_account != new_account, DelegationError::SameAccountMigration);
+
+            if self.delegatees.get(&new_account).is_none() {
+                self.delegatees.insert(new_account, KeyValueStore::new());
+            }
+
+            for delegator in delegators.iter() {
+                ensure(*delegator != new_account, DelegationError::MigrationToDelegator);
+
+                // Move the entry between the delegatees maps
+                let fraction = self
+                    .delegatees
+                    .get(&old_account)
+                    .and_then(|m| m.remove(delegator))
+                    .unwrap_or_else(|| DelegationError::NotDelegatingToOldAccount.raise());
+                let new_map = self.delegatees.get(&new_account).unwrap();
+                ensure(
+                    new_map.get(delegator).is_none(),
+                    DelegationError::AlreadyDelegatingToNewAccount,
+                );
+                new_map.insert(*delegator, fraction);
+                drop(new_map);
+
+                // Re-key the delegator's entry, keeping its position in the delegatee list
+                let mut delegations = self
+                    .delegators
+                    .get_mut(delegator)
+                    .unwrap_or_else(|| DelegationError::NoDelegations.raise());
+                if let Some(mut delegation) = delegations.by_delegatee.remove(&old_account) {
+                    delegation.delegatee = new_account;
+                    delegations.by_delegatee.insert(new_account, delegation);
+                }
+                for delegatee in delegations.delegatees.iter_mut() {
+                    if *delegatee == old_account {
+                        *delegatee = new_account;
                     }
                 }
-            } else {
-                panic!("No delegations found for this account");
+                drop(delegations);
+
+                self.record_delegation_removed(old_account);
+                self.record_delegation_added(new_account);
+                self.record_incoming_change(old_account, -fraction);
+                self.record_incoming_change(new_account, fraction);
+                self.record_checkpoint(*delegator, Clock::current_time_rounded_to_seconds());
             }
 
-            assert!(found_target, "No delegation found to the specified delegatee");
+            ensure(
+                self.get_incoming_delegation_count(old_account) == 0,
+                DelegationError::IncompleteMigration,
+            );
 
-            // Update delegators map with cleaned-up list
-            let mut delegations = self.delegators.get_mut(&delegator).unwrap();
-            *delegations = valid_delegations;
+            Runtime::emit_event(DelegateeMigratedEvent {
+                old_delegatee: old_account,
+                new_delegatee: new_account,
+                delegators,
+            });
+        }
 
-            // Clean up expired delegations from delegatees KVS
-            for expired_delegatee in expired_delegatees {
-                if let Some(delegatee_map) = self.delegatees.get(&expired_delegatee) {
-                    delegatee_map.remove(&delegator);
-                }
+        /// Removes all expired delegations of a delegator
+        /// Callable by anyone, since it only drops delegations that no longer have any effect
+        pub fn prune_expired_delegations(&mut self, delegator: Global<Account>) {
+            let now = Clock::current_time_rounded_to_seconds();
+            let previous_count = self.outgoing_count(delegator);
+            let expired_delegatees = match self.delegators.get(&delegator) {
+                Some(delegations) => Self::expired_delegatees(&delegations, now),
+                None => return,
+            };
+
+            if expired_delegatees.is_empty() {
+                return;
             }
 
-            // Remove the target delegation from delegatees map
-            if let Some(delegatee_map) = self.delegatees.get(&delegatee) {
-                delegatee_map.remove(&delegator);
+            self.drop_delegations(delegator, &expired_delegatees);
+            for expired_delegatee in expired_delegatees.iter() {
+                Runtime::emit_event(DelegationRemovedEvent {
+                    delegator,
+                    delegatee: *expired_delegatee,
+                });
             }
+            self.record_outgoing_count_change(previous_count, previous_count - expired_delegatees.len());
+            self.record_checkpoint(delegator, now);
+        }
 
-            Runtime::emit_event(DelegationRemovedEvent {
-                delegator,
+        /// Voids every delegation to `delegatee`, e.g. after the account was compromised
+        /// Callable by the owner or the linked governance component (as `global_caller`)
+        ///
+        /// # Arguments
+        /// * `delegatee` - The account whose incoming delegations are voided
+        /// * `delegators` - All accounts currently delegating to `delegatee`. Incoming delegations
+        ///   are not enumerable on-ledger, so the caller provides them; the call fails if any
+        ///   incoming delegation is left behind.
+        /// * `reason` - Why the delegations were voided, recorded in the event
+        pub fn revoke_all_incoming(
+            &mut self,
+            delegatee: Global<Account>,
+            delegators: Vec<Global<Account>>,
+            reason: String,
+        ) {
+            self.assert_owner_or_governance();
+            let now = Clock::current_time_rounded_to_seconds();
+
+            for delegator in delegators.iter() {
+                let has_delegation = self
+                    .delegators
+                    .get(delegator)
+                    .map(|d| d.by_delegatee.get(&delegatee).is_some())
+                    .unwrap_or(false);
+                ensure(has_delegation, DelegationError::DelegationNotFound);
+
+                let previous_count = self.outgoing_count(*delegator);
+                self.drop_delegations(*delegator, &[delegatee]);
+                self.record_outgoing_count_change(previous_count, previous_count - 1);
+                self.record_checkpoint(*delegator, now);
+
+                Runtime::emit_event(DelegationRemovedEvent {
+                    delegator: *delegator,
+                    delegatee,
+                });
+            }
+
+            ensure(
+                self.get_incoming_delegation_count(delegatee) == 0,
+                DelegationError::IncompleteRevocation,
+            );
+
+            Runtime::emit_event(IncomingDelegationsRevokedEvent {
                 delegatee,
+                delegators,
+                reason,
             });
         }
 
+        /// Limits the number of delegators a delegatee accepts (`None` removes the limit)
+        /// The delegatee must prove its presence. Existing delegations are kept; new delegators
+        /// are refused while the incoming count, which includes expired delegations until they
+        /// are pruned, is at the limit.
+        pub fn set_max_incoming_delegators(&mut self, delegatee: Global<Account>, max_delegators: Option<u64>) {
+            Runtime::assert_access_rule(delegatee.get_owner_role().rule);
+
+            let mut limits = self.get_delegatee_limits(delegatee);
+            limits.max_delegators = max_delegators;
+            self.store_delegatee_limits(delegatee, limits);
+        }
+
+        /// Limits the fraction a single delegator can delegate to a delegatee (`None` removes the
+        /// limit). The delegatee must prove its presence. Existing delegations are kept, but can
+        /// only be replaced or updated within the limit.
+        pub fn set_max_incoming_per_delegator(&mut self, delegatee: Global<Account>, max_fraction: Option<Decimal>) {
+            Runtime::assert_access_rule(delegatee.get_owner_role().rule);
+
+            if let Some(max_fraction) = max_fraction {
+                let min_fraction = Decimal::try_from(MIN_DELEGATION_FRACTION).unwrap();
+                ensure(
+                    max_fraction >= min_fraction && max_fraction <= Decimal::ONE,
+                    DelegationError::InvalidFraction,
+                );
+            }
+
+            let mut limits = self.get_delegatee_limits(delegatee);
+            limits.max_fraction_per_delegator = max_fraction;
+            self.store_delegatee_limits(delegatee, limits);
+        }
+
+        /// Returns the limits a delegatee set on its incoming delegations
+        pub fn get_delegatee_limits(&self, delegatee: Global<Account>) -> DelegateeLimits {
+            self.delegatee_limits
+                .get(&delegatee)
+                .map(|l| l.clone())
+                .unwrap_or_default()
+        }
+
+        /// Copies a delegator's unexpired delegations from the migration source, e.g. a
+        /// component of an earlier package version storing delegations as a single list
+        /// Callable by anyone, since the data comes from the owner-set source; the delegator must
+        /// not have delegations stored here yet.
+        pub fn import_delegations(&mut self, delegator: Global<Account>) {
+            let source = self
+                .migration_source
+                .unwrap_or_else(|| DelegationError::NoMigrationSource.raise());
+            ensure(self.outgoing_count(delegator) == 0, DelegationError::DelegationsAlreadyStored);
+
+            let now = Clock::current_time_rounded_to_seconds();
+            let source: Global<AnyComponent> = Global::from(source);
+            let imported: Vec<Delegation> = source
+                .call_raw::<Vec<Delegation>>("get_delegations", scrypto_args!(delegator))
+                .into_iter()
+                .filter(|d| d.valid_until.compare(now, TimeComparisonOperator::Gt))
+                .take(MAX_DELEGATIONS)
+                .collect();
+            if imported.is_empty() {
+                return;
+            }
+
+            if self.delegators.get(&delegator).is_none() {
+                self.delegators.insert(
+                    delegator,
+                    DelegatorDelegations {
+                        by_delegatee: KeyValueStore::new(),
+                        delegatees: Vec::new(),
+                    },
+                );
+            }
+            for delegation in imported.iter() {
+                let delegations = self.delegators.get(&delegator).unwrap();
+                delegations.by_delegatee.insert(delegation.delegatee, delegation.clone());
+                drop(delegations);
+                self.delegators.get_mut(&delegator).unwrap().delegatees.push(delegation.delegatee);
+
+                if self.delegatees.get(&delegation.delegatee).is_none() {
+                    self.delegatees.insert(delegation.delegatee, KeyValueStore::new());
+                }
+                let delegatee_map = self.delegatees.get(&delegation.delegatee).unwrap();
+                delegatee_map.insert(delegator, delegation.fraction);
+                drop(delegatee_map);
+                self.record_delegation_added(delegation.delegatee);
+                self.record_incoming_change(delegation.delegatee, delegation.fraction);
+
+                Runtime::emit_event(DelegationCreatedEvent {
+                    delegator,
+                    delegatee: delegation.delegatee,
+                    fraction: delegation.fraction,
+                    valid_until: delegation.valid_until,
+                    mode: delegation.mode,
+                    scope: delegation.scope,
+                });
+            }
+            self.record_outgoing_count_change(0, imported.len());
+            self.record_checkpoint(delegator, now);
+        }
+
+        /// Sets or clears the component `import_delegations` copies delegations from (owner only)
+        pub fn set_migration_source(&mut self, source: Option<ComponentAddress>) {
+            self.migration_source = source;
+        }
+
         /// Get all delegations made by a delegator
         pub fn get_delegations(&self, delegator: Global<Account>) -> Vec<Delegation> {
             self.delegators
                 .get(&delegator)
-                .map(|d| d.clone())
+                .map(|delegations| {
+                    delegations
+                        .delegatees
+                        .iter()
+                        .map(|delegatee| delegations.by_delegatee.get(delegatee).unwrap().clone())
+                        .collect()
+                })
                 .unwrap_or_default()
         }
 
@@ -226,5 +883,426 @@ mod vote_delegation {
                 .get(&delegatee)
                 .and_then(|m| m.get(&delegator).map(|d| *d))
         }
+
+        /// Get the fraction delegated from a delegator to a delegatee as it stood at a past instant
+        /// Returns None if there was no delegation valid at that instant. Panics if the instant
+        /// predates the retained history (MAX_DELEGATION_CHECKPOINTS changes)
+        pub fn get_delegation_fraction_at(
+            &self,
+            delegator: Global<Account>,
+            delegatee: Global<Account>,
+            at: Instant,
+        ) -> Option<Decimal> {
+            self.get_delegations_at(delegator, at)
+                .iter()
+                .find(|d| d.delegatee == delegatee)
+                .map(|d| d.fraction)
+        }
+
+        /// Get the delegations of a delegator that were valid at a past instant
+        /// Empty if there were none. Panics if the instant predates the retained history, as the
+        /// delegations at that instant are then unknown.
+        pub fn get_delegations_at(&self, delegator: Global<Account>, at: Instant) -> Vec<Delegation> {
+            let history = match self.delegation_history.get(&delegator) {
+                Some(history) => history,
+                None => return Vec::new(),
+            };
+
+            match history
+                .iter()
+                .rev()
+                .find(|checkpoint| checkpoint.at.compare(at, TimeComparisonOperator::Lte))
+            {
+                Some(checkpoint) => checkpoint
+                    .delegations
+                    .iter()
+                    .filter(|d| d.valid_until.compare(at, TimeComparisonOperator::Gt))
+                    .cloned()
+                    .collect(),
+                // A full history has dropped older checkpoints, one of which may cover the instant
+                None if history.len() >= MAX_DELEGATION_CHECKPOINTS => {
+                    DelegationError::InstantBeforeRetainedHistory.raise()
+                }
+                None => Vec::new(),
+            }
+        }
+
+        /// Get the sum of the fractions delegated to a delegatee at the end of an epoch
+        /// Like the incoming count, this includes expired delegations until they are pruned.
+        /// Zero if nothing was delegated to the delegatee by then. None if the epoch may predate
+        /// the retained history (MAX_INCOMING_POWER_CHECKPOINTS epochs with changes), as the
+        /// total is then unknown.
+        pub fn get_incoming_power_at_epoch(
+            &self,
+            delegatee: Global<Account>,
+            epoch: Epoch,
+        ) -> Option<Decimal> {
+            let history = match self.incoming_power_history.get(&delegatee) {
+                Some(history) => history,
+                None => return Some(Decimal::ZERO),
+            };
+            match history.iter().rev().find(|checkpoint| checkpoint.epoch <= epoch) {
+                Some(checkpoint) => Some(checkpoint.total_fraction),
+                // Older checkpoints may have been dropped once the history was full
+                None if history.len() >= MAX_INCOMING_POWER_CHECKPOINTS => None,
+                None => Some(Decimal::ZERO),
+            }
+        }
+
+        /// Returns the stored delegations of up to `limit` delegators as (delegator, delegatee,
+        /// fraction, valid_until) tuples, starting at position `start_key` of the delegator index
+        /// (accounts in the order they first delegated). Pages are deterministic, so the full graph
+        /// can be rebuilt by paging until `get_indexed_delegator_count`. Expired delegations not
+        /// pruned yet are included; callers filter on `valid_until`.
+        pub fn export_delegation_graph(
+            &self,
+            start_key: u64,
+            limit: u64,
+        ) -> Vec<(Global<Account>, Global<Account>, Decimal, Instant)> {
+            let end = start_key.saturating_add(limit).min(self.indexed_delegator_count);
+            (start_key..end)
+                .flat_map(|position| {
+                    let delegator = *self.delegator_index.get(&position).unwrap();
+                    self.get_delegations(delegator)
+                        .into_iter()
+                        .map(move |d| (delegator, d.delegatee, d.fraction, d.valid_until))
+                })
+                .collect()
+        }
+
+        /// Returns the number of accounts in the delegator index, i.e. that ever delegated
+        pub fn get_indexed_delegator_count(&self) -> u64 {
+            self.indexed_delegator_count
+        }
+
+        /// Returns the aggregate delegation statistics
+        pub fn get_delegation_stats(&self) -> DelegationStats {
+            self.stats.clone()
+        }
+
+        /// Returns the number of delegators currently delegating to a delegatee
+        pub fn get_incoming_delegation_count(&self, delegatee: Global<Account>) -> u64 {
+            self.incoming_counts
+                .get(&delegatee)
+                .map(|c| *c)
+                .unwrap_or(0)
+        }
+
+        /// Whether a delegator currently has an unexpired delegation to a delegatee
+        /// The delegatee's map is checked first, so the common negative case reads a single entry
+        pub fn has_active_delegation(&self, delegator: Global<Account>, delegatee: Global<Account>) -> bool {
+            if self.get_delegatee_delegators(delegatee, delegator).is_none() {
+                return false;
+            }
+
+            let now = Clock::current_time_rounded_to_seconds();
+            self.delegators
+                .get(&delegator)
+                .and_then(|delegations| {
+                    delegations
+                        .by_delegatee
+                        .get(&delegatee)
+                        .map(|d| d.valid_until.compare(now, TimeComparisonOperator::Gt))
+                })
+                .unwrap_or(false)
+        }
+
+        /// Fraction a delegator can still delegate: 1 minus its active delegated fractions
+        /// This is the capacity for a delegation covering both temperature checks and proposals;
+        /// a scoped delegation may fit more. Default proxies do not count towards the total.
+        pub fn get_remaining_delegation_capacity(&self, delegator: Global<Account>) -> Decimal {
+            let now = Clock::current_time_rounded_to_seconds();
+            let total_delegated = self
+                .get_delegations(delegator)
+                .iter()
+                .filter(|d| {
+                    d.mode != DelegationMode::DefaultProxy
+                        && d.valid_until.compare(now, TimeComparisonOperator::Gt)
+                })
+                .fold(Decimal::ZERO, |total, d| total + d.fraction);
+
+            (Decimal::ONE - total_delegated).max(Decimal::ZERO)
+        }
+
+        /// Whether any delegator is delegating to a delegatee
+        /// Reads only the incoming count, which includes expired delegations not pruned yet
+        pub fn has_any_incoming(&self, delegatee: Global<Account>) -> bool {
+            self.get_incoming_delegation_count(delegatee) > 0
+        }
+
+        /// Returns the governance component authorized to use this component, if linked
+        pub fn get_governance(&self) -> Option<ComponentAddress> {
+            self.governance
+        }
+
+        /// Authorizes a governance component to use this component (owner only)
+        /// Governance checks this link when `set_vote_delegation` is called on it
+        pub fn set_governance(&mut self, governance: ComponentAddress) {
+            self.governance = Some(governance);
+        }
+
+        /// Sets the royalty charged on each method creating a delegation (owner only)
+        /// `RoyaltyAmount::Free` disables it
+        pub fn set_delegation_royalty(&mut self, amount: RoyaltyAmount) {
+            let component = Runtime::global_component();
+            for method in [
+                "make_delegation",
+                "make_scoped_delegation",
+                "make_excess_delegation",
+                "make_default_proxy",
+                "make_delegation_signed",
+            ] {
+                component.set_royalty(method, amount);
+            }
+        }
+
+        /// Withdraws the royalties collected by the component (owner only)
+        pub fn claim_royalties(&mut self) -> Bucket {
+            Runtime::global_component().claim_component_royalties()
+        }
+
+        /// Number of stored outgoing delegations of a delegator (including expired ones)
+        fn outgoing_count(&self, delegator: Global<Account>) -> usize {
+            self.delegators
+                .get(&delegator)
+                .map(|d| d.delegatees.len())
+                .unwrap_or(0)
+        }
+
+        /// Delegatees of the stored delegations that are no longer valid at `now`
+        fn expired_delegatees(delegations: &DelegatorDelegations, now: Instant) -> Vec<Global<Account>> {
+            delegations
+                .delegatees
+                .iter()
+                .filter(|delegatee| {
+                    let delegation = delegations.by_delegatee.get(delegatee).unwrap();
+                    !delegation.valid_until.compare(now, TimeComparisonOperator::Gt)
+                })
+                .copied()
+                .collect()
+        }
+
+        /// Panics unless the owner badge is present or the caller is the linked governance component
+        fn assert_owner_or_governance(&self) {
+            let rule = match self.governance {
+                Some(governance) => {
+                    rule!(require(self.owner_badge) || require(global_caller(governance)))
+                }
+                None => rule!(require(self.owner_badge)),
+            };
+            Runtime::assert_access_rule(rule);
+        }
+
+        /// Panics if the delegatee's limits do not accept a delegation of `fraction`
+        /// A replaced delegation already counts as one of the delegatee's delegators
+        fn assert_within_delegatee_limits(&self, delegatee: Global<Account>, fraction: Decimal, replaces_existing: bool) {
+            let limits = self.get_delegatee_limits(delegatee);
+            if let Some(max_delegators) = limits.max_delegators {
+                ensure(
+                    replaces_existing || self.get_incoming_delegation_count(delegatee) < max_delegators,
+                    DelegationError::DelegateeAtCapacity,
+                );
+            }
+            if let Some(max_fraction) = limits.max_fraction_per_delegator {
+                ensure(fraction <= max_fraction, DelegationError::DelegateeFractionLimitExceeded);
+            }
+        }
+
+        /// Stores a delegatee's limits, dropping the entry once no limit is left
+        fn store_delegatee_limits(&mut self, delegatee: Global<Account>, limits: DelegateeLimits) {
+            if limits == DelegateeLimits::default() {
+                self.delegatee_limits.remove(&delegatee);
+            } else {
+                self.delegatee_limits.insert(delegatee, limits.clone());
+            }
+
+            Runtime::emit_event(DelegateeLimitsUpdatedEvent { delegatee, limits });
+        }
+
+        /// Removes the delegator's delegations to the given delegatees from both maps and the
+        /// statistics. The delegator's entry is kept, since it owns a KeyValueStore.
+        fn drop_delegations(&mut self, delegator: Global<Account>, delegatees: &[Global<Account>]) {
+            if delegatees.is_empty() {
+                return;
+            }
+
+            let mut delegations = self.delegators.get_mut(&delegator).unwrap();
+            for delegatee in delegatees {
+                delegations.by_delegatee.remove(delegatee);
+            }
+            delegations.delegatees.retain(|d| !delegatees.contains(d));
+            drop(delegations);
+
+            for delegatee in delegatees {
+                let removed = self
+                    .delegatees
+                    .get(delegatee)
+                    .and_then(|delegatee_map| delegatee_map.remove(&delegator));
+                if let Some(fraction) = removed {
+                    self.record_incoming_change(*delegatee, -fraction);
+                }
+                self.record_delegation_removed(*delegatee);
+            }
+        }
+
+        /// Updates the unique delegator count when a delegator's stored delegation count changes
+        fn record_outgoing_count_change(&mut self, previous_count: usize, new_count: usize) {
+            if previous_count == 0 && new_count > 0 {
+                self.stats.unique_delegators += 1;
+            } else if previous_count > 0 && new_count == 0 {
+                self.stats.unique_delegators -= 1;
+            }
+        }
+
+        /// Updates the statistics after a new delegator -> delegatee link was stored
+        fn record_delegation_added(&mut self, delegatee: Global<Account>) {
+            let count = self.get_incoming_delegation_count(delegatee) + 1;
+            self.incoming_counts.insert(delegatee, count);
+            if count > 1 {
+                self.remove_from_count_bucket(count - 1, delegatee);
+            }
+            self.insert_into_count_bucket(count, delegatee);
+
+            self.stats.total_active_delegations += 1;
+            if count == 1 {
+                self.stats.unique_delegatees += 1;
+            }
+
+            let overtakes = match self.stats.largest_delegatee {
+                None => true,
+                Some((largest, largest_count)) => largest == delegatee || count > largest_count,
+            };
+            if overtakes {
+                self.stats.largest_delegatee = Some((delegatee, count));
+            }
+        }
+
+        /// Updates the statistics after a delegator -> delegatee link was removed
+        fn record_delegation_removed(&mut self, delegatee: Global<Account>) {
+            let previous_count = self.get_incoming_delegation_count(delegatee);
+            let count = previous_count.saturating_sub(1);
+            if count == 0 {
+                self.incoming_counts.remove(&delegatee);
+                self.stats.unique_delegatees -= 1;
+            } else {
+                self.incoming_counts.insert(delegatee, count);
+            }
+            self.remove_from_count_bucket(previous_count, delegatee);
+            if count > 0 {
+                self.insert_into_count_bucket(count, delegatee);
+            }
+
+            self.stats.total_active_delegations -= 1;
+
+            // Losing a delegation at the largest count can only lower the maximum by one, and the
+            // delegatee just moved into that lower bucket
+            if let Some((_, largest_count)) = self.stats.largest_delegatee {
+                if largest_count == previous_count {
+                    self.stats.largest_delegatee = self
+                        .first_in_count_bucket(largest_count)
+                        .map(|account| (account, largest_count))
+                        .or_else(|| {
+                            self.first_in_count_bucket(largest_count - 1)
+                                .map(|account| (account, largest_count - 1))
+                        });
+                }
+            }
+        }
+
+        /// Appends a delegatee to the bucket of delegatees with `count` incoming delegations
+        fn insert_into_count_bucket(&mut self, count: u64, delegatee: Global<Account>) {
+            if self.count_buckets.get(&count).is_none() {
+                self.count_buckets.insert(
+                    count,
+                    IncomingCountBucket {
+                        delegatees: KeyValueStore::new(),
+                        size: 0,
+                    },
+                );
+            }
+            let mut bucket = self.count_buckets.get_mut(&count).unwrap();
+            let position = bucket.size;
+            bucket.delegatees.insert(position, delegatee);
+            bucket.size += 1;
+            drop(bucket);
+            self.count_bucket_positions.insert(delegatee, position);
+        }
+
+        /// Removes a delegatee from the bucket of `count`, moving the bucket's last delegatee
+        /// into the freed position
+        fn remove_from_count_bucket(&mut self, count: u64, delegatee: Global<Account>) {
+            let position = match self.count_bucket_positions.remove(&delegatee) {
+                Some(position) => position,
+                None => return,
+            };
+            let mut bucket = self.count_buckets.get_mut(&count).unwrap();
+            let last = bucket.size - 1;
+            let moved = if position != last {
+                let moved = *bucket.delegatees.get(&last).unwrap();
+                bucket.delegatees.insert(position, moved);
+                Some(moved)
+            } else {
+                None
+            };
+            bucket.delegatees.remove(&last);
+            bucket.size = last;
+            drop(bucket);
+            if let Some(moved) = moved {
+                self.count_bucket_positions.insert(moved, position);
+            }
+        }
+
+        /// Any delegatee with exactly `count` incoming delegations
+        fn first_in_count_bucket(&self, count: u64) -> Option<Global<Account>> {
+            let bucket = self.count_buckets.get(&count)?;
+            let first = bucket.delegatees.get(&0).map(|account| *account);
+            first
+        }
+
+        /// Adds `delta` to the delegatee's total incoming fraction, in the checkpoint of the
+        /// current epoch
+        fn record_incoming_change(&mut self, delegatee: Global<Account>, delta: Decimal) {
+            let epoch = Runtime::current_epoch();
+            if self.incoming_power_history.get(&delegatee).is_none() {
+                self.incoming_power_history.insert(delegatee, Vec::new());
+            }
+
+            let mut history = self.incoming_power_history.get_mut(&delegatee).unwrap();
+            let total_fraction = history.last().map_or(Decimal::ZERO, |c| c.total_fraction) + delta;
+            // Several changes within the same epoch collapse into one checkpoint
+            if history.last().map(|c| c.epoch == epoch).unwrap_or(false) {
+                history.pop();
+            }
+            history.push(IncomingPowerCheckpoint { epoch, total_fraction });
+            if history.len() > MAX_INCOMING_POWER_CHECKPOINTS {
+                history.remove(0);
+            }
+        }
+
+        /// Stores the delegator's current delegation list as a checkpoint effective from `now`
+        fn record_checkpoint(&mut self, delegator: Global<Account>, now: Instant) {
+            let checkpoint = DelegationCheckpoint {
+                at: now,
+                delegations: self.get_delegations(delegator),
+            };
+
+            if self.delegation_history.get(&delegator).is_none() {
+                self.delegation_history.insert(delegator, vec![checkpoint]);
+                // First delegation of the account: append it to the iterable index
+                self.delegator_index.insert(self.indexed_delegator_count, delegator);
+                self.indexed_delegator_count += 1;
+                return;
+            }
+
+            let mut history = self.delegation_history.get_mut(&delegator).unwrap();
+            // Several changes within the same second collapse into one checkpoint
+            if history.last().map(|c| c.at == now).unwrap_or(false) {
+                history.pop();
+            }
+            history.push(checkpoint);
+            if history.len() > MAX_DELEGATION_CHECKPOINTS {
+                history.remove(0);
+            }
+        }
     }
 }
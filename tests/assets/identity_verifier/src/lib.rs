@@ -0,0 +1,30 @@
+//! Minimal identity verifier for the governance tests: accounts count as verified once
+//! `verify` was called for them.
+
+use scrypto::prelude::*;
+
+#[blueprint]
+mod identity_verifier {
+    struct IdentityVerifier {
+        verified: KeyValueStore<Global<Account>, ()>,
+    }
+
+    impl IdentityVerifier {
+        pub fn instantiate() -> Global<IdentityVerifier> {
+            Self {
+                verified: KeyValueStore::new(),
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .globalize()
+        }
+
+        pub fn verify(&mut self, account: Global<Account>) {
+            self.verified.insert(account, ());
+        }
+
+        pub fn is_verified(&self, account: Global<Account>) -> bool {
+            self.verified.get(&account).is_some()
+        }
+    }
+}
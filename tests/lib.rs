@@ -1,118 +1,87 @@
 use scrypto::prelude::Url;
 use scrypto_test::prelude::*;
 use consultation_blueprint::*;
+use consultation_blueprint::test_utils::*;
 
 // =============================================================================
-// Test Helpers
+// Governance Blueprint Tests
 // =============================================================================
 
-/// Creates an owner badge and deposits it to a new account
-/// Returns (badge_address, owner_account, owner_public_key)
-fn create_owner_badge_with_account(
-    ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>
-) -> (ResourceAddress, ComponentAddress, Secp256k1PublicKey) {
-    let (public_key, _private_key, owner_account) = ledger.new_allocated_account();
+#[test]
+fn test_governance_instantiate() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _public_key) = create_owner_badge_with_account(&mut ledger);
+    let params = create_governance_parameters();
 
     let manifest = ManifestBuilder::new()
         .lock_fee_from_faucet()
-        .create_fungible_resource(
-            OwnerRole::None,
-            false,
-            0,
-            FungibleResourceRoles::default(),
-            metadata!(),
-            Some(dec!(1)),
+        .call_function(
+            ledger.compile_and_publish(this_package!()),
+            "Governance",
+            "instantiate",
+            manifest_args!(owner_badge, params, create_branding(), RoyaltyAmount::Free),
         )
-        .try_deposit_entire_worktop_or_abort(owner_account, None)
         .build();
 
-    let receipt = ledger.execute_manifest(
-        manifest,
-        vec![NonFungibleGlobalId::from_public_key(&public_key)],
-    );
+    let receipt = ledger.execute_manifest(manifest, vec![]);
     receipt.expect_commit_success();
-    let owner_badge = receipt.expect_commit(true).new_resource_addresses()[0];
-
-    (owner_badge, owner_account, public_key)
-}
-
-fn create_governance_parameters() -> GovernanceParameters {
-    GovernanceParameters {
-        temperature_check_days: 7,
-        temperature_check_quorum: dec!(1000),
-        temperature_check_approval_threshold: dec!("0.5"),
-        proposal_length_days: 14,
-        proposal_quorum: dec!(5000),
-        proposal_approval_threshold: dec!("0.5"),
-    }
-}
-
-fn create_temp_check_draft() -> TemperatureCheckDraft {
-    TemperatureCheckDraft {
-        title: "Test Proposal".to_string(),
-        short_description: "A short summary of the test proposal".to_string(),
-        description: "# Test Proposal\n\nA full markdown description of the test proposal.".to_string(),
-        vote_options: vec![
-            ProposalVoteOptionInput {
-                label: "For".to_string(),
-                color: VoteOptionColor::Green,
-            },
-            ProposalVoteOptionInput {
-                label: "Against".to_string(),
-                color: VoteOptionColor::Red,
-            },
-        ],
-        links: vec![Url::of("https://radixtalk.com/proposal/123")],
-        max_selections: None, // Single choice
-    }
-}
-
-fn create_multi_choice_temp_check_draft() -> TemperatureCheckDraft {
-    TemperatureCheckDraft {
-        title: "Multi-Choice Test Proposal".to_string(),
-        short_description: "A short summary of the multi-choice proposal".to_string(),
-        description: "# Multi-Choice Proposal\n\nA full markdown description with multiple choice voting.".to_string(),
-        vote_options: vec![
-            ProposalVoteOptionInput {
-                label: "Option A".to_string(),
-                color: VoteOptionColor::Blue,
-            },
-            ProposalVoteOptionInput {
-                label: "Option B".to_string(),
-                color: VoteOptionColor::Green,
-            },
-            ProposalVoteOptionInput {
-                label: "Option C".to_string(),
-                color: VoteOptionColor::Yellow,
-            },
-        ],
-        links: vec![Url::of("https://radixtalk.com/proposal/456")],
-        max_selections: Some(2), // Can select up to 2 options
-    }
 }
 
-// =============================================================================
-// Governance Blueprint Tests
-// =============================================================================
-
 #[test]
-fn test_governance_instantiate() {
+fn test_governance_instantiate_with_composite_rule() {
     let mut ledger = LedgerSimulatorBuilder::new().build();
-    let (owner_badge, _owner_account, _public_key) = create_owner_badge_with_account(&mut ledger);
-    let params = create_governance_parameters();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let (badge_a, account_a, pk_a) = create_owner_badge_with_account(&mut ledger);
+    let (badge_b, account_b, pk_b) = create_owner_badge_with_account(&mut ledger);
 
+    // Both badges are required for owner and moderator methods
     let manifest = ManifestBuilder::new()
         .lock_fee_from_faucet()
         .call_function(
-            ledger.compile_and_publish(this_package!()),
+            package_address,
             "Governance",
-            "instantiate",
-            manifest_args!(owner_badge, params),
+            "instantiate_with_rule",
+            manifest_args!(
+                rule!(require(badge_a) && require(badge_b)),
+                create_governance_parameters(),
+                create_branding(),
+                RoyaltyAmount::Free
+            ),
         )
         .build();
-
     let receipt = ledger.execute_manifest(manifest, vec![]);
-    receipt.expect_commit_success();
+    let governance_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+    // A single badge is not enough
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        (badge_a, account_a, pk_a),
+        "set_identity_verifier",
+        manifest_args!(None::<ComponentAddress>),
+    );
+    receipt.expect_commit_failure();
+
+    // Both badges together pass
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(account_a, badge_a, dec!(1))
+        .create_proof_from_account_of_amount(account_b, badge_b, dec!(1))
+        .call_method(
+            governance_component,
+            "set_identity_verifier",
+            manifest_args!(None::<ComponentAddress>),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![
+                NonFungibleGlobalId::from_public_key(&pk_a),
+                NonFungibleGlobalId::from_public_key(&pk_b),
+            ],
+        )
+        .expect_commit_success();
 }
 
 #[test]
@@ -132,7 +101,7 @@ fn test_make_temperature_check() {
             package_address,
             "Governance",
             "instantiate",
-            manifest_args!(owner_badge, params),
+            manifest_args!(owner_badge, params, create_branding(), RoyaltyAmount::Free),
         )
         .build();
 
@@ -192,7 +161,7 @@ fn test_vote_on_temperature_check() {
             package_address,
             "Governance",
             "instantiate",
-            manifest_args!(owner_badge, params),
+            manifest_args!(owner_badge, params, create_branding(), RoyaltyAmount::Free),
         )
         .build();
 
@@ -254,7 +223,7 @@ fn test_cannot_vote_twice_on_temperature_check() {
             package_address,
             "Governance",
             "instantiate",
-            manifest_args!(owner_badge, params),
+            manifest_args!(owner_badge, params, create_branding(), RoyaltyAmount::Free),
         )
         .build();
 
@@ -332,7 +301,7 @@ fn test_make_proposal_from_temperature_check() {
             package_address,
             "Governance",
             "instantiate",
-            manifest_args!(owner_badge, params),
+            manifest_args!(owner_badge, params, create_branding(), RoyaltyAmount::Free),
         )
         .build();
 
@@ -357,6 +326,35 @@ fn test_make_proposal_from_temperature_check() {
         )
         .expect_commit_success();
 
+    // Pass the temperature check
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_temperature_check",
+            manifest_args!(author_account, 0u64, TemperatureCheckVote::For),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&author_pk)],
+        )
+        .expect_commit_success();
+
+    advance_time(&mut ledger, 8 * 24 * 60 * 60);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "finalize_temperature_check",
+            manifest_args!(0u64),
+        )
+        .build();
+    ledger
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+
     // Elevate to proposal (requires owner badge proof for auth)
     let manifest = ManifestBuilder::new()
         .lock_fee_from_faucet()
@@ -404,7 +402,7 @@ fn test_vote_delegation_instantiate() {
             ledger.compile_and_publish(this_package!()),
             "VoteDelegation",
             "instantiate",
-            manifest_args!(owner_badge),
+            manifest_args!(owner_badge, RoyaltyAmount::Free),
         )
         .build();
 
@@ -429,7 +427,7 @@ fn test_make_delegation() {
             package_address,
             "VoteDelegation",
             "instantiate",
-            manifest_args!(owner_badge),
+            manifest_args!(owner_badge, RoyaltyAmount::Free),
         )
         .build();
 
@@ -487,7 +485,7 @@ fn test_remove_delegation() {
             package_address,
             "VoteDelegation",
             "instantiate",
-            manifest_args!(owner_badge),
+            manifest_args!(owner_badge, RoyaltyAmount::Free),
         )
         .build();
 
@@ -562,7 +560,7 @@ fn test_cannot_delegate_more_than_100_percent() {
             package_address,
             "VoteDelegation",
             "instantiate",
-            manifest_args!(owner_badge),
+            manifest_args!(owner_badge, RoyaltyAmount::Free),
         )
         .build();
 
@@ -621,7 +619,7 @@ fn test_cannot_delegate_to_self() {
             package_address,
             "VoteDelegation",
             "instantiate",
-            manifest_args!(owner_badge),
+            manifest_args!(owner_badge, RoyaltyAmount::Free),
         )
         .build();
 
@@ -671,7 +669,7 @@ fn test_multi_choice_proposal_voting() {
             package_address,
             "Governance",
             "instantiate",
-            manifest_args!(owner_badge, params),
+            manifest_args!(owner_badge, params, create_branding(), RoyaltyAmount::Free),
         )
         .build();
 
@@ -702,7 +700,7 @@ fn test_multi_choice_proposal_voting() {
         .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
         .call_method(
             governance_component,
-            "make_proposal",
+            "force_elevate",
             manifest_args!(0u64),
         )
         .build();
@@ -752,7 +750,7 @@ fn test_multi_choice_exceeds_max_selections() {
             package_address,
             "Governance",
             "instantiate",
-            manifest_args!(owner_badge, params),
+            manifest_args!(owner_badge, params, create_branding(), RoyaltyAmount::Free),
         )
         .build();
 
@@ -783,7 +781,7 @@ fn test_multi_choice_exceeds_max_selections() {
         .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
         .call_method(
             governance_component,
-            "make_proposal",
+            "force_elevate",
             manifest_args!(0u64),
         )
         .build();
@@ -837,7 +835,7 @@ fn test_single_choice_requires_exactly_one_vote() {
             package_address,
             "Governance",
             "instantiate",
-            manifest_args!(owner_badge, params),
+            manifest_args!(owner_badge, params, create_branding(), RoyaltyAmount::Free),
         )
         .build();
 
@@ -868,7 +866,7 @@ fn test_single_choice_requires_exactly_one_vote() {
         .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
         .call_method(
             governance_component,
-            "make_proposal",
+            "force_elevate",
             manifest_args!(0u64),
         )
         .build();
@@ -919,7 +917,7 @@ fn test_delegation_minimum_fraction() {
             package_address,
             "VoteDelegation",
             "instantiate",
-            manifest_args!(owner_badge),
+            manifest_args!(owner_badge, RoyaltyAmount::Free),
         )
         .build();
 
@@ -960,3 +958,7153 @@ fn test_delegation_minimum_fraction() {
     );
     receipt.expect_commit_success();
 }
+
+// =============================================================================
+// Turnout Tests
+// =============================================================================
+
+#[test]
+fn test_proposal_turnout_tracks_votes() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let (owner_badge, owner_account, owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let params = create_governance_parameters();
+
+    // Create author account
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+
+    // Create voter account
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    // Instantiate governance
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "Governance",
+            "instantiate",
+            manifest_args!(owner_badge, params, create_branding(), RoyaltyAmount::Free),
+        )
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let governance_component = receipt.expect_commit(true).new_component_addresses()[0];
+
+    // Create temperature check
+    let draft = create_temp_check_draft();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "make_temperature_check",
+            manifest_args!(author_account, draft),
+        )
+        .build();
+
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&author_pk)],
+        )
+        .expect_commit_success();
+
+    // Elevate to proposal
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(
+            governance_component,
+            "force_elevate",
+            manifest_args!(0u64),
+        )
+        .build();
+
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+        )
+        .expect_commit_success();
+
+    // Turnout starts empty
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "get_turnout",
+            manifest_args!(0u64),
+        )
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let turnout: ProposalTurnout = receipt.expect_commit_success().output(1);
+    assert_eq!(turnout.voter_count, 0);
+    assert_eq!(turnout.total_weight_cast, Decimal::ZERO);
+    assert!(turnout.eligible_weight > Decimal::ZERO);
+
+    // Vote
+    let votes: Vec<ProposalVoteOptionId> = vec![ProposalVoteOptionId(0)];
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_proposal",
+            manifest_args!(voter_account, 0u64, votes),
+        )
+        .build();
+
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+        )
+        .expect_commit_success();
+
+    // Turnout reflects the vote
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "get_turnout",
+            manifest_args!(0u64),
+        )
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let turnout: ProposalTurnout = receipt.expect_commit_success().output(1);
+    assert_eq!(turnout.voter_count, 1);
+    assert!(turnout.total_weight_cast > Decimal::ZERO);
+    assert!(turnout.turnout_fraction > Decimal::ZERO);
+}
+
+// =============================================================================
+// Delegation Statistics Tests
+// =============================================================================
+
+#[test]
+fn test_delegation_stats() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let package_address = ledger.compile_and_publish(this_package!());
+
+    // Create two delegators and one delegatee
+    let (delegator_a_pk, _delegator_a_sk, delegator_a) = ledger.new_allocated_account();
+    let (delegator_b_pk, _delegator_b_sk, delegator_b) = ledger.new_allocated_account();
+    let (_delegatee_pk, _delegatee_sk, delegatee_account) = ledger.new_allocated_account();
+
+    // Instantiate vote delegation
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "VoteDelegation",
+            "instantiate",
+            manifest_args!(owner_badge, RoyaltyAmount::Free),
+        )
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let delegation_component = receipt.expect_commit(true).new_component_addresses()[0];
+
+    let valid_until = Instant::new(i64::MAX / 2);
+
+    // Both delegators delegate to the same delegatee
+    for (delegator_pk, delegator) in [(delegator_a_pk, delegator_a), (delegator_b_pk, delegator_b)] {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                delegation_component,
+                "make_delegation",
+                manifest_args!(delegator, delegatee_account, dec!("0.5"), valid_until),
+            )
+            .build();
+
+        ledger
+            .execute_manifest(
+                manifest,
+                vec![NonFungibleGlobalId::from_public_key(&delegator_pk)],
+            )
+            .expect_commit_success();
+    }
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "get_delegation_stats",
+            manifest_args!(),
+        )
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let stats: DelegationStats = receipt.expect_commit_success().output(1);
+    assert_eq!(stats.total_active_delegations, 2);
+    assert_eq!(stats.unique_delegators, 2);
+    assert_eq!(stats.unique_delegatees, 1);
+    assert_eq!(stats.largest_delegatee.map(|(_, count)| count), Some(2));
+
+    // Remove one delegation
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "remove_delegation",
+            manifest_args!(delegator_a, delegatee_account),
+        )
+        .build();
+
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&delegator_a_pk)],
+        )
+        .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "get_delegation_stats",
+            manifest_args!(),
+        )
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let stats: DelegationStats = receipt.expect_commit_success().output(1);
+    assert_eq!(stats.total_active_delegations, 1);
+    assert_eq!(stats.unique_delegators, 1);
+    assert_eq!(stats.unique_delegatees, 1);
+    assert_eq!(stats.largest_delegatee.map(|(_, count)| count), Some(1));
+
+    // Delegator A moves to a second delegatee, then B leaves the first one: the second delegatee
+    // becomes the largest instead of the stats losing track of it
+    let (_other_pk, _other_sk, other_delegatee) = ledger.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "make_delegation",
+            manifest_args!(delegator_a, other_delegatee, dec!("0.5"), valid_until),
+        )
+        .build();
+
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&delegator_a_pk)],
+        )
+        .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "remove_delegation",
+            manifest_args!(delegator_b, delegatee_account),
+        )
+        .build();
+
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&delegator_b_pk)],
+        )
+        .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "get_delegation_stats",
+            manifest_args!(),
+        )
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let stats: DelegationStats = receipt.expect_commit_success().output(1);
+    assert_eq!(stats.unique_delegatees, 1);
+    assert_eq!(
+        stats.largest_delegatee,
+        Some((Global::<Account>::from(other_delegatee), 1))
+    );
+}
+
+#[test]
+fn test_remove_all_delegations() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let package_address = ledger.compile_and_publish(this_package!());
+
+    // Create one delegator and two delegatees
+    let (delegator_pk, _delegator_sk, delegator_account) = ledger.new_allocated_account();
+    let (_delegatee_a_pk, _delegatee_a_sk, delegatee_a) = ledger.new_allocated_account();
+    let (_delegatee_b_pk, _delegatee_b_sk, delegatee_b) = ledger.new_allocated_account();
+
+    // Instantiate vote delegation
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "VoteDelegation",
+            "instantiate",
+            manifest_args!(owner_badge, RoyaltyAmount::Free),
+        )
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let delegation_component = receipt.expect_commit(true).new_component_addresses()[0];
+
+    let valid_until = Instant::new(i64::MAX / 2);
+
+    // Delegate to both delegatees
+    for delegatee in [delegatee_a, delegatee_b] {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                delegation_component,
+                "make_delegation",
+                manifest_args!(delegator_account, delegatee, dec!("0.4"), valid_until),
+            )
+            .build();
+
+        ledger
+            .execute_manifest(
+                manifest,
+                vec![NonFungibleGlobalId::from_public_key(&delegator_pk)],
+            )
+            .expect_commit_success();
+    }
+
+    // Remove all delegations at once
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "remove_all_delegations",
+            manifest_args!(delegator_account),
+        )
+        .build();
+
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&delegator_pk)],
+    );
+    receipt.expect_commit_success();
+
+    // Verify no delegations remain
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "get_delegations",
+            manifest_args!(delegator_account),
+        )
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let delegations: Vec<Delegation> = receipt.expect_commit_success().output(1);
+    assert!(delegations.is_empty());
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "get_delegatee_delegators",
+            manifest_args!(delegatee_b, delegator_account),
+        )
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let fraction: Option<Decimal> = receipt.expect_commit_success().output(1);
+    assert_eq!(fraction, None);
+}
+
+#[test]
+fn test_migrate_delegatee() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let package_address = ledger.compile_and_publish(this_package!());
+
+    // Create delegator, old delegatee and new delegatee accounts
+    let (delegator_pk, _delegator_sk, delegator_account) = ledger.new_allocated_account();
+    let (old_pk, _old_sk, old_account) = ledger.new_allocated_account();
+    let (new_pk, _new_sk, new_account) = ledger.new_allocated_account();
+
+    // Instantiate vote delegation
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "VoteDelegation",
+            "instantiate",
+            manifest_args!(owner_badge, RoyaltyAmount::Free),
+        )
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let delegation_component = receipt.expect_commit(true).new_component_addresses()[0];
+
+    let valid_until = Instant::new(i64::MAX / 2);
+
+    // Delegate to the old account
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "make_delegation",
+            manifest_args!(delegator_account, old_account, dec!("0.5"), valid_until),
+        )
+        .build();
+
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&delegator_pk)],
+        )
+        .expect_commit_success();
+
+    // Migration without the new account's proof should fail
+    let delegators = vec![delegator_account];
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "migrate_delegatee",
+            manifest_args!(old_account, new_account, delegators.clone()),
+        )
+        .build();
+
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&old_pk)],
+    );
+    receipt.expect_commit_failure();
+
+    // Migration with both proofs succeeds
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "migrate_delegatee",
+            manifest_args!(old_account, new_account, delegators),
+        )
+        .build();
+
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![
+            NonFungibleGlobalId::from_public_key(&old_pk),
+            NonFungibleGlobalId::from_public_key(&new_pk),
+        ],
+    );
+    receipt.expect_commit_success();
+
+    // The delegation now points at the new account
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "get_delegatee_delegators",
+            manifest_args!(new_account, delegator_account),
+        )
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let fraction: Option<Decimal> = receipt.expect_commit_success().output(1);
+    assert_eq!(fraction, Some(dec!("0.5")));
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "get_delegations",
+            manifest_args!(delegator_account),
+        )
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let delegations: Vec<Delegation> = receipt.expect_commit_success().output(1);
+    assert_eq!(delegations.len(), 1);
+    assert_eq!(delegations[0].delegatee.address(), new_account);
+}
+
+// =============================================================================
+// Delegated Voting Tests
+// =============================================================================
+
+#[test]
+fn test_direct_vote_overrides_delegated_vote() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let delegation_component = instantiate_vote_delegation(&mut ledger, package_address, owner.0);
+
+    link_vote_delegation(&mut ledger, governance_component, delegation_component, owner);
+
+    let (delegator_pk, _delegator_sk, delegator_account) = ledger.new_allocated_account();
+    let (delegatee_pk, _delegatee_sk, delegatee_account) = ledger.new_allocated_account();
+
+    // Delegate half of the voting power
+    let valid_until = Instant::new(i64::MAX / 2);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "make_delegation",
+            manifest_args!(delegator_account, delegatee_account, dec!("0.5"), valid_until),
+        )
+        .build();
+
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&delegator_pk)],
+        )
+        .expect_commit_success();
+
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+
+    // Casting delegated power before the delegatee voted fails
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_proposal_as_delegatee",
+            manifest_args!(delegatee_account, proposal_id, vec![delegator_account]),
+        )
+        .build();
+
+    ledger
+        .execute_manifest(
+            manifest.clone(),
+            vec![NonFungibleGlobalId::from_public_key(&delegatee_pk)],
+        )
+        .expect_commit_failure();
+
+    // Delegatee votes, then casts the delegated power
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (delegatee_pk, delegatee_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&delegatee_pk)],
+        )
+        .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "get_delegated_votes",
+            manifest_args!(proposal_id, delegator_account),
+        )
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest.clone(), vec![]);
+    let delegated: Vec<DelegatedVote> = receipt.expect_commit_success().output(1);
+    assert_eq!(delegated.len(), 1);
+
+    // Delegator votes directly, overriding the delegated vote
+    let receipt = vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (delegator_pk, delegator_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(1)],
+    );
+    receipt.expect_commit_success();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let delegated: Vec<DelegatedVote> = receipt.expect_commit_success().output(1);
+    assert!(delegated.is_empty());
+}
+
+#[test]
+fn test_delegation_made_after_proposal_start_is_ignored() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let delegation_component = instantiate_vote_delegation(&mut ledger, package_address, owner.0);
+
+    link_vote_delegation(&mut ledger, governance_component, delegation_component, owner);
+
+    let (delegator_pk, _delegator_sk, delegator_account) = ledger.new_allocated_account();
+    let (delegatee_pk, _delegatee_sk, delegatee_account) = ledger.new_allocated_account();
+
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+    advance_time(&mut ledger, 60);
+
+    // Delegate after the proposal started
+    let valid_until = Instant::new(i64::MAX / 2);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "make_delegation",
+            manifest_args!(delegator_account, delegatee_account, dec!("0.5"), valid_until),
+        )
+        .build();
+
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&delegator_pk)],
+        )
+        .expect_commit_success();
+
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (delegatee_pk, delegatee_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+
+    // The delegation did not exist at the snapshot, so it cannot be cast
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_proposal_as_delegatee",
+            manifest_args!(delegatee_account, proposal_id, vec![delegator_account]),
+        )
+        .build();
+
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&delegatee_pk)],
+        )
+        .expect_commit_failure();
+}
+
+// =============================================================================
+// Branding Tests
+// =============================================================================
+
+#[test]
+fn test_update_branding() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+
+    // Branding is written to metadata at instantiation
+    let name = ledger.get_metadata(governance_component.into(), "name");
+    assert_eq!(name, Some(MetadataValue::String("Test DAO".to_string())));
+
+    let mut branding = create_branding();
+    branding.dao_name = "Renamed DAO".to_string();
+
+    // Non-owner cannot update branding
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "update_branding",
+            manifest_args!(branding.clone()),
+        )
+        .build();
+
+    ledger
+        .execute_manifest(manifest, vec![])
+        .expect_commit_failure();
+
+    // Owner updates branding
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_branding",
+        manifest_args!(branding),
+    )
+    .expect_commit_success();
+
+    let name = ledger.get_metadata(governance_component.into(), "name");
+    assert_eq!(name, Some(MetadataValue::String("Renamed DAO".to_string())));
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_dao_name", manifest_args!())
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let dao_name: String = receipt.expect_commit_success().output(1);
+    assert_eq!(dao_name, "Renamed DAO");
+
+    // An empty DAO name is rejected at instantiation too
+    let mut branding = create_branding();
+    branding.dao_name = String::new();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "Governance",
+            "instantiate",
+            manifest_args!(owner.0, create_governance_parameters(), branding, RoyaltyAmount::Free),
+        )
+        .build();
+    expect_error(ledger.execute_manifest(manifest, vec![]), GovernanceError::EmptyDaoName);
+}
+
+// =============================================================================
+// Voting Power Tests
+// =============================================================================
+
+#[test]
+fn test_voting_power_config_weights_balances() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (_voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_voting_power", manifest_args!(voter_account))
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest.clone(), vec![]);
+    let full_power: Decimal = receipt.expect_commit_success().output(1);
+    assert!(full_power > Decimal::ZERO);
+
+    // Weigh XRD at half
+    let config = VotingPowerConfig {
+        resource_weights: vec![(XRD, dec!("0.5"))],
+        mode: VotingPowerMode::CurrentBalance,
+    };
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_voting_power_config",
+        manifest_args!(config),
+    )
+    .expect_commit_success();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let half_power: Decimal = receipt.expect_commit_success().output(1);
+    assert_eq!(half_power, full_power * dec!("0.5"));
+
+    // Duplicate resources are rejected
+    let config = VotingPowerConfig {
+        resource_weights: vec![(XRD, dec!(1)), (XRD, dec!(2))],
+        mode: VotingPowerMode::CurrentBalance,
+    };
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_voting_power_config",
+        manifest_args!(config),
+    )
+    .expect_commit_failure();
+}
+
+// =============================================================================
+// Denylist Tests
+// =============================================================================
+
+#[test]
+fn test_denylisted_account_cannot_vote() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "add_to_denylist",
+        manifest_args!(voter_account, "Known exploiter".to_string()),
+    )
+    .expect_commit_success();
+
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_failure();
+
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "remove_from_denylist",
+        manifest_args!(voter_account),
+    )
+    .expect_commit_success();
+
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+}
+
+#[test]
+fn test_identity_verifier_rejects_unverified_accounts() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+
+    let verifier_package = ledger.compile_and_publish(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/assets/identity_verifier"
+    ));
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(verifier_package, "IdentityVerifier", "instantiate", manifest_args!())
+        .build();
+    let verifier = ledger
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success()
+        .new_component_addresses()[0];
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "set_identity_verifier",
+        manifest_args!(Some(verifier)),
+    )
+    .expect_commit_success();
+
+    let submit_draft = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                governance_component,
+                "make_temperature_check",
+                manifest_args!(author_account, create_temp_check_draft()),
+            )
+            .build();
+        ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&author_pk)])
+    };
+
+    // Unverified voters and authors are rejected
+    expect_error(
+        vote_on_proposal(
+            &mut ledger,
+            governance_component,
+            (voter_pk, voter_account),
+            proposal_id,
+            vec![ProposalVoteOptionId(0)],
+        ),
+        GovernanceError::AccountNotVerified,
+    );
+    expect_error(submit_draft(&mut ledger), GovernanceError::AccountNotVerified);
+
+    // Once verified they pass
+    for account in [voter_account, author_account] {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(verifier, "verify", manifest_args!(account))
+            .build();
+        ledger.execute_manifest(manifest, vec![]).expect_commit_success();
+    }
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+    submit_draft(&mut ledger).expect_commit_success();
+}
+
+// =============================================================================
+// Fee Subsidy Tests
+// =============================================================================
+
+#[test]
+fn test_fee_subsidy_limits() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+
+    // Anyone can fund the subsidy
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .get_free_xrd_from_faucet()
+        .take_from_worktop(XRD, dec!(1000), "funds")
+        .call_method_with_name_lookup(governance_component, "fund_fee_subsidy", |lookup| {
+            (lookup.bucket("funds"),)
+        })
+        .try_deposit_entire_worktop_or_abort(owner.1, None)
+        .build();
+    ledger.execute_manifest(manifest, vec![]).expect_commit_success();
+
+    let subsidy_balance = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(governance_component, "get_fee_subsidy_balance", manifest_args!())
+            .build();
+        let balance: Decimal = ledger
+            .execute_manifest(manifest, vec![])
+            .expect_commit_success()
+            .output(1);
+        balance
+    };
+    assert_eq!(subsidy_balance(&mut ledger), dec!(1000));
+
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_fee_subsidy_config",
+        manifest_args!(Some(FeeSubsidyConfig {
+            max_fee_per_vote: Decimal::ZERO,
+            max_subsidized_votes_per_day: 2,
+        })),
+    );
+    expect_error(receipt, GovernanceError::NonPositiveFeeCap);
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_fee_subsidy_config",
+        manifest_args!(Some(FeeSubsidyConfig {
+            max_fee_per_vote: dec!(10),
+            max_subsidized_votes_per_day: 0,
+        })),
+    );
+    expect_error(receipt, GovernanceError::NoSubsidizedVotes);
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_fee_subsidy_config",
+        manifest_args!(Some(FeeSubsidyConfig {
+            max_fee_per_vote: dec!(10),
+            max_subsidized_votes_per_day: 2,
+        })),
+    )
+    .expect_commit_success();
+
+    let proposal_ids: Vec<u64> =
+        (0..4).map(|_| create_proposal(&mut ledger, governance_component, owner)).collect();
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    // The subsidy pays the fees of successful votes first, at most 10 XRD each
+    let subsidized_vote = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+                           (voter_pk, voter_account): (Secp256k1PublicKey, ComponentAddress),
+                           proposal_id: u64,
+                           fail_afterwards: bool| {
+        let mut builder = ManifestBuilder::new().lock_fee_from_faucet().call_method(
+            governance_component,
+            "vote_on_proposal",
+            manifest_args!(voter_account, proposal_id, vec![ProposalVoteOptionId(0)]),
+        );
+        if fail_afterwards {
+            builder = builder.assert_worktop_contains(XRD, dec!(1));
+        }
+        ledger.execute_manifest(
+            builder.build(),
+            vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+        )
+    };
+    for proposal_id in &proposal_ids[..2] {
+        let before = subsidy_balance(&mut ledger);
+        subsidized_vote(&mut ledger, (voter_pk, voter_account), *proposal_id, false)
+            .expect_commit_success();
+        let spent = before - subsidy_balance(&mut ledger);
+        assert!(spent.is_positive() && spent <= dec!(10));
+    }
+
+    // The account's third vote of the day is not subsidized
+    let before = subsidy_balance(&mut ledger);
+    subsidized_vote(&mut ledger, (voter_pk, voter_account), proposal_ids[2], false)
+        .expect_commit_success();
+    assert_eq!(subsidy_balance(&mut ledger), before);
+
+    // A transaction failing after the vote costs the subsidy nothing, and does not use up
+    // the daily limit
+    let (other_pk, _other_sk, other_account) = ledger.new_allocated_account();
+    for _ in 0..3 {
+        let receipt =
+            subsidized_vote(&mut ledger, (other_pk, other_account), proposal_ids[0], true);
+        assert!(!receipt.is_commit_success());
+        assert_eq!(subsidy_balance(&mut ledger), before);
+    }
+
+    // The limit is per account, and resets after a day
+    subsidized_vote(&mut ledger, (other_pk, other_account), proposal_ids[0], false)
+        .expect_commit_success();
+    assert!(subsidy_balance(&mut ledger) < before);
+    advance_time(&mut ledger, 24 * 60 * 60);
+    let before = subsidy_balance(&mut ledger);
+    subsidized_vote(&mut ledger, (voter_pk, voter_account), proposal_ids[3], false)
+        .expect_commit_success();
+    assert!(subsidy_balance(&mut ledger) < before);
+}
+
+// =============================================================================
+// Storage Reimbursement Tests
+// =============================================================================
+
+#[test]
+fn test_storage_reimbursement_receipts() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "make_temperature_check",
+            manifest_args!(author_account, create_temp_check_draft()),
+        )
+        .build();
+    let temperature_check_id: u64 = ledger
+        .execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&author_pk)])
+        .expect_commit_success()
+        .output(1);
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "force_elevate",
+        manifest_args!(temperature_check_id),
+    );
+    let proposal_id: u64 = receipt.expect_commit_success().output(2);
+    let other_proposal_id = create_proposal(&mut ledger, governance_component, owner);
+
+    // Receipt 1 covers 30 XRD of fees for the proposal, receipt 2 is for another proposal
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_non_fungible_resource(
+            OwnerRole::None,
+            NonFungibleIdType::Integer,
+            false,
+            NonFungibleResourceRoles::default(),
+            metadata!(),
+            Some([
+                (
+                    NonFungibleLocalId::integer(1),
+                    StorageReceipt { proposal_id, amount: dec!(30) },
+                ),
+                (
+                    NonFungibleLocalId::integer(2),
+                    StorageReceipt { proposal_id: other_proposal_id, amount: dec!(30) },
+                ),
+            ]),
+        )
+        .try_deposit_entire_worktop_or_abort(author_account, None)
+        .build();
+    let receipt_resource = ledger
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success()
+        .new_resource_addresses()[0];
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "set_storage_receipt_resource",
+        manifest_args!(receipt_resource),
+    )
+    .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .get_free_xrd_from_faucet()
+        .take_from_worktop(XRD, dec!(1000), "funds")
+        .call_method_with_name_lookup(
+            governance_component,
+            "fund_storage_reimbursements",
+            |lookup| (lookup.bucket("funds"),),
+        )
+        .try_deposit_entire_worktop_or_abort(owner.1, None)
+        .build();
+    ledger.execute_manifest(manifest, vec![]).expect_commit_success();
+
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+    advance_time(&mut ledger, 15 * 24 * 60 * 60);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "finalize_proposal", manifest_args!(proposal_id))
+        .build();
+    ledger.execute_manifest(manifest, vec![]).expect_commit_success();
+
+    let claim = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+                 receipt_id: u64,
+                 amount: Decimal| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(
+                author_account,
+                receipt_resource,
+                [NonFungibleLocalId::integer(receipt_id)],
+            )
+            .pop_from_auth_zone("receipt_proof")
+            .call_method_with_name_lookup(
+                governance_component,
+                "claim_storage_reimbursement",
+                |lookup| (proposal_id, author_account, amount, lookup.proof("receipt_proof")),
+            )
+            .try_deposit_entire_worktop_or_abort(author_account, None)
+            .build();
+        ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&author_pk)])
+    };
+
+    // A receipt only covers its own proposal, up to the fees it records
+    expect_error(claim(&mut ledger, 2, dec!(10)), GovernanceError::InvalidStorageReceipt);
+    expect_error(
+        claim(&mut ledger, 1, dec!(31)),
+        GovernanceError::ReimbursementExceedsReceipt,
+    );
+
+    let balance_before = ledger.get_component_balance(author_account, XRD);
+    claim(&mut ledger, 1, dec!(30)).expect_commit_success();
+    assert_eq!(ledger.get_component_balance(author_account, XRD), balance_before + dec!(30));
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_storage_reimbursement_balance", manifest_args!())
+        .build();
+    let escrow: Decimal = ledger
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success()
+        .output(1);
+    assert_eq!(escrow, dec!(970));
+
+    // The same receipt cannot be used again, even within the proposal's cap
+    expect_error(
+        claim(&mut ledger, 1, dec!(10)),
+        GovernanceError::StorageReceiptAlreadyClaimed,
+    );
+}
+
+// =============================================================================
+// Finalization Tests
+// =============================================================================
+
+#[test]
+fn test_finalize_proposal() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "finalize_proposal", manifest_args!(proposal_id))
+        .build();
+
+    // Cannot finalize before the deadline
+    ledger
+        .execute_manifest(manifest.clone(), vec![])
+        .expect_commit_failure();
+
+    advance_time(&mut ledger, 15 * 24 * 60 * 60);
+
+    let receipt = ledger.execute_manifest(manifest.clone(), vec![]);
+    let status: ProposalStatus = receipt.expect_commit_success().output(1);
+    assert_eq!(status, ProposalStatus::Passed);
+
+    // Cannot finalize twice
+    ledger
+        .execute_manifest(manifest, vec![])
+        .expect_commit_failure();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_proposal_tally", manifest_args!(proposal_id))
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let tally: ProposalTally = receipt.expect_commit_success().output(1);
+    assert!(tally.quorum_met);
+    assert!(tally.threshold_met);
+    assert_eq!(tally.winning_options, vec![ProposalVoteOptionId(0)]);
+    assert_eq!(tally.options[0].voter_count, 1);
+}
+
+#[test]
+fn test_auto_elevate_after_review_period() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    let mut draft = create_temp_check_draft();
+    draft.auto_elevate = true;
+    let temperature_check_id = create_temperature_check(&mut ledger, governance_component, draft);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_temperature_check",
+            manifest_args!(voter_account, temperature_check_id, TemperatureCheckVote::For),
+        )
+        .build();
+
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+        )
+        .expect_commit_success();
+
+    // Temperature check lasts 7 days
+    advance_time(&mut ledger, 7 * 24 * 60 * 60);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "finalize_temperature_check",
+            manifest_args!(temperature_check_id),
+        )
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let status: TemperatureCheckStatus = receipt.expect_commit_success().output(1);
+    assert_eq!(status, TemperatureCheckStatus::Passed);
+
+    // Review period (2 days) has not ended yet
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "auto_elevate", manifest_args!(temperature_check_id))
+        .build();
+
+    ledger
+        .execute_manifest(manifest.clone(), vec![])
+        .expect_commit_failure();
+
+    advance_time(&mut ledger, 2 * 24 * 60 * 60);
+
+    ledger
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+}
+
+#[test]
+fn test_reopen_proposal_carries_over_decayed_votes() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+
+    advance_time(&mut ledger, 15 * 24 * 60 * 60);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "finalize_proposal", manifest_args!(proposal_id))
+        .build();
+
+    ledger
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "reopen_proposal",
+        manifest_args!(proposal_id),
+    );
+    let reopened_id: u64 = receipt.expect_commit_success().output(2);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_proposal_tally", manifest_args!(proposal_id))
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let original: ProposalTally = receipt.expect_commit_success().output(1);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_proposal_tally", manifest_args!(reopened_id))
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest.clone(), vec![]);
+    let carried: ProposalTally = receipt.expect_commit_success().output(1);
+    assert_eq!(carried.options[0].weight, original.options[0].weight * dec!("0.5"));
+
+    // Voting again replaces the carried-over vote
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        reopened_id,
+        vec![ProposalVoteOptionId(1)],
+    )
+    .expect_commit_success();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let replaced: ProposalTally = receipt.expect_commit_success().output(1);
+    assert_eq!(replaced.options[0].weight, Decimal::ZERO);
+    assert!(replaced.options[1].weight > Decimal::ZERO);
+}
+
+#[test]
+fn test_reopen_chain_replaces_all_carried_votes() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    let finalize_and_reopen = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+                               proposal_id: u64| {
+        advance_time(ledger, 15 * 24 * 60 * 60);
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(governance_component, "finalize_proposal", manifest_args!(proposal_id))
+            .build();
+        ledger.execute_manifest(manifest, vec![]).expect_commit_success();
+        call_as_owner(
+            ledger,
+            governance_component,
+            owner,
+            "reopen_proposal",
+            manifest_args!(proposal_id),
+        )
+    };
+    let get_tally = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+                     proposal_id: u64| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(governance_component, "get_proposal_tally", manifest_args!(proposal_id))
+            .call_method(governance_component, "get_turnout", manifest_args!(proposal_id))
+            .build();
+        let receipt = ledger.execute_manifest(manifest, vec![]);
+        let commit = receipt.expect_commit_success();
+        (commit.output::<ProposalTally>(1), commit.output::<ProposalTurnout>(2))
+    };
+
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+    let (original, _) = get_tally(&mut ledger, proposal_id);
+
+    // The voter sits out the first reopen, so its vote is carried over twice
+    let first_reopen: u64 = finalize_and_reopen(&mut ledger, proposal_id)
+        .expect_commit_success()
+        .output(2);
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "reopen_proposal",
+        manifest_args!(proposal_id),
+    );
+    expect_error(receipt, GovernanceError::AlreadyReopened);
+    let second_reopen: u64 = finalize_and_reopen(&mut ledger, first_reopen)
+        .expect_commit_success()
+        .output(2);
+
+    let (carried, turnout) = get_tally(&mut ledger, second_reopen);
+    assert_eq!(carried.options[0].weight, original.options[0].weight * dec!("0.25"));
+    assert_eq!(carried.options[0].voter_count, 1);
+    assert_eq!(turnout.voter_count, 1);
+
+    // Voting on the latest proposal replaces the vote carried over from the first one
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        second_reopen,
+        vec![ProposalVoteOptionId(1)],
+    )
+    .expect_commit_success();
+
+    let (replaced, turnout) = get_tally(&mut ledger, second_reopen);
+    assert_eq!(replaced.options[0].weight, Decimal::ZERO);
+    assert_eq!(replaced.options[0].voter_count, 0);
+    assert!(replaced.options[1].weight > Decimal::ZERO);
+    assert_eq!(replaced.options[1].voter_count, 1);
+    assert_eq!(turnout.voter_count, 1);
+}
+
+#[test]
+fn test_failed_reconfirmation_lapses_decision() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+
+    advance_time(&mut ledger, 15 * 24 * 60 * 60);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "finalize_proposal", manifest_args!(proposal_id))
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let status: ProposalStatus = receipt.expect_commit_success().output(1);
+    assert_eq!(status, ProposalStatus::Passed);
+
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "execute_proposal",
+        manifest_args!(proposal_id, Some(30u16)),
+    )
+    .expect_commit_success();
+
+    // Not due yet
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "spawn_reconfirmation", manifest_args!(proposal_id))
+        .build();
+    ledger
+        .execute_manifest(manifest.clone(), vec![])
+        .expect_commit_failure();
+
+    advance_time(&mut ledger, 31 * 24 * 60 * 60);
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let reconfirmation_id: u64 = receipt.expect_commit_success().output(1);
+
+    // Nobody votes, so the re-confirmation is rejected
+    advance_time(&mut ledger, 15 * 24 * 60 * 60);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "finalize_proposal", manifest_args!(reconfirmation_id))
+        .call_method(governance_component, "get_proposal_status", manifest_args!(proposal_id))
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let status: ProposalStatus = receipt.expect_commit_success().output(2);
+    assert_eq!(status, ProposalStatus::Lapsed);
+}
+
+#[test]
+fn test_preview_vote() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "preview_vote",
+            manifest_args!(voter_account, proposal_id, vec![ProposalVoteOptionId(0)]),
+        )
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest.clone(), vec![]);
+    let preview: VotePreview = receipt.expect_commit_success().output(1);
+    assert_eq!(preview.failure_reason, None);
+    assert!(preview.weight > Decimal::ZERO);
+
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let preview: VotePreview = receipt.expect_commit_success().output(1);
+    assert_eq!(
+        preview.failure_reason,
+        Some(GovernanceError::AlreadyVotedOnProposal)
+    );
+}
+
+// =============================================================================
+// Error Code Tests
+// =============================================================================
+
+#[test]
+fn test_error_codes_are_stable() {
+    assert_eq!(GovernanceError::ProposalNotFound.code(), "GOV-002");
+    assert_eq!(
+        GovernanceError::TooManySelections { max: 2 }.to_string(),
+        "[GOV-024] Cannot select more than 2 options"
+    );
+    assert_eq!(DelegationError::SelfDelegation.code(), "DEL-002");
+}
+
+#[test]
+fn test_vote_twice_fails_with_error_code() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+
+    let receipt = vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    );
+    expect_error(receipt, GovernanceError::AlreadyVotedOnProposal);
+
+    let receipt = vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id + 1,
+        vec![ProposalVoteOptionId(0)],
+    );
+    expect_error(receipt, GovernanceError::ProposalNotFound);
+}
+
+#[test]
+fn test_self_delegation_fails_with_error_code() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let delegation_component = instantiate_vote_delegation(&mut ledger, package_address, owner_badge);
+    let (delegator_pk, _delegator_sk, delegator_account) = ledger.new_allocated_account();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "make_delegation",
+            manifest_args!(delegator_account, delegator_account, dec!("0.5"), Instant::new(i64::MAX / 2)),
+        )
+        .build();
+
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&delegator_pk)],
+    );
+    expect_error(receipt, DelegationError::SelfDelegation);
+}
+
+// =============================================================================
+// Draft Validation Tests
+// =============================================================================
+
+#[test]
+fn test_draft_length_limits() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+
+    let mut draft = create_temp_check_draft();
+    draft.title = "a".repeat(MAX_TITLE_LENGTH);
+    draft.short_description = "a".repeat(MAX_SHORT_DESCRIPTION_LENGTH);
+    draft.description = "a".repeat(10_000);
+    create_temperature_check(&mut ledger, governance_component, draft);
+
+    let mut draft = create_temp_check_draft();
+    draft.title = "a".repeat(MAX_TITLE_LENGTH + 1);
+    let receipt = submit_temperature_check(&mut ledger, governance_component, draft);
+    expect_error(receipt, GovernanceError::TitleTooLong);
+
+    let mut draft = create_temp_check_draft();
+    draft.short_description = "a".repeat(MAX_SHORT_DESCRIPTION_LENGTH + 1);
+    let receipt = submit_temperature_check(&mut ledger, governance_component, draft);
+    expect_error(receipt, GovernanceError::ShortDescriptionTooLong);
+
+    let mut draft = create_temp_check_draft();
+    draft.description = "a".repeat(10_001);
+    let receipt = submit_temperature_check(&mut ledger, governance_component, draft);
+    expect_error(receipt, GovernanceError::DescriptionTooLong { max: 10_000 });
+}
+
+#[test]
+fn test_draft_option_label_validation() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+
+    let mut draft = create_temp_check_draft();
+    draft.vote_options[0].label = String::new();
+    let receipt = submit_temperature_check(&mut ledger, governance_component, draft);
+    expect_error(receipt, GovernanceError::EmptyOptionLabel);
+
+    let mut draft = create_temp_check_draft();
+    draft.vote_options[0].label = "a".repeat(MAX_OPTION_LABEL_LENGTH + 1);
+    let receipt = submit_temperature_check(&mut ledger, governance_component, draft);
+    expect_error(receipt, GovernanceError::OptionLabelTooLong);
+
+    let mut draft = create_temp_check_draft();
+    draft.vote_options[1].label = draft.vote_options[0].label.clone();
+    let receipt = submit_temperature_check(&mut ledger, governance_component, draft);
+    expect_error(receipt, GovernanceError::DuplicateOptionLabel);
+}
+
+#[test]
+fn test_draft_links_must_use_https() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+
+    let mut draft = create_temp_check_draft();
+    draft.links = vec![Url::of("http://radixtalk.com/proposal/123")];
+    let receipt = submit_temperature_check(&mut ledger, governance_component, draft);
+    expect_error(receipt, GovernanceError::InsecureLink);
+}
+
+#[test]
+fn test_description_file_hash_commitment() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+
+    // The governance component stands in for the radix-file-storage component
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "add_trusted_storage",
+        manifest_args!(governance_component),
+    )
+    .expect_commit_success();
+
+    let content = "# Long Proposal\n\nA description too long to keep in component state.";
+    let mut draft = create_temp_check_draft();
+    draft.description = String::new();
+    draft.description_file = Some(DescriptionFile {
+        file: File {
+            kvs_address: "internal_keyvaluestore_sim1".to_string(),
+            component_address: governance_component,
+            file_hash: "abc123".to_string(),
+        },
+        content_hash: hash(content.as_bytes()),
+    });
+    let file_tc_id = create_temperature_check(&mut ledger, governance_component, draft);
+    let inline_tc_id =
+        create_temperature_check(&mut ledger, governance_component, create_temp_check_draft());
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "verify_description_hash",
+            manifest_args!(file_tc_id, hash(content.as_bytes())),
+        )
+        .call_method(
+            governance_component,
+            "verify_description_hash",
+            manifest_args!(file_tc_id, hash("tampered".as_bytes())),
+        )
+        .call_method(
+            governance_component,
+            "verify_description_hash",
+            manifest_args!(inline_tc_id, hash(create_temp_check_draft().description.as_bytes())),
+        )
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let commit = receipt.expect_commit_success();
+    assert!(commit.output::<bool>(1));
+    assert!(!commit.output::<bool>(2));
+    assert!(commit.output::<bool>(3));
+}
+
+#[test]
+fn test_draft_attachments_metadata() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+
+    // The governance component stands in for the radix-file-storage component
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "add_trusted_storage",
+        manifest_args!(governance_component),
+    )
+    .expect_commit_success();
+
+    let attachment = Attachment {
+        file: File {
+            kvs_address: "internal_keyvaluestore_sim1".to_string(),
+            component_address: governance_component,
+            file_hash: "abc123".to_string(),
+        },
+        label: "Budget breakdown".to_string(),
+        mime_type: "application/pdf".to_string(),
+        size_bytes: 2 * 1024 * 1024,
+    };
+
+    // Every attachment needs a label, and together they are bounded by the declared size
+    let mut draft = create_temp_check_draft();
+    draft.attachments = vec![Attachment { label: String::new(), ..attachment.clone() }];
+    let receipt = submit_temperature_check(&mut ledger, governance_component, draft.clone());
+    expect_error(receipt, GovernanceError::InvalidAttachment);
+
+    draft.attachments = vec![
+        attachment.clone(),
+        Attachment { size_bytes: MAX_ATTACHMENTS_TOTAL_SIZE, ..attachment.clone() },
+    ];
+    let receipt = submit_temperature_check(&mut ledger, governance_component, draft.clone());
+    expect_error(receipt, GovernanceError::AttachmentsTooLarge);
+
+    draft.attachments = vec![attachment.clone()];
+    let temperature_check_id = create_temperature_check(&mut ledger, governance_component, draft);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "get_temperature_check",
+            manifest_args!(temperature_check_id),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let tc: TemperatureCheckView = receipt.expect_commit_success().output(1);
+    assert_eq!(tc.attachments, vec![attachment]);
+}
+
+#[test]
+fn test_discussion_hash_commitment() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+
+    let post = "RFC: fund the community grants program for another quarter";
+    let mut draft = create_temp_check_draft();
+    draft.discussion_hash = Some(hash(post.as_bytes()));
+    let committed_tc_id = create_temperature_check(&mut ledger, governance_component, draft);
+    let uncommitted_tc_id =
+        create_temperature_check(&mut ledger, governance_component, create_temp_check_draft());
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "verify_discussion_hash",
+            manifest_args!(committed_tc_id, hash(post.as_bytes())),
+        )
+        .call_method(
+            governance_component,
+            "verify_discussion_hash",
+            manifest_args!(committed_tc_id, hash("edited after the vote started".as_bytes())),
+        )
+        .call_method(
+            governance_component,
+            "verify_discussion_hash",
+            manifest_args!(uncommitted_tc_id, hash(post.as_bytes())),
+        )
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let commit = receipt.expect_commit_success();
+    assert!(commit.output::<bool>(1));
+    assert!(!commit.output::<bool>(2));
+    assert!(!commit.output::<bool>(3));
+}
+
+// =============================================================================
+// Parameter History Tests
+// =============================================================================
+
+#[test]
+fn test_parameter_history_records_changes() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+
+    let mut new_params = create_governance_parameters();
+    new_params.proposal_quorum = dec!(10000);
+    new_params.binding_quorum = dec!(20000);
+
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_governance_parameters",
+        manifest_args!(new_params),
+    )
+    .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_parameter_history", manifest_args!())
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let history: Vec<ParameterChange> = receipt.expect_commit_success().output(1);
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].old_params.proposal_quorum, dec!(5000));
+    assert_eq!(history[0].new_params.proposal_quorum, dec!(10000));
+}
+
+#[test]
+fn test_voting_period_floors_enforced_on_update() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+
+    let mut params = create_governance_parameters();
+    params.temperature_check_days = MIN_TEMPERATURE_CHECK_DAYS - 1;
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_governance_parameters",
+        manifest_args!(params),
+    );
+    expect_error(receipt, GovernanceError::TemperatureCheckLengthOutOfBounds);
+
+    let mut params = create_governance_parameters();
+    params.proposal_length_days = MIN_PROPOSAL_LENGTH_DAYS - 1;
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_governance_parameters",
+        manifest_args!(params),
+    );
+    expect_error(receipt, GovernanceError::ProposalLengthOutOfBounds);
+
+    let mut params = create_governance_parameters();
+    params.signal_phase_days = MIN_SIGNAL_PHASE_DAYS - 1;
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_governance_parameters",
+        manifest_args!(params),
+    );
+    expect_error(receipt, GovernanceError::SignalPhaseLengthOutOfBounds);
+
+    // The bounds themselves are accepted
+    let mut params = create_governance_parameters();
+    params.temperature_check_days = MIN_TEMPERATURE_CHECK_DAYS;
+    params.proposal_length_days = MIN_PROPOSAL_LENGTH_DAYS;
+    params.signal_phase_days = MIN_SIGNAL_PHASE_DAYS;
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_governance_parameters",
+        manifest_args!(params),
+    )
+    .expect_commit_success();
+}
+
+#[test]
+fn test_voting_period_ceilings_enforced_on_update() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+
+    let mut params = create_governance_parameters();
+    params.temperature_check_days = MAX_TEMPERATURE_CHECK_DAYS + 1;
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_governance_parameters",
+        manifest_args!(params),
+    );
+    expect_error(receipt, GovernanceError::TemperatureCheckLengthOutOfBounds);
+
+    let mut params = create_governance_parameters();
+    params.proposal_length_days = MAX_PROPOSAL_LENGTH_DAYS + 1;
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_governance_parameters",
+        manifest_args!(params),
+    );
+    expect_error(receipt, GovernanceError::ProposalLengthOutOfBounds);
+
+    let mut params = create_governance_parameters();
+    params.signal_phase_days = MAX_SIGNAL_PHASE_DAYS + 1;
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_governance_parameters",
+        manifest_args!(params),
+    );
+    expect_error(receipt, GovernanceError::SignalPhaseLengthOutOfBounds);
+
+    let mut params = create_governance_parameters();
+    params.temperature_check_days = MAX_TEMPERATURE_CHECK_DAYS;
+    params.proposal_length_days = MAX_PROPOSAL_LENGTH_DAYS;
+    params.signal_phase_days = MAX_SIGNAL_PHASE_DAYS;
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_governance_parameters",
+        manifest_args!(params),
+    )
+    .expect_commit_success();
+}
+
+// =============================================================================
+// View Getter Tests
+// =============================================================================
+
+#[test]
+fn test_binding_quorum_above_proposal_quorum() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+
+    for binding_quorum in [Decimal::ZERO, dec!(5000)] {
+        let mut params = create_governance_parameters();
+        params.binding_quorum = binding_quorum;
+        let receipt = call_as_owner(
+            &mut ledger,
+            governance_component,
+            owner,
+            "update_governance_parameters",
+            manifest_args!(params),
+        );
+        expect_error(receipt, GovernanceError::InvalidBindingQuorum);
+    }
+
+    let mut params = create_governance_parameters();
+    params.binding_quorum = dec!("5000.000000000000000001");
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_governance_parameters",
+        manifest_args!(params),
+    )
+    .expect_commit_success();
+}
+
+#[test]
+fn test_get_temperature_check_and_proposal_views() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_proposal", manifest_args!(proposal_id))
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let proposal: ProposalView = receipt.expect_commit_success().output(1);
+    assert_eq!(proposal.title, "Test Proposal");
+    assert_eq!(proposal.status, ProposalStatus::Active);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "get_temperature_check",
+            manifest_args!(proposal.temperature_check_id),
+        )
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let tc: TemperatureCheckView = receipt.expect_commit_success().output(1);
+    assert_eq!(tc.elevated_proposal_id, Some(proposal_id));
+    assert_eq!(tc.vote_options.len(), 2);
+}
+
+#[test]
+fn test_temperature_check_result_and_navigation() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    let temperature_check_id =
+        create_temperature_check(&mut ledger, governance_component, create_temp_check_draft());
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_temperature_check",
+            manifest_args!(voter_account, temperature_check_id, TemperatureCheckVote::For),
+        )
+        .build();
+    ledger
+        .execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&voter_pk)])
+        .expect_commit_success();
+
+    advance_time(&mut ledger, 8 * 24 * 60 * 60);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "finalize_temperature_check",
+            manifest_args!(temperature_check_id),
+        )
+        .build();
+    ledger
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "make_proposal",
+        manifest_args!(temperature_check_id),
+    );
+    let proposal_id: u64 = receipt.expect_commit_success().output(2);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "get_temperature_check_result",
+            manifest_args!(temperature_check_id),
+        )
+        .call_method(
+            governance_component,
+            "get_proposal_for_temperature_check",
+            manifest_args!(temperature_check_id),
+        )
+        .call_method(
+            governance_component,
+            "get_temperature_check_for_proposal",
+            manifest_args!(proposal_id),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let commit = receipt.expect_commit_success();
+
+    let result: TemperatureCheckResult = commit.output(1);
+    assert!(result.passed);
+    assert!(result.for_weight > Decimal::ZERO);
+    assert_eq!(result.against_weight, Decimal::ZERO);
+    assert_eq!(result.elevated_proposal_id, Some(proposal_id));
+    let linked_proposal: Option<u64> = commit.output(2);
+    assert_eq!(linked_proposal, Some(proposal_id));
+    let linked_temperature_check: u64 = commit.output(3);
+    assert_eq!(linked_temperature_check, temperature_check_id);
+}
+
+#[test]
+fn test_governance_stats() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+    create_proposal(&mut ledger, governance_component, owner);
+
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+
+    advance_time(&mut ledger, 15 * 24 * 60 * 60);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "finalize_proposal", manifest_args!(proposal_id))
+        .call_method(governance_component, "get_governance_stats", manifest_args!())
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let stats: GovernanceStats = receipt.expect_commit_success().output(2);
+    assert_eq!(stats.proposal_count, 2);
+    assert_eq!(stats.active_proposals, 1);
+    assert_eq!(stats.passed_proposals, 1);
+    assert_eq!(stats.unique_voters, 1);
+    assert!(stats.total_weight_cast > Decimal::ZERO);
+}
+
+#[test]
+fn test_elevate_seconded_temperature_check() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    let temperature_check_id =
+        create_temperature_check(&mut ledger, governance_component, create_temp_check_draft());
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_temperature_check",
+            manifest_args!(voter_account, temperature_check_id, TemperatureCheckVote::For),
+        )
+        .call_method(
+            governance_component,
+            "second_temperature_check",
+            manifest_args!(voter_account, temperature_check_id),
+        )
+        .build();
+
+    ledger
+        .execute_manifest(
+            manifest.clone(),
+            vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+        )
+        .expect_commit_success();
+
+    advance_time(&mut ledger, 7 * 24 * 60 * 60);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "finalize_temperature_check",
+            manifest_args!(temperature_check_id),
+        )
+        .call_method(governance_component, "elevate_seconded", manifest_args!(temperature_check_id))
+        .build();
+
+    // The voter's endorsed power exceeds the propose threshold of 1000
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let proposal_id: u64 = receipt.expect_commit_success().output(2);
+    assert_eq!(proposal_id, 0);
+}
+
+#[test]
+fn test_second_temperature_check_only_once() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    let temperature_check_id =
+        create_temperature_check(&mut ledger, governance_component, create_temp_check_draft());
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "second_temperature_check",
+            manifest_args!(voter_account, temperature_check_id),
+        )
+        .build();
+
+    ledger
+        .execute_manifest(
+            manifest.clone(),
+            vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+        )
+        .expect_commit_success();
+
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+    );
+    expect_error(receipt, GovernanceError::AlreadySeconded);
+}
+
+// =============================================================================
+// Execution Approval Tests
+// =============================================================================
+
+/// Approves executing a proposal with the executor badge of the given ID
+fn approve_execution(
+    ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+    governance_component: ComponentAddress,
+    executor: (Secp256k1PublicKey, ComponentAddress, ResourceAddress),
+    badge_id: u64,
+    proposal_id: u64,
+) -> TransactionReceipt {
+    let (executor_pk, executor_account, executor_badge) = executor;
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(
+            executor_account,
+            executor_badge,
+            [NonFungibleLocalId::integer(badge_id)],
+        )
+        .pop_from_auth_zone("executor_proof")
+        .call_method_with_name_lookup(governance_component, "approve_execution", |lookup| {
+            (proposal_id, lookup.proof("executor_proof"))
+        })
+        .build();
+
+    ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&executor_pk)],
+    )
+}
+
+#[test]
+fn test_execution_requires_distinct_executor_approvals() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+    let (executor_pk, _executor_sk, executor_account) = ledger.new_allocated_account();
+    // Mints badges with IDs 1, 2 and 3 to the executor account
+    let executor_badge = ledger.create_non_fungible_resource(executor_account);
+    let executor = (executor_pk, executor_account, executor_badge);
+
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "set_execution_approval_config",
+        manifest_args!(Some(ExecutionApprovalConfig {
+            executor_badge,
+            required_approvals: 2,
+        })),
+    )
+    .expect_commit_success();
+
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+
+    advance_time(&mut ledger, 15 * 24 * 60 * 60);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "finalize_proposal", manifest_args!(proposal_id))
+        .build();
+    ledger
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+
+    approve_execution(&mut ledger, governance_component, executor, 1, proposal_id)
+        .expect_commit_success();
+
+    // The same executor cannot approve twice
+    let receipt = approve_execution(&mut ledger, governance_component, executor, 1, proposal_id);
+    expect_error(receipt, GovernanceError::ExecutionAlreadyApproved);
+
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "execute_proposal",
+        manifest_args!(proposal_id, None::<u16>),
+    );
+    expect_error(receipt, GovernanceError::InsufficientExecutionApprovals { required: 2 });
+
+    approve_execution(&mut ledger, governance_component, executor, 2, proposal_id)
+        .expect_commit_success();
+
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "execute_proposal",
+        manifest_args!(proposal_id, None::<u16>),
+    )
+    .expect_commit_success();
+}
+
+#[test]
+fn test_draft_action_requires_registered_executor() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (_pk, _sk, executor_component) = ledger.new_allocated_account();
+
+    let args = scrypto_encode(&1u64).unwrap();
+    let mut draft = create_temp_check_draft();
+    draft.action = Some(ProposalAction::CallExecutor {
+        executor_id: 0,
+        args: args.clone(),
+    });
+    let receipt = submit_temperature_check(&mut ledger, governance_component, draft.clone());
+    expect_error(receipt, GovernanceError::ExecutorNotFound);
+
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "register_executor",
+        manifest_args!(executor_component),
+    );
+    let executor_id: u64 = receipt.expect_commit_success().output(2);
+    assert_eq!(executor_id, 0);
+
+    create_temperature_check(&mut ledger, governance_component, draft);
+}
+
+// =============================================================================
+// Vote Bond Tests
+// =============================================================================
+
+#[test]
+fn test_vote_bond_locked_and_reclaimed() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    let mut draft = create_temp_check_draft();
+    draft.vote_bond = Some(dec!(10));
+    let temperature_check_id = create_temperature_check(&mut ledger, governance_component, draft);
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "force_elevate",
+        manifest_args!(temperature_check_id),
+    );
+    let proposal_id: u64 = receipt.expect_commit_success().output(2);
+
+    // Plain votes are rejected on bonded proposals
+    let receipt = vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    );
+    expect_error(receipt, GovernanceError::VoteBondRequired);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(voter_account, XRD, dec!(15))
+        .take_all_from_worktop(XRD, "bond")
+        .call_method_with_name_lookup(governance_component, "vote_on_proposal_with_bond", |lookup| {
+            (voter_account, proposal_id, vec![ProposalVoteOptionId(0)], lookup.bucket("bond"))
+        })
+        .deposit_batch(voter_account)
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+        )
+        .expect_commit_success();
+
+    let reclaim = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "reclaim_vote_bond",
+            manifest_args!(voter_account, proposal_id),
+        )
+        .deposit_batch(voter_account)
+        .build();
+
+    // Locked until finalization
+    let receipt = ledger.execute_manifest(
+        reclaim.clone(),
+        vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+    );
+    expect_error(receipt, GovernanceError::VoteBondLocked);
+
+    advance_time(&mut ledger, 15 * 24 * 60 * 60);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "finalize_proposal", manifest_args!(proposal_id))
+        .build();
+    ledger
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+
+    ledger
+        .execute_manifest(
+            reclaim.clone(),
+            vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+        )
+        .expect_commit_success();
+
+    let receipt = ledger.execute_manifest(
+        reclaim,
+        vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+    );
+    expect_error(receipt, GovernanceError::NoVoteBond);
+}
+
+// =============================================================================
+// Archiving Tests
+// =============================================================================
+
+#[test]
+fn test_archive_proposal_after_retention() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+
+    advance_time(&mut ledger, 15 * 24 * 60 * 60);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "finalize_proposal", manifest_args!(proposal_id))
+        .build();
+    ledger
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+
+    // Too early: the retention window has not elapsed
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "archive_proposal",
+        manifest_args!(proposal_id),
+    );
+    expect_error(receipt, GovernanceError::ArchiveRetentionNotElapsed);
+
+    advance_time(&mut ledger, 30 * 24 * 60 * 60);
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "archive_proposal",
+        manifest_args!(proposal_id),
+    )
+    .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_archived_proposal", manifest_args!(proposal_id))
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let archived: ArchivedProposal = receipt.expect_commit_success().output(1);
+    assert_eq!(archived.status, ProposalStatus::Passed);
+    assert_eq!(archived.voter_count, 1);
+
+    // The proposal is no longer in the active store
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_proposal_status", manifest_args!(proposal_id))
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    expect_error(receipt, GovernanceError::ProposalNotFound);
+}
+
+// =============================================================================
+// Weight Cap Tests
+// =============================================================================
+
+#[test]
+fn test_weight_cap_limits_account_weight_in_tally() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    let mut params = create_governance_parameters();
+    params.max_weight_per_account = Some(WeightCap::Fixed(dec!(1000)));
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_governance_parameters",
+        manifest_args!(params),
+    )
+    .expect_commit_success();
+
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_proposal_tally", manifest_args!(proposal_id))
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let tally: ProposalTally = receipt.expect_commit_success().output(1);
+
+    // The voter holds more than the cap: only the cap counts, the raw weight is kept alongside
+    assert_eq!(tally.weight_cap, Some(dec!(1000)));
+    assert_eq!(tally.turnout.total_weight_cast, dec!(1000));
+    assert_eq!(tally.options[0].weight, dec!(1000));
+    assert!(tally.raw_total_weight_cast > dec!(1000));
+    assert_eq!(tally.options[0].raw_weight, tally.raw_total_weight_cast);
+}
+
+#[test]
+fn test_invalid_weight_cap_rejected() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+
+    let mut params = create_governance_parameters();
+    params.max_weight_per_account = Some(WeightCap::SupplyFraction(dec!("1.5")));
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_governance_parameters",
+        manifest_args!(params),
+    );
+    expect_error(receipt, GovernanceError::InvalidWeightCap);
+}
+
+#[test]
+fn test_excess_delegation_casts_power_above_cap() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let delegation_component = instantiate_vote_delegation(&mut ledger, package_address, owner.0);
+
+    link_vote_delegation(&mut ledger, governance_component, delegation_component, owner);
+
+    let mut params = create_governance_parameters();
+    params.max_weight_per_account = Some(WeightCap::Fixed(dec!(5000)));
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_governance_parameters",
+        manifest_args!(params),
+    )
+    .expect_commit_success();
+
+    let (delegator_pk, _delegator_sk, delegator_account) = ledger.new_allocated_account();
+    let (delegatee_pk, _delegatee_sk, delegatee_account) = ledger.new_allocated_account();
+
+    // Delegator holds 19000 XRD, delegatee 1000
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(delegatee_account, XRD, dec!(9000))
+        .deposit_batch(delegator_account)
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&delegatee_pk)],
+        )
+        .expect_commit_success();
+
+    // Delegate a quarter of the 14000 above the cap
+    let valid_until = Instant::new(i64::MAX / 2);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "make_excess_delegation",
+            manifest_args!(delegator_account, delegatee_account, dec!("0.25"), valid_until),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&delegator_pk)],
+        )
+        .expect_commit_success();
+
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (delegatee_pk, delegatee_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_proposal_as_delegatee",
+            manifest_args!(delegatee_account, proposal_id, vec![delegator_account]),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&delegatee_pk)],
+        )
+        .expect_commit_success();
+
+    // The delegator still votes directly with the power it kept
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (delegator_pk, delegator_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(1)],
+    )
+    .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_proposal_tally", manifest_args!(proposal_id))
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let tally: ProposalTally = receipt.expect_commit_success().output(1);
+
+    // Delegatee: 1000 own + 3500 delegated excess; delegator: 15500 kept, capped at 5000
+    assert_eq!(tally.options[0].weight, dec!(4500));
+    assert_eq!(tally.options[1].weight, dec!(5000));
+    assert_eq!(tally.options[1].raw_weight, dec!(15500));
+    assert_eq!(tally.raw_total_weight_cast, dec!(20000));
+
+    // Full and excess delegations cannot be mixed
+    let (_other_pk, _other_sk, other_account) = ledger.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "make_delegation",
+            manifest_args!(delegator_account, other_account, dec!("0.25"), valid_until),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&delegator_pk)],
+    );
+    expect_error(receipt, DelegationError::MixedDelegationModes);
+}
+
+// =============================================================================
+// Co-Author Tests
+// =============================================================================
+
+#[test]
+fn test_co_authors_must_consent() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (author_pk, _author_sk, author_account) = ledger.new_allocated_account();
+    let (co_author_pk, _co_author_sk, co_author_account) = ledger.new_allocated_account();
+
+    let mut draft = create_temp_check_draft();
+    draft.co_authors = vec![co_author_account];
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "make_temperature_check",
+            manifest_args!(author_account, draft),
+        )
+        .build();
+
+    // Without the co-author's proof, the draft is rejected
+    ledger
+        .execute_manifest(
+            manifest.clone(),
+            vec![NonFungibleGlobalId::from_public_key(&author_pk)],
+        )
+        .expect_commit_failure();
+
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![
+            NonFungibleGlobalId::from_public_key(&author_pk),
+            NonFungibleGlobalId::from_public_key(&co_author_pk),
+        ],
+    );
+    let temperature_check_id: u64 = receipt.expect_commit_success().output(1);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "get_temperature_check",
+            manifest_args!(temperature_check_id),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let view: TemperatureCheckView = receipt.expect_commit_success().output(1);
+    assert_eq!(view.co_authors, vec![Global::<Account>::from(co_author_account)]);
+
+    // The author cannot also be listed as a co-author
+    let mut draft = create_temp_check_draft();
+    draft.co_authors = vec![author_account];
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "make_temperature_check",
+            manifest_args!(author_account, draft),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&author_pk)],
+    );
+    expect_error(receipt, GovernanceError::DuplicateCoAuthor);
+}
+
+// =============================================================================
+// Private Consultation Tests
+// =============================================================================
+
+#[test]
+fn test_members_only_proposal() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (member_pk, _member_sk, member_account) = ledger.new_allocated_account();
+    let (late_pk, _late_sk, late_account) = ledger.new_allocated_account();
+
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "add_member",
+        manifest_args!(member_account),
+    )
+    .expect_commit_success();
+
+    let mut draft = create_temp_check_draft();
+    draft.members_only = true;
+    let temperature_check_id = create_temperature_check(&mut ledger, governance_component, draft);
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "force_elevate",
+        manifest_args!(temperature_check_id),
+    );
+    let proposal_id: u64 = receipt.expect_commit_success().output(2);
+
+    // Members added after creation are not part of the snapshot
+    advance_time(&mut ledger, 60);
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "add_member",
+        manifest_args!(late_account),
+    )
+    .expect_commit_success();
+
+    let receipt = vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (late_pk, late_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    );
+    expect_error(receipt, GovernanceError::NotAMember);
+
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (member_pk, member_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+
+    // The tally stays hidden until finalization
+    let tally_manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_proposal_tally", manifest_args!(proposal_id))
+        .build();
+    let receipt = ledger.execute_manifest(tally_manifest.clone(), vec![]);
+    expect_error(receipt, GovernanceError::TallyHiddenUntilFinalized);
+
+    advance_time(&mut ledger, 15 * 24 * 60 * 60);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "finalize_proposal", manifest_args!(proposal_id))
+        .build();
+    ledger
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+
+    let receipt = ledger.execute_manifest(tally_manifest, vec![]);
+    let tally: ProposalTally = receipt.expect_commit_success().output(1);
+    assert_eq!(tally.turnout.voter_count, 1);
+}
+
+// =============================================================================
+// Two-Phase Proposal Tests
+// =============================================================================
+
+#[test]
+fn test_two_phase_proposal_signal_then_binding() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let voter = ledger.new_allocated_account();
+    let voter = (voter.0, voter.2);
+
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "make_two_phase_proposal",
+        manifest_args!(owner.1, create_temp_check_draft()),
+    );
+    let commit = receipt.expect_commit_success();
+    let proposal_id: u64 = commit.output(2);
+    // The skipped temperature check vote is still reported as finalized
+    assert!(commit
+        .application_events
+        .iter()
+        .any(|(event_type, _)| ledger.event_name(event_type) == "TemperatureCheckFinalizedEvent"));
+
+    let signal_vote = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                governance_component,
+                "vote_on_signal",
+                manifest_args!(voter.1, proposal_id, vec![ProposalVoteOptionId(0)]),
+            )
+            .build();
+        ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&voter.0)])
+    };
+
+    // The binding window only opens once the signal window has ended
+    signal_vote(&mut ledger).expect_commit_success();
+    expect_error(signal_vote(&mut ledger), GovernanceError::AlreadyVotedOnSignal);
+    let receipt = vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        voter,
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    );
+    expect_error(receipt, GovernanceError::VotingNotStarted);
+
+    advance_time(&mut ledger, 3 * 24 * 60 * 60 + 60);
+    expect_error(signal_vote(&mut ledger), GovernanceError::VotingEnded);
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        voter,
+        proposal_id,
+        vec![ProposalVoteOptionId(1)],
+    )
+    .expect_commit_success();
+
+    // Both tallies are kept apart and the binding one uses the binding quorum
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_signal_tally", manifest_args!(proposal_id))
+        .call_method(governance_component, "get_proposal_tally", manifest_args!(proposal_id))
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let commit = receipt.expect_commit_success();
+    let signal_tally: ProposalTally = commit.output(1);
+    let binding_tally: ProposalTally = commit.output(2);
+    assert_eq!(signal_tally.winning_options, vec![ProposalVoteOptionId(0)]);
+    assert!(signal_tally.quorum_met);
+    assert_eq!(binding_tally.winning_options, vec![ProposalVoteOptionId(1)]);
+    assert!(binding_tally.quorum_met);
+
+    // A regular proposal has no signal window
+    let regular_id = create_proposal(&mut ledger, governance_component, owner);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_signal_tally", manifest_args!(regular_id))
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    expect_error(receipt, GovernanceError::NotTwoPhase);
+}
+
+// =============================================================================
+// Voter Export Tests
+// =============================================================================
+
+#[test]
+fn test_export_voters_pages_in_voting_order() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+
+    let mut voters = Vec::new();
+    for option in [0u32, 1, 0] {
+        let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+        vote_on_proposal(
+            &mut ledger,
+            governance_component,
+            (voter_pk, voter_account),
+            proposal_id,
+            vec![ProposalVoteOptionId(option)],
+        )
+        .expect_commit_success();
+        voters.push((voter_account, option));
+    }
+
+    let export = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+                  start: u64,
+                  limit: u64| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                governance_component,
+                "export_voters",
+                manifest_args!(proposal_id, start, limit),
+            )
+            .build();
+        let receipt = ledger.execute_manifest(manifest, vec![]);
+        let page: Vec<(Global<Account>, Vec<ProposalVoteOptionId>, Decimal)> =
+            receipt.expect_commit_success().output(1);
+        page
+    };
+
+    let first_page = export(&mut ledger, 0, 2);
+    let second_page = export(&mut ledger, 2, 2);
+    assert_eq!(first_page.len(), 2);
+    assert_eq!(second_page.len(), 1);
+
+    for (index, (account, votes, weight)) in first_page.into_iter().chain(second_page).enumerate() {
+        let (voter_account, option) = voters[index];
+        assert_eq!(account, Global::<Account>::from(voter_account));
+        assert_eq!(votes, vec![ProposalVoteOptionId(option)]);
+        assert!(weight > Decimal::ZERO);
+    }
+
+    // Past the last voter the page is empty
+    assert!(export(&mut ledger, 3, 10).is_empty());
+}
+
+#[test]
+fn test_anonymous_proposal_keeps_only_aggregates() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+    let (other_pk, _other_sk, other_account) = ledger.new_allocated_account();
+
+    let mut draft = create_temp_check_draft();
+    draft.anonymous = true;
+    let temperature_check_id = create_temperature_check(&mut ledger, governance_component, draft);
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "force_elevate",
+        manifest_args!(temperature_check_id),
+    );
+    let proposal_id: u64 = receipt.expect_commit_success().output(2);
+
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (other_pk, other_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(1)],
+    )
+    .expect_commit_success();
+
+    // The "has voted" marker still prevents voting twice
+    let receipt = vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(1)],
+    );
+    expect_error(receipt, GovernanceError::AlreadyVotedOnProposal);
+
+    // Delegated power cannot follow a selection that is not recorded
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_proposal_as_delegatee",
+            manifest_args!(voter_account, proposal_id, vec![other_account]),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&voter_pk)],
+    );
+    expect_error(receipt, GovernanceError::AnonymousDelegatedVote);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "get_proposal_tally",
+            manifest_args!(proposal_id),
+        )
+        .call_method(
+            governance_component,
+            "export_voters",
+            manifest_args!(proposal_id, 0u64, 10u64),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let commit = receipt.expect_commit_success();
+
+    // Per-option aggregates are kept
+    let tally: ProposalTally = commit.output(1);
+    assert_eq!(tally.options[0].voter_count, 1);
+    assert_eq!(tally.options[1].voter_count, 1);
+    assert!(tally.options[0].weight > Decimal::ZERO);
+    assert!(tally.options[1].weight > Decimal::ZERO);
+
+    // Voters are known, but not which option each selected
+    let voters: Vec<(Global<Account>, Vec<ProposalVoteOptionId>, Decimal)> = commit.output(2);
+    assert_eq!(voters.len(), 2);
+    assert!(voters.iter().all(|(_, votes, _)| votes.is_empty()));
+}
+
+#[test]
+fn test_eligibility_allowlist_vote_with_merkle_proof() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+    let (other_pk, _other_sk, other_account) = ledger.new_allocated_account();
+
+    // Two-leaf allowlist: each leaf's proof is the other leaf
+    let voter_leaf = eligibility_leaf(voter_account, dec!(1234));
+    let other_leaf = eligibility_leaf(other_account, dec!(10));
+    let root = merkle_parent(voter_leaf, other_leaf);
+
+    let mut draft = create_temp_check_draft();
+    draft.eligibility_root = Some(root);
+    let temperature_check_id = create_temperature_check(&mut ledger, governance_component, draft);
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "force_elevate",
+        manifest_args!(temperature_check_id),
+    );
+    let proposal_id: u64 = receipt.expect_commit_success().output(2);
+
+    let vote_with_proof = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+                           (pk, account): (Secp256k1PublicKey, ComponentAddress),
+                           weight: Decimal,
+                           proof: Vec<Hash>| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                governance_component,
+                "vote_on_proposal_with_proof",
+                manifest_args!(account, proposal_id, vec![ProposalVoteOptionId(0)], weight, proof),
+            )
+            .build();
+        ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&pk)])
+    };
+
+    // Voting without a proof fails
+    let receipt = vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (other_pk, other_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    );
+    expect_error(receipt, GovernanceError::EligibilityProofRequired);
+
+    // Claiming a weight other than the listed one fails
+    let receipt = vote_with_proof(&mut ledger, (other_pk, other_account), dec!(1234), vec![voter_leaf]);
+    expect_error(receipt, GovernanceError::InvalidEligibilityProof);
+
+    // Valid proofs count at the listed weight
+    vote_with_proof(&mut ledger, (voter_pk, voter_account), dec!(1234), vec![other_leaf])
+        .expect_commit_success();
+    vote_with_proof(&mut ledger, (other_pk, other_account), dec!(10), vec![voter_leaf])
+        .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "get_proposal",
+            manifest_args!(proposal_id),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let proposal: ProposalView = receipt.expect_commit_success().output(1);
+    assert_eq!(proposal.eligibility_root, Some(root));
+    assert_eq!(proposal.voter_count, 2);
+    assert_eq!(proposal.total_weight_cast, dec!(1244));
+}
+
+// =============================================================================
+// Result Challenge Tests
+// =============================================================================
+
+#[test]
+fn test_challenged_result_becomes_disputed() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+    let (challenger_pk, _challenger_sk, challenger_account) = ledger.new_allocated_account();
+
+    // A threshold so low that a single challenger disputes the result
+    let mut params = create_governance_parameters();
+    params.challenge_period_days = 3;
+    params.challenge_threshold = dec!("0.000000000000000001");
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_governance_parameters",
+        manifest_args!(params),
+    )
+    .expect_commit_success();
+
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+    advance_time(&mut ledger, 15 * 24 * 60 * 60);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "finalize_proposal", manifest_args!(proposal_id))
+        .build();
+    ledger
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+
+    // No execution while the result can still be challenged
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "execute_proposal",
+        manifest_args!(proposal_id, None::<u16>),
+    );
+    expect_error(receipt, GovernanceError::ChallengeWindowOpen);
+
+    let challenge = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>, amount: Decimal| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(challenger_account, XRD, amount)
+            .take_all_from_worktop(XRD, "bond")
+            .call_method_with_name_lookup(governance_component, "challenge_result", |lookup| {
+                (challenger_account, proposal_id, lookup.bucket("bond"))
+            })
+            .deposit_batch(challenger_account)
+            .build();
+        ledger.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&challenger_pk)],
+        )
+    };
+
+    expect_error(challenge(&mut ledger, dec!(50)), GovernanceError::InsufficientChallengeBond);
+    challenge(&mut ledger, dec!(150)).expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_proposal_status", manifest_args!(proposal_id))
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let status: ProposalStatus = receipt.expect_commit_success().output(1);
+    assert_eq!(status, ProposalStatus::Disputed);
+
+    // The bond is returned once the result is disputed
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "reclaim_challenge_bond",
+            manifest_args!(challenger_account, proposal_id),
+        )
+        .deposit_batch(challenger_account)
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&challenger_pk)],
+        )
+        .expect_commit_success();
+
+    // A disputed result needs a re-vote
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "reopen_proposal",
+        manifest_args!(proposal_id),
+    )
+    .expect_commit_success();
+}
+
+// =============================================================================
+// Quorum Progress Tests
+// =============================================================================
+
+#[test]
+fn test_quorum_reached_on_crossing_vote() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_proposal", manifest_args!(proposal_id))
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest.clone(), vec![]);
+    let proposal: ProposalView = receipt.expect_commit_success().output(1);
+    assert!(!proposal.quorum_reached);
+
+    // The voter's balance alone exceeds the quorum
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let proposal: ProposalView = receipt.expect_commit_success().output(1);
+    assert!(proposal.quorum_reached);
+    assert!(proposal.total_weight_cast >= proposal.quorum);
+}
+
+// =============================================================================
+// Delegation Lookup Tests
+// =============================================================================
+
+#[test]
+fn test_delegation_existence_checks() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let delegation_component = instantiate_vote_delegation(&mut ledger, package_address, owner_badge);
+    let (delegator_pk, _delegator_sk, delegator_account) = ledger.new_allocated_account();
+    let (_delegatee_pk, _delegatee_sk, delegatee_account) = ledger.new_allocated_account();
+
+    let check = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                delegation_component,
+                "has_active_delegation",
+                manifest_args!(delegator_account, delegatee_account),
+            )
+            .call_method(
+                delegation_component,
+                "has_any_incoming",
+                manifest_args!(delegatee_account),
+            )
+            .build();
+        let receipt = ledger.execute_manifest(manifest, vec![]);
+        let commit = receipt.expect_commit_success();
+        let active: bool = commit.output(1);
+        let incoming: bool = commit.output(2);
+        (active, incoming)
+    };
+
+    assert_eq!(check(&mut ledger), (false, false));
+
+    // The delegation expires after one day
+    let valid_until = Instant::new(ledger.get_current_proposer_timestamp_ms() / 1000 + 24 * 60 * 60);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "make_delegation",
+            manifest_args!(delegator_account, delegatee_account, dec!("0.5"), valid_until),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&delegator_pk)],
+        )
+        .expect_commit_success();
+
+    assert_eq!(check(&mut ledger), (true, true));
+
+    // Once expired the delegation is no longer active, but still counted until pruned
+    advance_time(&mut ledger, 2 * 24 * 60 * 60);
+    assert_eq!(check(&mut ledger), (false, true));
+}
+
+#[test]
+fn test_update_delegation_fraction_keeps_expiry() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let delegation_component = instantiate_vote_delegation(&mut ledger, package_address, owner_badge);
+    let (delegator_pk, _delegator_sk, delegator_account) = ledger.new_allocated_account();
+    let (_delegatee_pk, _delegatee_sk, delegatee_account) = ledger.new_allocated_account();
+    let (_other_pk, _other_sk, other_account) = ledger.new_allocated_account();
+
+    let valid_until = Instant::new(i64::MAX / 2);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "make_delegation",
+            manifest_args!(delegator_account, delegatee_account, dec!("0.5"), valid_until),
+        )
+        .call_method(
+            delegation_component,
+            "make_delegation",
+            manifest_args!(delegator_account, other_account, dec!("0.3"), valid_until),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&delegator_pk)],
+        )
+        .expect_commit_success();
+
+    let update = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+                  new_fraction: Decimal| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                delegation_component,
+                "update_delegation_fraction",
+                manifest_args!(delegator_account, delegatee_account, new_fraction),
+            )
+            .build();
+        ledger.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&delegator_pk)],
+        )
+    };
+
+    // 0.8 plus the other 0.3 would exceed 100%
+    expect_error(update(&mut ledger, dec!("0.8")), DelegationError::TotalDelegationExceeded);
+    update(&mut ledger, dec!("0.7")).expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "get_delegations",
+            manifest_args!(delegator_account),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let delegations: Vec<Delegation> = receipt.expect_commit_success().output(1);
+    let updated = delegations
+        .iter()
+        .find(|d| d.delegatee == Global::<Account>::from(delegatee_account))
+        .unwrap();
+    assert_eq!(updated.fraction, dec!("0.7"));
+    assert_eq!(updated.valid_until, valid_until);
+}
+
+#[test]
+fn test_default_proxy_applies_without_other_delegations() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let delegation_component = instantiate_vote_delegation(&mut ledger, package_address, owner.0);
+    link_vote_delegation(&mut ledger, governance_component, delegation_component, owner);
+
+    let (proxy_pk, _proxy_sk, proxy_account) = ledger.new_allocated_account();
+    let (only_proxy_pk, _only_proxy_sk, only_proxy_account) = ledger.new_allocated_account();
+    let (mixed_pk, _mixed_sk, mixed_account) = ledger.new_allocated_account();
+    let (_expert_pk, _expert_sk, expert_account) = ledger.new_allocated_account();
+
+    // One delegator only has the proxy, the other also delegates half its power to an expert
+    let valid_until = Instant::new(i64::MAX / 2);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "make_default_proxy",
+            manifest_args!(only_proxy_account, proxy_account, valid_until),
+        )
+        .call_method(
+            delegation_component,
+            "make_default_proxy",
+            manifest_args!(mixed_account, proxy_account, valid_until),
+        )
+        .call_method(
+            delegation_component,
+            "make_delegation",
+            manifest_args!(mixed_account, expert_account, dec!("0.5"), valid_until),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![
+                NonFungibleGlobalId::from_public_key(&only_proxy_pk),
+                NonFungibleGlobalId::from_public_key(&mixed_pk),
+            ],
+        )
+        .expect_commit_success();
+
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (proxy_pk, proxy_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+
+    let cast = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+                delegator: ComponentAddress| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                governance_component,
+                "vote_on_proposal_as_delegatee",
+                manifest_args!(proxy_account, proposal_id, vec![delegator]),
+            )
+            .build();
+        ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&proxy_pk)])
+    };
+
+    expect_error(cast(&mut ledger, mixed_account), GovernanceError::DefaultProxyNotApplicable);
+    cast(&mut ledger, only_proxy_account).expect_commit_success();
+
+    // The proxy cast the delegator's full power
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "get_delegated_votes",
+            manifest_args!(proposal_id, only_proxy_account),
+        )
+        .call_method(
+            governance_component,
+            "get_voting_power",
+            manifest_args!(only_proxy_account),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let commit = receipt.expect_commit_success();
+    let delegated: Vec<DelegatedVote> = commit.output(1);
+    let power: Decimal = commit.output(2);
+    assert_eq!(delegated.len(), 1);
+    assert_eq!(delegated[0].weight, power);
+}
+
+#[test]
+fn test_delegated_breakdown_per_delegatee() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let delegation_component = instantiate_vote_delegation(&mut ledger, package_address, owner.0);
+    link_vote_delegation(&mut ledger, governance_component, delegation_component, owner);
+
+    let (delegatee_pk, _delegatee_sk, delegatee_account) = ledger.new_allocated_account();
+    let (first_pk, _first_sk, first_account) = ledger.new_allocated_account();
+    let (second_pk, _second_sk, second_account) = ledger.new_allocated_account();
+
+    let valid_until = Instant::new(i64::MAX / 2);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "make_delegation",
+            manifest_args!(first_account, delegatee_account, dec!("0.5"), valid_until),
+        )
+        .call_method(
+            delegation_component,
+            "make_delegation",
+            manifest_args!(second_account, delegatee_account, dec!("0.25"), valid_until),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![
+                NonFungibleGlobalId::from_public_key(&first_pk),
+                NonFungibleGlobalId::from_public_key(&second_pk),
+            ],
+        )
+        .expect_commit_success();
+
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (delegatee_pk, delegatee_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_proposal_as_delegatee",
+            manifest_args!(delegatee_account, proposal_id, vec![first_account, second_account]),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&delegatee_pk)],
+        )
+        .expect_commit_success();
+
+    let breakdown = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                governance_component,
+                "get_delegated_breakdown",
+                manifest_args!(proposal_id, delegatee_account),
+            )
+            .build();
+        let receipt = ledger.execute_manifest(manifest, vec![]);
+        let breakdown: Vec<(Global<Account>, Decimal)> = receipt.expect_commit_success().output(1);
+        breakdown
+    };
+
+    let cast = breakdown(&mut ledger);
+    assert_eq!(cast.len(), 2);
+    assert_eq!(cast[0].0, Global::<Account>::from(first_account));
+    assert_eq!(cast[1].0, Global::<Account>::from(second_account));
+    assert!(cast[0].1 > cast[1].1);
+
+    // A direct vote replaces the delegated contribution, which leaves the breakdown
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (first_pk, first_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(1)],
+    )
+    .expect_commit_success();
+
+    let cast = breakdown(&mut ledger);
+    assert_eq!(cast.len(), 1);
+    assert_eq!(cast[0].0, Global::<Account>::from(second_account));
+}
+
+#[test]
+fn test_delegation_scope_limits_vote_kinds() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let delegation_component = instantiate_vote_delegation(&mut ledger, package_address, owner.0);
+    link_vote_delegation(&mut ledger, governance_component, delegation_component, owner);
+
+    let (expert_pk, _expert_sk, expert_account) = ledger.new_allocated_account();
+    let (delegator_pk, _delegator_sk, delegator_account) = ledger.new_allocated_account();
+
+    // All temperature check power to the expert; proposals stay with the delegator
+    let valid_until = Instant::new(i64::MAX / 2);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "make_scoped_delegation",
+            manifest_args!(
+                delegator_account,
+                expert_account,
+                dec!(1),
+                valid_until,
+                DelegationScope::TemperatureChecksOnly
+            ),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&delegator_pk)],
+        )
+        .expect_commit_success();
+
+    let temperature_check_id =
+        create_temperature_check(&mut ledger, governance_component, create_temp_check_draft());
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_temperature_check",
+            manifest_args!(expert_account, temperature_check_id, TemperatureCheckVote::For),
+        )
+        .call_method(
+            governance_component,
+            "vote_on_temperature_check_as_delegatee",
+            manifest_args!(expert_account, temperature_check_id, vec![delegator_account]),
+        )
+        .build();
+    ledger
+        .execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&expert_pk)])
+        .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "get_voting_power",
+            manifest_args!(expert_account),
+        )
+        .call_method(
+            governance_component,
+            "get_voting_power",
+            manifest_args!(delegator_account),
+        )
+        .call_method(
+            governance_component,
+            "get_temperature_check",
+            manifest_args!(temperature_check_id),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let commit = receipt.expect_commit_success();
+    let expert_power: Decimal = commit.output(1);
+    let delegator_power: Decimal = commit.output(2);
+    let tc: TemperatureCheckView = commit.output(3);
+    assert_eq!(tc.for_weight, expert_power + delegator_power);
+
+    // The same delegation cannot be cast on a proposal
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (expert_pk, expert_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_proposal_as_delegatee",
+            manifest_args!(expert_account, proposal_id, vec![delegator_account]),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&expert_pk)],
+    );
+    expect_error(receipt, GovernanceError::NoDelegationAtSnapshot);
+}
+
+#[test]
+fn test_vote_delegation_link_requires_handshake() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let delegation_component = instantiate_vote_delegation(&mut ledger, package_address, owner.0);
+    let other_governance = instantiate_governance(&mut ledger, package_address, owner.0);
+
+    // A component of another blueprint is rejected (by the typed argument or the blueprint check)
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "set_vote_delegation",
+        manifest_args!(other_governance),
+    )
+    .expect_commit_failure();
+
+    // The VoteDelegation must have authorized this governance component
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "set_vote_delegation",
+        manifest_args!(delegation_component),
+    );
+    expect_error(receipt, GovernanceError::VoteDelegationNotLinked);
+
+    link_vote_delegation(&mut ledger, governance_component, delegation_component, owner);
+}
+
+#[test]
+fn test_make_proposal_requires_ended_and_passed_temperature_check() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+
+    let temperature_check_id =
+        create_temperature_check(&mut ledger, governance_component, create_temp_check_draft());
+
+    // The voting window is still open
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "make_proposal",
+        manifest_args!(temperature_check_id),
+    );
+    expect_error(receipt, GovernanceError::VotingNotEnded);
+
+    // Without votes the temperature check is rejected
+    advance_time(&mut ledger, 8 * 24 * 60 * 60);
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "make_proposal",
+        manifest_args!(temperature_check_id),
+    );
+    expect_error(receipt, GovernanceError::TemperatureCheckNotPassed);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "finalize_temperature_check",
+            manifest_args!(temperature_check_id),
+        )
+        .build();
+    let status: TemperatureCheckStatus =
+        ledger.execute_manifest(manifest, vec![]).expect_commit_success().output(1);
+    assert_eq!(status, TemperatureCheckStatus::Rejected);
+
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "make_proposal",
+        manifest_args!(temperature_check_id),
+    );
+    expect_error(receipt, GovernanceError::TemperatureCheckNotPassed);
+
+    // The owner can still force it through
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "force_elevate",
+        manifest_args!(temperature_check_id),
+    );
+    let proposal_id: u64 = receipt.expect_commit_success().output(2);
+    assert_eq!(proposal_id, 0);
+
+    // A non-owner cannot force elevate
+    let other_check =
+        create_temperature_check(&mut ledger, governance_component, create_temp_check_draft());
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "force_elevate",
+            manifest_args!(other_check),
+        )
+        .build();
+    ledger.execute_manifest(manifest, vec![]).expect_commit_failure();
+}
+
+#[test]
+fn test_elevation_delay_after_temperature_check() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    let mut params = create_governance_parameters();
+    params.elevation_delay_days = 3;
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_governance_parameters",
+        manifest_args!(params),
+    )
+    .expect_commit_success();
+
+    let temperature_check_id =
+        create_temperature_check(&mut ledger, governance_component, create_temp_check_draft());
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "vote_on_temperature_check",
+            manifest_args!(voter_account, temperature_check_id, TemperatureCheckVote::For),
+        )
+        .build();
+    ledger
+        .execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&voter_pk)])
+        .expect_commit_success();
+
+    advance_time(&mut ledger, 8 * 24 * 60 * 60);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "finalize_temperature_check",
+            manifest_args!(temperature_check_id),
+        )
+        .build();
+    ledger
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+
+    // One day after the deadline the review gap is still running
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "make_proposal",
+        manifest_args!(temperature_check_id),
+    );
+    expect_error(receipt, GovernanceError::ReviewPeriodNotEnded);
+
+    advance_time(&mut ledger, 2 * 24 * 60 * 60);
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "make_proposal",
+        manifest_args!(temperature_check_id),
+    )
+    .expect_commit_success();
+}
+
+#[test]
+fn test_temperature_check_option_preferences() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+    let (binary_pk, _binary_sk, binary_account) = ledger.new_allocated_account();
+    let (against_pk, _against_sk, against_account) = ledger.new_allocated_account();
+
+    let temperature_check_id = create_temperature_check(
+        &mut ledger,
+        governance_component,
+        create_multi_choice_temp_check_draft(),
+    );
+
+    // Selections follow the proposal rules
+    let receipt = vote_on_temperature_check(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        temperature_check_id,
+        TemperatureCheckVote::Options(vec![
+            ProposalVoteOptionId(0),
+            ProposalVoteOptionId(1),
+            ProposalVoteOptionId(2),
+        ]),
+    );
+    expect_error(receipt, GovernanceError::TooManySelections { max: 2 });
+    let receipt = vote_on_temperature_check(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        temperature_check_id,
+        TemperatureCheckVote::Options(vec![ProposalVoteOptionId(5)]),
+    );
+    expect_error(receipt, GovernanceError::InvalidVoteOption);
+
+    vote_on_temperature_check(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        temperature_check_id,
+        TemperatureCheckVote::Options(vec![ProposalVoteOptionId(0), ProposalVoteOptionId(2)]),
+    )
+    .expect_commit_success();
+    vote_on_temperature_check(
+        &mut ledger,
+        governance_component,
+        (binary_pk, binary_account),
+        temperature_check_id,
+        TemperatureCheckVote::For,
+    )
+    .expect_commit_success();
+    vote_on_temperature_check(
+        &mut ledger,
+        governance_component,
+        (against_pk, against_account),
+        temperature_check_id,
+        TemperatureCheckVote::Against,
+    )
+    .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_voting_power", manifest_args!(voter_account))
+        .call_method(
+            governance_component,
+            "get_temperature_check_result",
+            manifest_args!(temperature_check_id),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let commit = receipt.expect_commit_success();
+    let power: Decimal = commit.output(1);
+    let result: TemperatureCheckResult = commit.output(2);
+
+    // Preferences count as For; binary votes carry no preference
+    assert_eq!(result.for_weight, power * 2);
+    assert_eq!(result.against_weight, power);
+    assert_eq!(result.option_weights, vec![power, Decimal::ZERO, power]);
+}
+
+#[test]
+fn test_owner_updates_draft_limits() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_limits", manifest_args!())
+        .build();
+    let limits: GovernanceLimits = ledger
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success()
+        .output(1);
+    assert_eq!(limits, GovernanceLimits::default());
+
+    // Limits above the hard maxima, or more selections than options, are rejected
+    for invalid in [
+        GovernanceLimits { max_links: MAX_LINKS + 1, ..limits },
+        GovernanceLimits { max_vote_options: 0, ..limits },
+        GovernanceLimits { max_vote_options: 2, max_selections: 3, ..limits },
+    ] {
+        let receipt = call_as_owner(
+            &mut ledger,
+            governance_component,
+            owner,
+            "update_limits",
+            manifest_args!(invalid),
+        );
+        expect_error(receipt, GovernanceError::InvalidGovernanceLimits);
+    }
+
+    let tightened = GovernanceLimits {
+        max_links: 1,
+        max_vote_options: 2,
+        max_selections: 2,
+    };
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_limits",
+        manifest_args!(tightened),
+    )
+    .expect_commit_success();
+
+    let receipt = submit_temperature_check(
+        &mut ledger,
+        governance_component,
+        create_multi_choice_temp_check_draft(),
+    );
+    expect_error(receipt, GovernanceError::TooManyVoteOptions { max: 2 });
+    create_temperature_check(&mut ledger, governance_component, create_temp_check_draft());
+}
+
+#[test]
+fn test_temperature_check_royalty_claimed_by_owner() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let (owner_badge, owner_account, owner_pk) = owner;
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "Governance",
+            "instantiate",
+            manifest_args!(
+                owner_badge,
+                create_governance_parameters(),
+                create_branding(),
+                RoyaltyAmount::Xrd(dec!(2))
+            ),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let governance_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+    create_temperature_check(&mut ledger, governance_component, create_temp_check_draft());
+
+    // The royalty can be lowered, but only by the owner
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "set_temperature_check_royalty",
+            manifest_args!(RoyaltyAmount::Free),
+        )
+        .build();
+    ledger.execute_manifest(manifest, vec![]).expect_commit_failure();
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "set_temperature_check_royalty",
+        manifest_args!(RoyaltyAmount::Free),
+    )
+    .expect_commit_success();
+    create_temperature_check(&mut ledger, governance_component, create_temp_check_draft());
+
+    let balance_before = ledger.get_component_balance(owner_account, XRD);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+        .call_method(governance_component, "claim_royalties", manifest_args!())
+        .try_deposit_entire_worktop_or_abort(owner_account, None)
+        .build();
+    ledger
+        .execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&owner_pk)])
+        .expect_commit_success();
+
+    assert_eq!(
+        ledger.get_component_balance(owner_account, XRD),
+        balance_before + dec!(2)
+    );
+}
+
+#[test]
+fn test_co_sponsors_satisfy_sponsor_threshold() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+    let (first_pk, _first_sk, first_sponsor) = ledger.new_allocated_account();
+    let (second_pk, _second_sk, second_sponsor) = ledger.new_allocated_account();
+
+    // Endorsed power alone can never reach the propose threshold
+    let mut params = create_governance_parameters();
+    params.temperature_check_propose_threshold = dec!(1_000_000_000);
+    params.temperature_check_sponsor_threshold = 2;
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_governance_parameters",
+        manifest_args!(params),
+    )
+    .expect_commit_success();
+
+    let temperature_check_id =
+        create_temperature_check(&mut ledger, governance_component, create_temp_check_draft());
+    vote_on_temperature_check(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        temperature_check_id,
+        TemperatureCheckVote::For,
+    )
+    .expect_commit_success();
+
+    let co_sponsor = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+                      (pk, account): (Secp256k1PublicKey, ComponentAddress)| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                governance_component,
+                "co_sponsor",
+                manifest_args!(temperature_check_id, account),
+            )
+            .build();
+        ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&pk)])
+    };
+
+    co_sponsor(&mut ledger, (first_pk, first_sponsor)).expect_commit_success();
+    let receipt = co_sponsor(&mut ledger, (first_pk, first_sponsor));
+    expect_error(receipt, GovernanceError::AlreadySponsored);
+
+    advance_time(&mut ledger, 8 * 24 * 60 * 60);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "finalize_temperature_check",
+            manifest_args!(temperature_check_id),
+        )
+        .build();
+    ledger.execute_manifest(manifest, vec![]).expect_commit_success();
+
+    let elevate = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "elevate_seconded", manifest_args!(temperature_check_id))
+        .build();
+    let receipt = ledger.execute_manifest(elevate.clone(), vec![]);
+    expect_error(receipt, GovernanceError::ProposeThresholdNotReached);
+
+    co_sponsor(&mut ledger, (second_pk, second_sponsor)).expect_commit_success();
+    ledger.execute_manifest(elevate, vec![]).expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_sponsors", manifest_args!(temperature_check_id))
+        .build();
+    let sponsors: Vec<Global<Account>> = ledger
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success()
+        .output(1);
+    assert_eq!(
+        sponsors,
+        vec![Global::<Account>::from(first_sponsor), Global::<Account>::from(second_sponsor)]
+    );
+}
+
+#[test]
+fn test_description_file_requires_trusted_storage() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let storage_component = instantiate_governance(&mut ledger, package_address, owner.0);
+
+    let mut draft = create_temp_check_draft();
+    draft.description_file = Some(DescriptionFile {
+        file: File {
+            kvs_address: "internal_keyvaluestore_sim1".to_string(),
+            component_address: storage_component,
+            file_hash: "abc123".to_string(),
+        },
+        content_hash: hash("content".as_bytes()),
+    });
+
+    let receipt = submit_temperature_check(&mut ledger, governance_component, draft.clone());
+    expect_error(receipt, GovernanceError::UntrustedStorage);
+
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "add_trusted_storage",
+        manifest_args!(storage_component),
+    )
+    .expect_commit_success();
+    create_temperature_check(&mut ledger, governance_component, draft.clone());
+
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "remove_trusted_storage",
+        manifest_args!(storage_component),
+    )
+    .expect_commit_success();
+    let receipt = submit_temperature_check(&mut ledger, governance_component, draft);
+    expect_error(receipt, GovernanceError::UntrustedStorage);
+}
+
+#[test]
+fn test_export_delegation_graph_pages() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let delegation_component = instantiate_vote_delegation(&mut ledger, package_address, owner_badge);
+    let (first_pk, _first_sk, first_account) = ledger.new_allocated_account();
+    let (second_pk, _second_sk, second_account) = ledger.new_allocated_account();
+    let (_delegatee_pk, _delegatee_sk, delegatee_account) = ledger.new_allocated_account();
+    let (_other_pk, _other_sk, other_account) = ledger.new_allocated_account();
+
+    let valid_until = Instant::new(i64::MAX / 2);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "make_delegation",
+            manifest_args!(first_account, delegatee_account, dec!("0.5"), valid_until),
+        )
+        .call_method(
+            delegation_component,
+            "make_delegation",
+            manifest_args!(first_account, other_account, dec!("0.25"), valid_until),
+        )
+        .call_method(
+            delegation_component,
+            "make_delegation",
+            manifest_args!(second_account, delegatee_account, dec!(1), valid_until),
+        )
+        .build();
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![
+                NonFungibleGlobalId::from_public_key(&first_pk),
+                NonFungibleGlobalId::from_public_key(&second_pk),
+            ],
+        )
+        .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(delegation_component, "get_indexed_delegator_count", manifest_args!())
+        .call_method(delegation_component, "export_delegation_graph", manifest_args!(0u64, 1u64))
+        .call_method(delegation_component, "export_delegation_graph", manifest_args!(1u64, 10u64))
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let commit = receipt.expect_commit_success();
+    let count: u64 = commit.output(1);
+    let first_page: Vec<(Global<Account>, Global<Account>, Decimal, Instant)> = commit.output(2);
+    let second_page: Vec<(Global<Account>, Global<Account>, Decimal, Instant)> = commit.output(3);
+
+    // Pages cover delegators in the order they first delegated
+    assert_eq!(count, 2);
+    let first = Global::<Account>::from(first_account);
+    let second = Global::<Account>::from(second_account);
+    let delegatee = Global::<Account>::from(delegatee_account);
+    let other = Global::<Account>::from(other_account);
+    assert_eq!(
+        first_page,
+        vec![
+            (first, delegatee, dec!("0.5"), valid_until),
+            (first, other, dec!("0.25"), valid_until),
+        ]
+    );
+    assert_eq!(second_page, vec![(second, delegatee, dec!(1), valid_until)]);
+}
+
+#[test]
+fn test_vote_rationales() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+    let (other_pk, _other_sk, other_account) = ledger.new_allocated_account();
+
+    let vote_with_rationale = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+                               proposal_id: u64,
+                               (pk, account): (Secp256k1PublicKey, ComponentAddress),
+                               rationale: VoteRationale| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                governance_component,
+                "vote_on_proposal_with_rationale",
+                manifest_args!(account, proposal_id, vec![ProposalVoteOptionId(0)], rationale),
+            )
+            .build();
+        ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&pk)])
+    };
+    let rationale = VoteRationale::Text("Lowers fees for everyone".to_string());
+
+    // Rationales are rejected unless the draft enabled them
+    let disabled_id = create_proposal(&mut ledger, governance_component, owner);
+    let receipt = vote_with_rationale(&mut ledger, disabled_id, (voter_pk, voter_account), rationale.clone());
+    expect_error(receipt, GovernanceError::RationalesDisabled);
+
+    let mut draft = create_temp_check_draft();
+    draft.allow_rationales = true;
+    let temperature_check_id = create_temperature_check(&mut ledger, governance_component, draft);
+    let proposal_id: u64 = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "force_elevate",
+        manifest_args!(temperature_check_id),
+    )
+    .expect_commit_success()
+    .output(2);
+
+    let too_long = VoteRationale::Text("a".repeat(MAX_RATIONALE_LENGTH + 1));
+    let receipt = vote_with_rationale(&mut ledger, proposal_id, (voter_pk, voter_account), too_long);
+    expect_error(receipt, GovernanceError::RationaleTooLong);
+
+    let untrusted_file = VoteRationale::File(File {
+        kvs_address: "internal_keyvaluestore_sim1".to_string(),
+        component_address: governance_component,
+        file_hash: "abc123".to_string(),
+    });
+    let receipt = vote_with_rationale(&mut ledger, proposal_id, (voter_pk, voter_account), untrusted_file);
+    expect_error(receipt, GovernanceError::UntrustedStorage);
+
+    vote_with_rationale(&mut ledger, proposal_id, (voter_pk, voter_account), rationale.clone())
+        .expect_commit_success();
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (other_pk, other_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(1)],
+    )
+    .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "get_vote_rationale",
+            manifest_args!(proposal_id, voter_account),
+        )
+        .call_method(
+            governance_component,
+            "get_vote_rationale",
+            manifest_args!(proposal_id, other_account),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let commit = receipt.expect_commit_success();
+    let voter_rationale: Option<VoteRationale> = commit.output(1);
+    let other_rationale: Option<VoteRationale> = commit.output(2);
+    assert_eq!(voter_rationale, Some(rationale));
+    assert_eq!(other_rationale, None);
+}
+
+#[test]
+fn test_proposal_kinds() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    // Kind-specific invariants are checked at creation
+    let mut draft = create_temp_check_draft();
+    draft.kind = ProposalKind::Funding { amount: Decimal::ZERO, recipient: voter_account };
+    let receipt = submit_temperature_check(&mut ledger, governance_component, draft.clone());
+    expect_error(receipt, GovernanceError::InvalidFundingAmount);
+
+    draft.kind = ProposalKind::Funding { amount: dec!(1000), recipient: governance_component };
+    let receipt = submit_temperature_check(&mut ledger, governance_component, draft.clone());
+    expect_error(receipt, GovernanceError::InvalidFundingRecipient);
+
+    draft.kind = ProposalKind::Funding { amount: dec!(1000), recipient: voter_account };
+    create_temperature_check(&mut ledger, governance_component, draft);
+
+    // Executing a parameter change applies the new parameters
+    let mut parameters = create_governance_parameters();
+    parameters.archive_retention_days = 99;
+    let mut draft = create_temp_check_draft();
+    draft.kind = ProposalKind::ParameterChange { parameters };
+    let temperature_check_id = create_temperature_check(&mut ledger, governance_component, draft);
+    let proposal_id: u64 = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "force_elevate",
+        manifest_args!(temperature_check_id),
+    )
+    .expect_commit_success()
+    .output(2);
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+
+    advance_time(&mut ledger, 15 * 24 * 60 * 60);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "finalize_proposal", manifest_args!(proposal_id))
+        .build();
+    ledger.execute_manifest(manifest, vec![]).expect_commit_success();
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "execute_proposal",
+        manifest_args!(proposal_id, None::<u16>),
+    )
+    .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_governance_parameters", manifest_args!())
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let current: GovernanceParameters = receipt.expect_commit_success().output(1);
+    assert_eq!(current.archive_retention_days, 99);
+}
+
+#[test]
+fn test_vote_on_temperature_check_as_signer() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (account_pk, _account_sk, account) = ledger.new_allocated_account();
+    let (signer_pk, _signer_sk, signer_account) = ledger.new_allocated_account();
+
+    // The account issues a signer badge to the signer
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "issue_signer_badge", manifest_args!(account))
+        .try_deposit_entire_worktop_or_abort(signer_account, None)
+        .call_method(governance_component, "get_signer_badge_resource", manifest_args!())
+        .build();
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&account_pk)],
+    );
+    let signer_badge: ResourceAddress = receipt.expect_commit_success().output(3);
+    let badge_id = ledger
+        .get_component_vaults(signer_account, signer_badge)
+        .first()
+        .and_then(|vault| ledger.inspect_non_fungible_vault(*vault))
+        .and_then(|(_, mut ids)| ids.next())
+        .unwrap();
+
+    let vote_as_signer = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+                          temperature_check_id: u64| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(signer_account, signer_badge, [badge_id.clone()])
+            .pop_from_auth_zone("signer_proof")
+            .call_method_with_name_lookup(
+                governance_component,
+                "vote_on_temperature_check_as_signer",
+                |lookup| (account, temperature_check_id, TemperatureCheckVote::For, lookup.proof("signer_proof")),
+            )
+            .build();
+        ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&signer_pk)])
+    };
+
+    // The signer votes on behalf of the account, and the vote counts as the account's
+    let first_id = create_temperature_check(&mut ledger, governance_component, create_temp_check_draft());
+    vote_as_signer(&mut ledger, first_id).expect_commit_success();
+    let receipt = vote_on_temperature_check(
+        &mut ledger,
+        governance_component,
+        (account_pk, account),
+        first_id,
+        TemperatureCheckVote::Against,
+    );
+    expect_error(receipt, GovernanceError::AlreadyVotedOnTemperatureCheck);
+
+    // The badge cannot vote for another account
+    let (_other_pk, _other_sk, other_account) = ledger.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(signer_account, signer_badge, [badge_id.clone()])
+        .pop_from_auth_zone("signer_proof")
+        .call_method_with_name_lookup(
+            governance_component,
+            "vote_on_temperature_check_as_signer",
+            |lookup| (other_account, first_id, TemperatureCheckVote::For, lookup.proof("signer_proof")),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&signer_pk)]);
+    expect_error(receipt, GovernanceError::NotAuthorizedSigner);
+
+    // Once revoked, the badge is no longer accepted
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "revoke_signer_badge",
+            manifest_args!(account, badge_id.clone()),
+        )
+        .build();
+    ledger
+        .execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&account_pk)])
+        .expect_commit_success();
+
+    let second_id = create_temperature_check(&mut ledger, governance_component, create_temp_check_draft());
+    let receipt = vote_as_signer(&mut ledger, second_id);
+    expect_error(receipt, GovernanceError::NotAuthorizedSigner);
+}
+
+#[test]
+fn test_early_vote_bonus() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (early_pk, _early_sk, early_account) = ledger.new_allocated_account();
+    let (late_pk, _late_sk, late_account) = ledger.new_allocated_account();
+
+    // The multiplier is capped at 1.5x
+    let mut params = create_governance_parameters();
+    params.early_vote_bonus = Some(EarlyVoteBonus {
+        window_fraction: dec!("0.5"),
+        multiplier: dec!(2),
+    });
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_governance_parameters",
+        manifest_args!(params.clone()),
+    );
+    expect_error(receipt, GovernanceError::InvalidEarlyVoteBonus);
+
+    params.early_vote_bonus = Some(EarlyVoteBonus {
+        window_fraction: dec!("0.5"),
+        multiplier: dec!("1.5"),
+    });
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_governance_parameters",
+        manifest_args!(params),
+    )
+    .expect_commit_success();
+
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (early_pk, early_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+
+    // Past half of the 14-day window, votes count at their base weight
+    advance_time(&mut ledger, 8 * 24 * 60 * 60);
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (late_pk, late_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(1)],
+    )
+    .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_proposal_tally", manifest_args!(proposal_id))
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let tally: ProposalTally = receipt.expect_commit_success().output(1);
+
+    // Both accounts hold about the same XRD, minus the fees they paid
+    assert!(tally.options[0].weight > tally.options[1].weight * dec!("1.49"));
+    assert!(tally.options[0].weight < tally.options[1].weight * dec!("1.51"));
+}
+
+#[test]
+fn test_auditor_dumps_votes_after_finalization() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    let mut draft = create_temp_check_draft();
+    draft.members_only = true;
+    let temperature_check_id = create_temperature_check(&mut ledger, governance_component, draft);
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "add_member",
+        manifest_args!(voter_account),
+    )
+    .expect_commit_success();
+    let proposal_id: u64 = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "force_elevate",
+        manifest_args!(temperature_check_id),
+    )
+    .expect_commit_success()
+    .output(2);
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+
+    // Hidden while voting, even from the auditor
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "dump_votes",
+        manifest_args!(proposal_id, 0u64, 10u64),
+    );
+    expect_error(receipt, GovernanceError::ProposalNotFinalized);
+
+    advance_time(&mut ledger, 15 * 24 * 60 * 60);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "finalize_proposal", manifest_args!(proposal_id))
+        .build();
+    ledger.execute_manifest(manifest, vec![]).expect_commit_success();
+
+    // Only the auditor can read the raw records
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "dump_votes", manifest_args!(proposal_id, 0u64, 10u64))
+        .build();
+    ledger.execute_manifest(manifest, vec![]).expect_commit_failure();
+
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "dump_votes",
+        manifest_args!(proposal_id, 0u64, 10u64),
+    );
+    let records: Vec<VoteRecord> = receipt.expect_commit_success().output(2);
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].account, Global::<Account>::from(voter_account));
+    assert_eq!(records[0].votes, vec![ProposalVoteOptionId(0)]);
+    assert_eq!(records[0].early_vote_multiplier, Decimal::ONE);
+    assert_eq!(records[0].rationale, None);
+}
+
+#[test]
+fn test_import_delegations_from_migration_source() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let package_address = ledger.compile_and_publish(this_package!());
+    let old_component = instantiate_vote_delegation(&mut ledger, package_address, owner.0);
+    let new_component = instantiate_vote_delegation(&mut ledger, package_address, owner.0);
+
+    let (delegator_pk, _, delegator_account) = ledger.new_allocated_account();
+    let (_, _, first_delegatee) = ledger.new_allocated_account();
+    let (_, _, second_delegatee) = ledger.new_allocated_account();
+    let valid_until = Instant::new(i64::MAX / 2);
+
+    for (delegatee, fraction) in [(first_delegatee, dec!("0.3")), (second_delegatee, dec!("0.2"))] {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                old_component,
+                "make_delegation",
+                manifest_args!(delegator_account, delegatee, fraction, valid_until),
+            )
+            .build();
+        ledger
+            .execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&delegator_pk)])
+            .expect_commit_success();
+    }
+
+    // Updating one delegation leaves the others and their order untouched
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            old_component,
+            "update_delegation_fraction",
+            manifest_args!(delegator_account, first_delegatee, dec!("0.4")),
+        )
+        .build();
+    ledger
+        .execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&delegator_pk)])
+        .expect_commit_success();
+
+    let get_delegations = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+                           component: ComponentAddress| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(component, "get_delegations", manifest_args!(delegator_account))
+            .build();
+        let delegations: Vec<Delegation> =
+            ledger.execute_manifest(manifest, vec![]).expect_commit_success().output(1);
+        delegations
+            .iter()
+            .map(|d| (d.delegatee, d.fraction))
+            .collect::<Vec<_>>()
+    };
+    let expected = vec![
+        (Global::<Account>::from(first_delegatee), dec!("0.4")),
+        (Global::<Account>::from(second_delegatee), dec!("0.2")),
+    ];
+    assert_eq!(get_delegations(&mut ledger, old_component), expected);
+
+    // Importing requires a migration source
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(new_component, "import_delegations", manifest_args!(delegator_account))
+        .build();
+    expect_error(ledger.execute_manifest(manifest, vec![]), DelegationError::NoMigrationSource);
+
+    call_as_owner(
+        &mut ledger,
+        new_component,
+        owner,
+        "set_migration_source",
+        manifest_args!(Some(old_component)),
+    )
+    .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(new_component, "import_delegations", manifest_args!(delegator_account))
+        .build();
+    ledger.execute_manifest(manifest, vec![]).expect_commit_success();
+    assert_eq!(get_delegations(&mut ledger, new_component), expected);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            new_component,
+            "get_delegatee_delegators",
+            manifest_args!(second_delegatee, delegator_account),
+        )
+        .build();
+    let fraction: Option<Decimal> =
+        ledger.execute_manifest(manifest, vec![]).expect_commit_success().output(1);
+    assert_eq!(fraction, Some(dec!("0.2")));
+
+    // A second import would duplicate the delegations
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(new_component, "import_delegations", manifest_args!(delegator_account))
+        .build();
+    expect_error(
+        ledger.execute_manifest(manifest, vec![]),
+        DelegationError::DelegationsAlreadyStored,
+    );
+
+    // Removing one delegation keeps the other
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            new_component,
+            "remove_delegation",
+            manifest_args!(delegator_account, first_delegatee),
+        )
+        .build();
+    ledger
+        .execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&delegator_pk)])
+        .expect_commit_success();
+    assert_eq!(get_delegations(&mut ledger, new_component), expected[1..].to_vec());
+}
+
+#[test]
+fn test_votes_stored_with_weight_and_time() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    let temperature_check_id =
+        create_temperature_check(&mut ledger, governance_component, create_temp_check_draft());
+    vote_on_temperature_check(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        temperature_check_id,
+        TemperatureCheckVote::For,
+    )
+    .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "get_temperature_check_vote",
+            manifest_args!(temperature_check_id, voter_account),
+        )
+        .build();
+    let record: Option<TemperatureCheckVoteRecord> =
+        ledger.execute_manifest(manifest, vec![]).expect_commit_success().output(1);
+    let record = record.unwrap();
+    assert_eq!(record.vote, TemperatureCheckVote::For);
+    assert!(record.weight > Decimal::ZERO);
+    assert_eq!(record.cast_at, Instant::new(ledger.get_current_proposer_timestamp_ms() / 1000));
+
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+    advance_time(&mut ledger, 60);
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(1)],
+    )
+    .expect_commit_success();
+    let voted_at = Instant::new(ledger.get_current_proposer_timestamp_ms() / 1000);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "get_proposal_vote",
+            manifest_args!(proposal_id, voter_account),
+        )
+        .call_method(governance_component, "get_proposal_tally", manifest_args!(proposal_id))
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let record: Option<ProposalVoteRecord> = receipt.expect_commit_success().output(1);
+    let tally: ProposalTally = receipt.expect_commit_success().output(2);
+    let record = record.unwrap();
+    assert_eq!(record.votes, vec![ProposalVoteOptionId(1)]);
+    assert_eq!(record.weight, tally.options[1].weight);
+    assert_eq!(record.cast_at, voted_at);
+
+    // Accounts that did not vote have no record
+    let (_, _, other_account) = ledger.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "get_proposal_vote",
+            manifest_args!(proposal_id, other_account),
+        )
+        .build();
+    let record: Option<ProposalVoteRecord> =
+        ledger.execute_manifest(manifest, vec![]).expect_commit_success().output(1);
+    assert_eq!(record, None);
+}
+
+#[test]
+fn test_remaining_delegation_capacity() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let package_address = ledger.compile_and_publish(this_package!());
+    let delegation_component = instantiate_vote_delegation(&mut ledger, package_address, owner.0);
+
+    let (delegator_pk, _, delegator_account) = ledger.new_allocated_account();
+    let (_, _, delegatee_account) = ledger.new_allocated_account();
+    let (_, _, proxy_account) = ledger.new_allocated_account();
+    let valid_until = Instant::new(i64::MAX / 2);
+
+    let get_capacity = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                delegation_component,
+                "get_remaining_delegation_capacity",
+                manifest_args!(delegator_account),
+            )
+            .build();
+        let capacity: Decimal =
+            ledger.execute_manifest(manifest, vec![]).expect_commit_success().output(1);
+        capacity
+    };
+    assert_eq!(get_capacity(&mut ledger), Decimal::ONE);
+
+    // A default proxy does not use up capacity
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "make_delegation",
+            manifest_args!(delegator_account, delegatee_account, dec!("0.3"), valid_until),
+        )
+        .call_method(
+            delegation_component,
+            "make_default_proxy",
+            manifest_args!(delegator_account, proxy_account, valid_until),
+        )
+        .build();
+    ledger
+        .execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&delegator_pk)])
+        .expect_commit_success();
+    assert_eq!(get_capacity(&mut ledger), dec!("0.7"));
+}
+
+#[test]
+fn test_delegatee_limits() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let package_address = ledger.compile_and_publish(this_package!());
+    let delegation_component = instantiate_vote_delegation(&mut ledger, package_address, owner.0);
+
+    let (delegatee_pk, _, delegatee_account) = ledger.new_allocated_account();
+    let (first_pk, _, first_delegator) = ledger.new_allocated_account();
+    let (second_pk, _, second_delegator) = ledger.new_allocated_account();
+    let valid_until = Instant::new(i64::MAX / 2);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "set_max_incoming_delegators",
+            manifest_args!(delegatee_account, Some(1u64)),
+        )
+        .call_method(
+            delegation_component,
+            "set_max_incoming_per_delegator",
+            manifest_args!(delegatee_account, Some(dec!("0.5"))),
+        )
+        .build();
+    ledger
+        .execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&delegatee_pk)])
+        .expect_commit_success();
+
+    let delegate = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+                    (delegator_pk, delegator): (Secp256k1PublicKey, ComponentAddress),
+                    fraction: Decimal| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                delegation_component,
+                "make_delegation",
+                manifest_args!(delegator, delegatee_account, fraction, valid_until),
+            )
+            .build();
+        ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&delegator_pk)])
+    };
+
+    let receipt = delegate(&mut ledger, (first_pk, first_delegator), dec!("0.6"));
+    expect_error(receipt, DelegationError::DelegateeFractionLimitExceeded);
+    delegate(&mut ledger, (first_pk, first_delegator), dec!("0.5")).expect_commit_success();
+
+    // Replacing an existing delegation does not count as a new delegator
+    delegate(&mut ledger, (first_pk, first_delegator), dec!("0.4")).expect_commit_success();
+    let receipt = delegate(&mut ledger, (second_pk, second_delegator), dec!("0.1"));
+    expect_error(receipt, DelegationError::DelegateeAtCapacity);
+
+    // Limits can only be changed by the delegatee
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "set_max_incoming_delegators",
+            manifest_args!(delegatee_account, None::<u64>),
+        )
+        .build();
+    ledger
+        .execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&second_pk)])
+        .expect_commit_failure();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "set_max_incoming_delegators",
+            manifest_args!(delegatee_account, None::<u64>),
+        )
+        .build();
+    ledger
+        .execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&delegatee_pk)])
+        .expect_commit_success();
+    delegate(&mut ledger, (second_pk, second_delegator), dec!("0.1")).expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(delegation_component, "get_delegatee_limits", manifest_args!(delegatee_account))
+        .build();
+    let limits: DelegateeLimits =
+        ledger.execute_manifest(manifest, vec![]).expect_commit_success().output(1);
+    assert_eq!(limits.max_delegators, None);
+    assert_eq!(limits.max_fraction_per_delegator, Some(dec!("0.5")));
+}
+
+#[test]
+fn test_revoke_all_incoming() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let package_address = ledger.compile_and_publish(this_package!());
+    let delegation_component = instantiate_vote_delegation(&mut ledger, package_address, owner.0);
+
+    let (_, _, delegatee_account) = ledger.new_allocated_account();
+    let (first_pk, _, first_delegator) = ledger.new_allocated_account();
+    let (second_pk, _, second_delegator) = ledger.new_allocated_account();
+    let valid_until = Instant::new(i64::MAX / 2);
+
+    for (delegator_pk, delegator) in [(first_pk, first_delegator), (second_pk, second_delegator)] {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                delegation_component,
+                "make_delegation",
+                manifest_args!(delegator, delegatee_account, dec!("0.5"), valid_until),
+            )
+            .build();
+        ledger
+            .execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&delegator_pk)])
+            .expect_commit_success();
+    }
+
+    // Only the owner (or governance) can revoke
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "revoke_all_incoming",
+            manifest_args!(delegatee_account, vec![first_delegator, second_delegator], "Compromised"),
+        )
+        .build();
+    ledger.execute_manifest(manifest, vec![]).expect_commit_failure();
+
+    // Every delegator must be listed
+    let receipt = call_as_owner(
+        &mut ledger,
+        delegation_component,
+        owner,
+        "revoke_all_incoming",
+        manifest_args!(delegatee_account, vec![first_delegator], "Compromised"),
+    );
+    expect_error(receipt, DelegationError::IncompleteRevocation);
+
+    call_as_owner(
+        &mut ledger,
+        delegation_component,
+        owner,
+        "revoke_all_incoming",
+        manifest_args!(delegatee_account, vec![first_delegator, second_delegator], "Compromised"),
+    )
+    .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "get_incoming_delegation_count",
+            manifest_args!(delegatee_account),
+        )
+        .call_method(delegation_component, "get_delegations", manifest_args!(first_delegator))
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let incoming: u64 = receipt.expect_commit_success().output(1);
+    let delegations: Vec<Delegation> = receipt.expect_commit_success().output(2);
+    assert_eq!(incoming, 0);
+    assert!(delegations.is_empty());
+}
+
+#[test]
+fn test_deadline_rounding() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+
+    let mut params = create_governance_parameters();
+    params.deadline_rounding = DeadlineRounding::Day;
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_governance_parameters",
+        manifest_args!(params),
+    )
+    .expect_commit_success();
+
+    // Move off a day boundary
+    advance_time(&mut ledger, 5 * 60 * 60 + 17);
+    let temperature_check_id =
+        create_temperature_check(&mut ledger, governance_component, create_temp_check_draft());
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "get_temperature_check_time_remaining",
+            manifest_args!(temperature_check_id),
+        )
+        .build();
+    let remaining: i64 = ledger.execute_manifest(manifest, vec![]).expect_commit_success().output(1);
+    let now = ledger.get_current_proposer_timestamp_ms() / 1000;
+    let day = 24 * 60 * 60;
+    assert_eq!((now + remaining) % day, 0);
+    assert!(remaining >= 7 * day && remaining < 8 * day);
+
+    // Past the deadline nothing remains
+    advance_time(&mut ledger, 8 * day);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "get_temperature_check_time_remaining",
+            manifest_args!(temperature_check_id),
+        )
+        .build();
+    let remaining: i64 = ledger.execute_manifest(manifest, vec![]).expect_commit_success().output(1);
+    assert_eq!(remaining, 0);
+}
+
+#[test]
+fn test_multi_question_ballot() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    let mut draft = create_temp_check_draft();
+    draft.extra_questions = vec![BallotQuestionInput {
+        label: "Which fee model?".to_string(),
+        vote_options: vec![
+            ProposalVoteOptionInput {
+                label: "Flat".to_string(),
+                color: VoteOptionColor::Blue,
+            },
+            ProposalVoteOptionInput {
+                label: "Tiered".to_string(),
+                color: VoteOptionColor::Green,
+            },
+        ],
+        max_selections: None,
+        approval_threshold: dec!("0.5"),
+    }];
+    let temperature_check_id = create_temperature_check(&mut ledger, governance_component, draft);
+    let proposal_id: u64 = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "force_elevate",
+        manifest_args!(temperature_check_id),
+    )
+    .expect_commit_success()
+    .output(2);
+
+    let ballot = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+                  answers: Vec<(u32, Vec<ProposalVoteOptionId>)>| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                governance_component,
+                "vote_on_ballot",
+                manifest_args!(voter_account, proposal_id, answers),
+            )
+            .build();
+        ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&voter_pk)])
+    };
+
+    // The main question must be answered, and only known questions can be
+    expect_error(
+        ballot(&mut ledger, vec![(1, vec![ProposalVoteOptionId(1)])]),
+        GovernanceError::MainQuestionUnanswered,
+    );
+    expect_error(
+        ballot(
+            &mut ledger,
+            vec![(0, vec![ProposalVoteOptionId(0)]), (2, vec![ProposalVoteOptionId(0)])],
+        ),
+        GovernanceError::QuestionNotFound,
+    );
+    ballot(
+        &mut ledger,
+        vec![(0, vec![ProposalVoteOptionId(0)]), (1, vec![ProposalVoteOptionId(1)])],
+    )
+    .expect_commit_success();
+
+    advance_time(&mut ledger, 15 * 24 * 60 * 60);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "finalize_proposal", manifest_args!(proposal_id))
+        .call_method(governance_component, "get_proposal", manifest_args!(proposal_id))
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let proposal: ProposalView = receipt.expect_commit_success().output(2);
+    assert_eq!(proposal.questions.len(), 1);
+    assert_eq!(proposal.questions[0].status, ProposalStatus::Passed);
+    assert_eq!(proposal.questions[0].winning_options, vec![ProposalVoteOptionId(1)]);
+}
+
+#[test]
+fn test_points_allocation_voting() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    let mut draft = create_multi_choice_temp_check_draft();
+    draft.voting_mode = VotingMode::PointsAllocation;
+    let temperature_check_id = create_temperature_check(&mut ledger, governance_component, draft);
+    let proposal_id: u64 = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "force_elevate",
+        manifest_args!(temperature_check_id),
+    )
+    .expect_commit_success()
+    .output(2);
+
+    let vote_with_points = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+                            points: Vec<(ProposalVoteOptionId, u32)>| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                governance_component,
+                "vote_on_proposal_with_points",
+                manifest_args!(voter_account, proposal_id, points),
+            )
+            .build();
+        ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&voter_pk)])
+    };
+
+    // Selection votes and allocations not summing to 100 are rejected
+    expect_error(
+        vote_on_proposal(
+            &mut ledger,
+            governance_component,
+            (voter_pk, voter_account),
+            proposal_id,
+            vec![ProposalVoteOptionId(0)],
+        ),
+        GovernanceError::WrongVotingMode,
+    );
+    expect_error(
+        vote_with_points(&mut ledger, vec![(ProposalVoteOptionId(0), 60), (ProposalVoteOptionId(1), 30)]),
+        GovernanceError::InvalidPointsAllocation,
+    );
+    vote_with_points(
+        &mut ledger,
+        vec![
+            (ProposalVoteOptionId(0), 50),
+            (ProposalVoteOptionId(1), 30),
+            (ProposalVoteOptionId(2), 20),
+        ],
+    )
+    .expect_commit_success();
+
+    advance_time(&mut ledger, 15 * 24 * 60 * 60);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "finalize_proposal", manifest_args!(proposal_id))
+        .call_method(governance_component, "get_proposal", manifest_args!(proposal_id))
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let proposal: ProposalView = receipt.expect_commit_success().output(2);
+    assert_eq!(proposal.allocation, vec![dec!("0.5"), dec!("0.3"), dec!("0.2")]);
+}
+
+#[test]
+fn test_delegate_notice_period() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let delegation_component = instantiate_vote_delegation(&mut ledger, package_address, owner.0);
+    link_vote_delegation(&mut ledger, governance_component, delegation_component, owner);
+
+    let mut params = create_governance_parameters();
+    params.delegate_notice_hours = 24;
+    params.vote_change_policy = VoteChangePolicy::UntilDeadline;
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_governance_parameters",
+        manifest_args!(params),
+    )
+    .expect_commit_success();
+
+    let (delegator_pk, _delegator_sk, delegator_account) = ledger.new_allocated_account();
+    let (delegatee_pk, _delegatee_sk, delegatee_account) = ledger.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "make_delegation",
+            manifest_args!(delegator_account, delegatee_account, dec!("1"), Instant::new(i64::MAX / 2)),
+        )
+        .build();
+    ledger
+        .execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&delegator_pk)])
+        .expect_commit_success();
+    advance_time(&mut ledger, 1);
+
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (delegatee_pk, delegatee_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+
+    let cast_delegated = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                governance_component,
+                "vote_on_proposal_as_delegatee",
+                manifest_args!(delegatee_account, proposal_id, vec![delegator_account]),
+            )
+            .build();
+        ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&delegatee_pk)])
+    };
+
+    // Delegated power cannot be cast without an announcement, nor within the notice period
+    expect_error(cast_delegated(&mut ledger), GovernanceError::IntendedVoteNotAnnounced);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "announce_intended_vote",
+            manifest_args!(
+                delegatee_account,
+                proposal_id,
+                vec![ProposalVoteOptionId(0)],
+                None::<File>
+            ),
+        )
+        .build();
+    ledger
+        .execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&delegatee_pk)])
+        .expect_commit_success();
+    expect_error(cast_delegated(&mut ledger), GovernanceError::DelegateNoticePeriodNotElapsed);
+
+    advance_time(&mut ledger, 24 * 60 * 60);
+    cast_delegated(&mut ledger).expect_commit_success();
+
+    // The delegatee cannot move the delegated power away from the announced selection
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "change_vote",
+            manifest_args!(delegatee_account, proposal_id, vec![ProposalVoteOptionId(1)]),
+        )
+        .build();
+    expect_error(
+        ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&delegatee_pk)]),
+        GovernanceError::VoteCarriesDelegatedPower,
+    );
+}
+
+#[test]
+fn test_min_balance_to_vote() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    // The draft override applies above the voter's 10,000 XRD
+    let mut draft = create_temp_check_draft();
+    draft.min_balance_to_vote = Some(dec!(20000));
+    let temperature_check_id = create_temperature_check(&mut ledger, governance_component, draft);
+    let proposal_id: u64 = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "force_elevate",
+        manifest_args!(temperature_check_id),
+    )
+    .expect_commit_success()
+    .output(2);
+
+    expect_error(
+        vote_on_proposal(
+            &mut ledger,
+            governance_component,
+            (voter_pk, voter_account),
+            proposal_id,
+            vec![ProposalVoteOptionId(0)],
+        ),
+        GovernanceError::BalanceBelowMinimum,
+    );
+
+    // Without an override, proposals use the parameter
+    let mut params = create_governance_parameters();
+    params.min_balance_to_vote = Some(dec!(100));
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_governance_parameters",
+        manifest_args!(params),
+    )
+    .expect_commit_success();
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+}
+
+#[test]
+fn test_average_balance_voting_power() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(package_address, "BalanceCheckpoints", "instantiate", manifest_args!())
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let checkpoints_component = receipt.expect_commit(true).new_component_addresses()[0];
+
+    let config = VotingPowerConfig {
+        resource_weights: vec![(XRD, Decimal::ONE)],
+        mode: VotingPowerMode::AverageBalance {
+            balance_history: checkpoints_component,
+            days: 2,
+        },
+    };
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_voting_power_config",
+        manifest_args!(config),
+    )
+    .expect_commit_success();
+
+    // The voter's balance is only recorded one day before the temperature check opens,
+    // so it counts for half of the two-day window
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(checkpoints_component, "checkpoint", manifest_args!(voter_account, XRD))
+        .build();
+    ledger.execute_manifest(manifest, vec![]).expect_commit_success();
+    let balance = ledger.get_component_balance(voter_account, XRD);
+    advance_time(&mut ledger, 24 * 60 * 60);
+
+    let temperature_check_id =
+        create_temperature_check(&mut ledger, governance_component, create_temp_check_draft());
+    vote_on_temperature_check(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        temperature_check_id,
+        TemperatureCheckVote::For,
+    )
+    .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "get_temperature_check",
+            manifest_args!(temperature_check_id),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let temperature_check: TemperatureCheckView = receipt.expect_commit_success().output(1);
+    assert_eq!(temperature_check.for_weight, balance * dec!("0.5"));
+}
+
+#[test]
+fn test_balance_checkpoints_survive_flooding() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let (account_pk, _account_sk, account) = ledger.new_allocated_account();
+    let (other_pk, _other_sk, other_account) = ledger.new_allocated_account();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(package_address, "BalanceCheckpoints", "instantiate", manifest_args!())
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let checkpoints_component = receipt.expect_commit(true).new_component_addresses()[0];
+
+    let checkpoint = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(checkpoints_component, "checkpoint", manifest_args!(account, XRD))
+            .build();
+        ledger.execute_manifest(manifest, vec![]).expect_commit_success();
+    };
+    let now = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>| {
+        Instant::new(ledger.get_current_proposer_timestamp_ms() / 1000)
+    };
+    let average = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+                   from: Instant,
+                   to: Instant| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                checkpoints_component,
+                "get_average_balance",
+                manifest_args!(account, XRD, from, to),
+            )
+            .build();
+        let average: Decimal = ledger
+            .execute_manifest(manifest, vec![])
+            .expect_commit_success()
+            .output(1);
+        average
+    };
+
+    let balance = ledger.get_component_balance(account, XRD);
+    let first_recorded = now(&mut ledger);
+    checkpoint(&mut ledger);
+    advance_time(&mut ledger, 24 * 60 * 60);
+
+    // Anyone recording more than MAX_BALANCE_CHECKPOINTS checkpoints in a row only folds the
+    // oldest ones together, the balance since the first checkpoint still counts
+    for _ in 0..=MAX_BALANCE_CHECKPOINTS {
+        advance_time(&mut ledger, 1);
+        checkpoint(&mut ledger);
+    }
+    let checkpoints_manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(checkpoints_component, "get_checkpoints", manifest_args!(account, XRD))
+        .build();
+    let checkpoints: Vec<BalanceCheckpoint> = ledger
+        .execute_manifest(checkpoints_manifest, vec![])
+        .expect_commit_success()
+        .output(1);
+    assert_eq!(checkpoints.len(), MAX_BALANCE_CHECKPOINTS);
+    assert_eq!(checkpoints[0].recorded_at, first_recorded);
+    let to = now(&mut ledger);
+    assert_eq!(average(&mut ledger, first_recorded, to), balance);
+
+    // Without a later checkpoint, the last recorded balance only counts up to the current one
+    let last_recorded = now(&mut ledger);
+    advance_time(&mut ledger, 60);
+    let window_end = now(&mut ledger);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, dec!(9000))
+        .try_deposit_entire_worktop_or_abort(other_account, None)
+        .build();
+    ledger
+        .execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&account_pk)])
+        .expect_commit_success();
+    assert_eq!(average(&mut ledger, last_recorded, window_end), balance - dec!(9000));
+
+    // Once the window has ended, the current balance no longer counts for it: a balance
+    // borrowed for the lookup cannot stand in for the stretch nobody recorded
+    advance_time(&mut ledger, 60);
+    let restore = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(other_account, XRD, dec!(9000))
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    ledger
+        .execute_manifest(restore, vec![NonFungibleGlobalId::from_public_key(&other_pk)])
+        .expect_commit_success();
+    assert_eq!(average(&mut ledger, last_recorded, window_end), Decimal::ZERO);
+
+    // A keeper checkpoint after the window closes it at the lowest balance recorded since, so
+    // checkpointing a borrowed balance later does not raise it
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, dec!(9000))
+        .try_deposit_entire_worktop_or_abort(other_account, None)
+        .build();
+    ledger
+        .execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&account_pk)])
+        .expect_commit_success();
+    checkpoint(&mut ledger);
+    advance_time(&mut ledger, 1);
+    let restore = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(other_account, XRD, dec!(9000))
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    ledger
+        .execute_manifest(restore, vec![NonFungibleGlobalId::from_public_key(&other_pk)])
+        .expect_commit_success();
+    checkpoint(&mut ledger);
+    assert_eq!(average(&mut ledger, last_recorded, window_end), balance - dec!(9000));
+}
+
+#[test]
+fn test_survey_responses() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    let mut draft = create_temp_check_draft();
+    draft.kind = ProposalKind::Survey;
+    let temperature_check_id = create_temperature_check(&mut ledger, governance_component, draft);
+    let proposal_id: u64 = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "force_elevate",
+        manifest_args!(temperature_check_id),
+    )
+    .expect_commit_success()
+    .output(2);
+
+    let respond = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                governance_component,
+                "respond_to_survey",
+                manifest_args!(
+                    voter_account,
+                    proposal_id,
+                    SurveyResponse::Text("Please extend the RFC period".to_string())
+                ),
+            )
+            .build();
+        ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&voter_pk)])
+    };
+    respond(&mut ledger).expect_commit_success();
+    expect_error(respond(&mut ledger), GovernanceError::AlreadyResponded);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "get_survey_responses",
+            manifest_args!(proposal_id, 0u64, 10u64),
+        )
+        .build();
+    expect_error(
+        ledger.execute_manifest(manifest.clone(), vec![]),
+        GovernanceError::SurveyResponsesHidden,
+    );
+
+    advance_time(&mut ledger, 15 * 24 * 60 * 60);
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let responses: Vec<SurveyResponseRecord> = receipt.expect_commit_success().output(1);
+    assert_eq!(responses.len(), 1);
+    assert_eq!(responses[0].account, Global::<Account>::from(voter_account));
+    assert_eq!(
+        responses[0].response,
+        SurveyResponse::Text("Please extend the RFC period".to_string())
+    );
+}
+
+#[test]
+fn test_proposal_reactions() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+
+    let react = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>, reaction: u8| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                governance_component,
+                "react",
+                manifest_args!(voter_account, proposal_id, reaction),
+            )
+            .build();
+        ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&voter_pk)])
+    };
+
+    expect_error(react(&mut ledger, REACTION_KINDS), GovernanceError::InvalidReaction);
+    react(&mut ledger, 1).expect_commit_success();
+    expect_error(react(&mut ledger, 1), GovernanceError::AlreadyReacted);
+    // Reacting again moves the account's count
+    react(&mut ledger, 3).expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_reaction_counts", manifest_args!(proposal_id))
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let counts: Vec<u64> = receipt.expect_commit_success().output(1);
+    assert_eq!(counts, vec![0, 0, 0, 1, 0, 0, 0, 0]);
+}
+
+#[test]
+fn test_account_age_config() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    let config = AccountAgeConfig {
+        oracle: governance_component,
+        enforced: false,
+    };
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "set_account_age_config",
+        manifest_args!(Some(config.clone())),
+    )
+    .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_account_age_config", manifest_args!())
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let stored: Option<AccountAgeConfig> = receipt.expect_commit_success().output(1);
+    assert_eq!(stored, Some(config));
+
+    // While the check is paused the oracle is not consulted
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+
+    assert_eq!(GovernanceError::AccountTooNew.code(), "GOV-147");
+}
+
+#[test]
+fn test_vote_on_proposal_as_signer() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (account_pk, _account_sk, account) = ledger.new_allocated_account();
+    let (signer_pk, _signer_sk, signer_account) = ledger.new_allocated_account();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "issue_signer_badge", manifest_args!(account))
+        .try_deposit_entire_worktop_or_abort(signer_account, None)
+        .call_method(governance_component, "get_signer_badge_resource", manifest_args!())
+        .build();
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&account_pk)],
+    );
+    let signer_badge: ResourceAddress = receipt.expect_commit_success().output(3);
+    let badge_id = ledger
+        .get_component_vaults(signer_account, signer_badge)
+        .first()
+        .and_then(|vault| ledger.inspect_non_fungible_vault(*vault))
+        .and_then(|(_, mut ids)| ids.next())
+        .unwrap();
+
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+    let vote_as_signer = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+                          voter: ComponentAddress| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(signer_account, signer_badge, [badge_id.clone()])
+            .pop_from_auth_zone("signer_proof")
+            .call_method_with_name_lookup(
+                governance_component,
+                "vote_on_proposal_as_signer",
+                |lookup| (voter, proposal_id, vec![ProposalVoteOptionId(0)], lookup.proof("signer_proof")),
+            )
+            .build();
+        ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&signer_pk)])
+    };
+
+    // The badge cannot vote for another account
+    let (_other_pk, _other_sk, other_account) = ledger.new_allocated_account();
+    expect_error(vote_as_signer(&mut ledger, other_account), GovernanceError::NotAuthorizedSigner);
+
+    // The signer votes without the account's key, and the vote counts as the account's
+    vote_as_signer(&mut ledger, account).expect_commit_success();
+    let receipt = vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (account_pk, account),
+        proposal_id,
+        vec![ProposalVoteOptionId(1)],
+    );
+    expect_error(receipt, GovernanceError::AlreadyVotedOnProposal);
+}
+
+#[test]
+fn test_make_delegation_signed() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let (owner_badge, _owner_account, _owner_pk) = create_owner_badge_with_account(&mut ledger);
+    let delegation_component = instantiate_vote_delegation(&mut ledger, package_address, owner_badge);
+    let (_delegator_pk, delegator_sk, delegator_account) = ledger.new_allocated_account();
+    let (_delegatee_pk, _delegatee_sk, delegatee_account) = ledger.new_allocated_account();
+    let (_other_pk, other_sk, _other_account) = ledger.new_allocated_account();
+
+    let authorization = DelegationAuthorization {
+        component: delegation_component,
+        delegator: delegator_account,
+        delegatee: delegatee_account,
+        fraction: dec!("0.5"),
+        valid_until: Instant::new(i64::MAX / 2),
+        nonce: 0,
+    };
+    let message_hash = delegation_authorization_hash(&authorization);
+    let sign_with = |sk: &Secp256k1PrivateKey| DelegationSignature::Secp256k1 {
+        public_key: sk.public_key(),
+        signature: sk.sign(&message_hash),
+    };
+
+    // The relayer submits without any signature of the delegator on the transaction
+    let submit = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+                  signature: DelegationSignature| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                delegation_component,
+                "make_delegation_signed",
+                manifest_args!(authorization.clone(), signature),
+            )
+            .build();
+        ledger.execute_manifest(manifest, vec![])
+    };
+
+    // A key the account is not owned by is rejected
+    expect_error(
+        submit(&mut ledger, sign_with(&other_sk)),
+        DelegationError::InvalidDelegationSignature,
+    );
+
+    submit(&mut ledger, sign_with(&delegator_sk)).expect_commit_success();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(delegation_component, "get_delegations", manifest_args!(delegator_account))
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let delegations: Vec<Delegation> = receipt.expect_commit_success().output(1);
+    assert_eq!(delegations.len(), 1);
+    assert_eq!(delegations[0].fraction, dec!("0.5"));
+
+    // The nonce is consumed, so the signature cannot be replayed
+    expect_error(
+        submit(&mut ledger, sign_with(&delegator_sk)),
+        DelegationError::InvalidNonce,
+    );
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(delegation_component, "get_nonce", manifest_args!(delegator_account))
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let next_nonce: u64 = receipt.expect_commit_success().output(1);
+    assert_eq!(next_nonce, 1);
+}
+
+#[test]
+fn test_temperature_check_tally() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+    let temperature_check_id =
+        create_temperature_check(&mut ledger, governance_component, create_temp_check_draft());
+
+    let get_tally = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                governance_component,
+                "get_temperature_check_tally",
+                manifest_args!(temperature_check_id),
+            )
+            .build();
+        let receipt = ledger.execute_manifest(manifest, vec![]);
+        let tally: TemperatureCheckTally = receipt.expect_commit_success().output(1);
+        tally
+    };
+
+    vote_on_temperature_check(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        temperature_check_id,
+        TemperatureCheckVote::For,
+    )
+    .expect_commit_success();
+
+    // The vote shows up live, before the deadline
+    let tally = get_tally(&mut ledger);
+    assert_eq!(tally.for_weight, ledger.get_component_balance(voter_account, XRD));
+    assert_eq!(tally.against_weight, Decimal::ZERO);
+    assert!(tally.quorum_met && tally.threshold_met);
+    assert!(!tally.voting_ended && !tally.finalized);
+
+    // After the deadline the tally is settled but still provisional until finalized
+    advance_time(&mut ledger, 8 * 24 * 60 * 60);
+    let tally = get_tally(&mut ledger);
+    assert!(tally.voting_ended && !tally.finalized);
+}
+
+#[test]
+fn test_change_vote_until_quorum() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (first_pk, _first_sk, first_account) = ledger.new_allocated_account();
+    let (second_pk, _second_sk, second_account) = ledger.new_allocated_account();
+
+    let mut params = create_governance_parameters();
+    params.vote_change_policy = VoteChangePolicy::UntilQuorum;
+    params.proposal_quorum = dec!(15000);
+    params.binding_quorum = dec!(30000);
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_governance_parameters",
+        manifest_args!(params),
+    )
+    .expect_commit_success();
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+
+    let change_vote = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+                       voter: (Secp256k1PublicKey, ComponentAddress),
+                       option: u32| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                governance_component,
+                "change_vote",
+                manifest_args!(voter.1, proposal_id, vec![ProposalVoteOptionId(option)]),
+            )
+            .build();
+        ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&voter.0)])
+    };
+
+    expect_error(
+        change_vote(&mut ledger, (first_pk, first_account), 1),
+        GovernanceError::VoteNotFound,
+    );
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (first_pk, first_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+
+    // Below the quorum the vote can still move
+    change_vote(&mut ledger, (first_pk, first_account), 1).expect_commit_success();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_proposal_tally", manifest_args!(proposal_id))
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let tally: ProposalTally = receipt.expect_commit_success().output(1);
+    assert_eq!(tally.options[0].weight, Decimal::ZERO);
+    assert_eq!(tally.options[1].weight, ledger.get_component_balance(first_account, XRD));
+
+    // Once the live tally reaches the quorum, votes are final
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (second_pk, second_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+    expect_error(
+        change_vote(&mut ledger, (first_pk, first_account), 0),
+        GovernanceError::VoteChangeAfterQuorum,
+    );
+}
+
+#[test]
+fn test_failed_challenge_bonds_slashed_to_winning_voters() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+    let (challenger_pk, _challenger_sk, challenger_account) = ledger.new_allocated_account();
+
+    // A threshold the single challenger cannot reach
+    let mut params = create_governance_parameters();
+    params.challenge_period_days = 3;
+    params.challenge_threshold = dec!("0.9");
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_governance_parameters",
+        manifest_args!(params),
+    )
+    .expect_commit_success();
+
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+    advance_time(&mut ledger, 15 * 24 * 60 * 60);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "finalize_proposal", manifest_args!(proposal_id))
+        .build();
+    ledger.execute_manifest(manifest, vec![]).expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(challenger_account, XRD, dec!(100))
+        .take_all_from_worktop(XRD, "bond")
+        .call_method_with_name_lookup(governance_component, "challenge_result", |lookup| {
+            (challenger_account, proposal_id, lookup.bucket("bond"))
+        })
+        .deposit_batch(challenger_account)
+        .build();
+    ledger
+        .execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&challenger_pk)])
+        .expect_commit_success();
+
+    let claim = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+                 account: (Secp256k1PublicKey, ComponentAddress)| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                governance_component,
+                "claim_dispute_reward",
+                manifest_args!(proposal_id, account.1),
+            )
+            .deposit_batch(account.1)
+            .build();
+        ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&account.0)])
+    };
+
+    expect_error(claim(&mut ledger, (voter_pk, voter_account)), GovernanceError::ChallengeWindowOpen);
+    advance_time(&mut ledger, 3 * 24 * 60 * 60);
+
+    // The challenge failed: the bond is slashed instead of returned
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "reclaim_challenge_bond",
+            manifest_args!(challenger_account, proposal_id),
+        )
+        .deposit_batch(challenger_account)
+        .build();
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&challenger_pk)],
+    );
+    expect_error(receipt, GovernanceError::ChallengeBondSlashed);
+
+    // The only winning voter gets the whole bond, once
+    let balance_before = ledger.get_component_balance(voter_account, XRD);
+    claim(&mut ledger, (voter_pk, voter_account)).expect_commit_success();
+    assert_eq!(ledger.get_component_balance(voter_account, XRD), balance_before + dec!(100));
+    expect_error(
+        claim(&mut ledger, (voter_pk, voter_account)),
+        GovernanceError::DisputeRewardAlreadyClaimed,
+    );
+    expect_error(
+        claim(&mut ledger, (challenger_pk, challenger_account)),
+        GovernanceError::NoDisputeReward,
+    );
+}
+
+#[test]
+fn test_unclaimed_dispute_rewards_swept() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+    let (challenger_pk, _challenger_sk, challenger_account) = ledger.new_allocated_account();
+
+    let mut params = create_governance_parameters();
+    params.challenge_period_days = 3;
+    params.challenge_threshold = dec!("0.9");
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_governance_parameters",
+        manifest_args!(params),
+    )
+    .expect_commit_success();
+
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+    advance_time(&mut ledger, 15 * 24 * 60 * 60);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "finalize_proposal", manifest_args!(proposal_id))
+        .build();
+    ledger.execute_manifest(manifest, vec![]).expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(challenger_account, XRD, dec!(100))
+        .take_all_from_worktop(XRD, "bond")
+        .call_method_with_name_lookup(governance_component, "challenge_result", |lookup| {
+            (challenger_account, proposal_id, lookup.bucket("bond"))
+        })
+        .deposit_batch(challenger_account)
+        .build();
+    ledger
+        .execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&challenger_pk)])
+        .expect_commit_success();
+
+    let (owner_badge, owner_account, owner_pk) = owner;
+    let sweep = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(owner_account, owner_badge, dec!(1))
+            .call_method(governance_component, "sweep_dispute_rewards", manifest_args!(proposal_id))
+            .deposit_batch(owner_account)
+            .build();
+        ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&owner_pk)])
+    };
+    let archive = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>| {
+        call_as_owner(
+            ledger,
+            governance_component,
+            owner,
+            "archive_proposal",
+            manifest_args!(proposal_id),
+        )
+    };
+
+    // The winning voter never claims: neither sweeping nor archiving cuts the claim period short
+    advance_time(&mut ledger, 31 * 24 * 60 * 60);
+    expect_error(sweep(&mut ledger), GovernanceError::DisputeRewardClaimPeriodOpen);
+    expect_error(archive(&mut ledger), GovernanceError::DisputeRewardClaimPeriodOpen);
+
+    // Afterwards the bond is slashed by archiving instead of blocking it, and swept once
+    advance_time(&mut ledger, 3 * 24 * 60 * 60);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            governance_component,
+            "claim_dispute_reward",
+            manifest_args!(proposal_id, voter_account),
+        )
+        .deposit_batch(voter_account)
+        .build();
+    let receipt =
+        ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&voter_pk)]);
+    expect_error(receipt, GovernanceError::DisputeRewardClaimPeriodEnded);
+    archive(&mut ledger).expect_commit_success();
+
+    let balance_before = ledger.get_component_balance(owner_account, XRD);
+    sweep(&mut ledger).expect_commit_success();
+    assert_eq!(ledger.get_component_balance(owner_account, XRD), balance_before + dec!(100));
+    expect_error(sweep(&mut ledger), GovernanceError::NoDisputeReward);
+}
+
+#[test]
+fn test_external_ref_lookup() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+
+    let mut draft = create_temp_check_draft();
+    draft.external_ref = Some("GP-2025-07".to_string());
+    let temperature_check_id =
+        create_temperature_check(&mut ledger, governance_component, draft.clone());
+
+    // References are unique and bounded
+    expect_error(
+        submit_temperature_check(&mut ledger, governance_component, draft.clone()),
+        GovernanceError::DuplicateExternalRef,
+    );
+    draft.external_ref = Some(String::new());
+    expect_error(
+        submit_temperature_check(&mut ledger, governance_component, draft),
+        GovernanceError::InvalidExternalRef,
+    );
+
+    let lookup = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+                  method: &str,
+                  external_ref: &str| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(governance_component, method, manifest_args!(external_ref.to_string()))
+            .build();
+        let output: Option<u64> = ledger
+            .execute_manifest(manifest, vec![])
+            .expect_commit_success()
+            .output(1);
+        output
+    };
+
+    assert_eq!(
+        lookup(&mut ledger, "get_temperature_check_by_external_ref", "GP-2025-07"),
+        Some(temperature_check_id)
+    );
+    assert_eq!(lookup(&mut ledger, "get_proposal_by_external_ref", "GP-2025-07"), None);
+    assert_eq!(lookup(&mut ledger, "get_temperature_check_by_external_ref", "GP-2025-08"), None);
+
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "force_elevate",
+        manifest_args!(temperature_check_id),
+    );
+    let proposal_id: u64 = receipt.expect_commit_success().output(2);
+    assert_eq!(
+        lookup(&mut ledger, "get_proposal_by_external_ref", "GP-2025-07"),
+        Some(proposal_id)
+    );
+}
+
+#[test]
+fn test_large_vote_event() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    // Votes above half the quorum (2500) are reported
+    let mut params = create_governance_parameters();
+    params.large_vote_fraction = Some(dec!("0.5"));
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_governance_parameters",
+        manifest_args!(params.clone()),
+    )
+    .expect_commit_success();
+    let large_proposal_id = create_proposal(&mut ledger, governance_component, owner);
+
+    // With a higher quorum the same vote stays below the threshold
+    params.proposal_quorum = dec!(50000);
+    params.binding_quorum = dec!(100000);
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_governance_parameters",
+        manifest_args!(params),
+    )
+    .expect_commit_success();
+    let small_proposal_id = create_proposal(&mut ledger, governance_component, owner);
+
+    let has_large_vote_event =
+        |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>, proposal_id: u64| {
+            let receipt = vote_on_proposal(
+                ledger,
+                governance_component,
+                (voter_pk, voter_account),
+                proposal_id,
+                vec![ProposalVoteOptionId(0)],
+            );
+            let commit = receipt.expect_commit_success();
+            commit
+                .application_events
+                .iter()
+                .any(|(event_type, _)| ledger.event_name(event_type) == "LargeVoteEvent")
+        };
+
+    assert!(has_large_vote_event(&mut ledger, large_proposal_id));
+    assert!(!has_large_vote_event(&mut ledger, small_proposal_id));
+}
+
+#[test]
+fn test_adaptive_approval_threshold() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+
+    // 70% at the quorum (5000), down to 60% from twice the quorum
+    let mut params = create_governance_parameters();
+    params.proposal_approval_threshold = dec!("0.7");
+    params.adaptive_approval_threshold = Some(AdaptiveApprovalThreshold {
+        quorum_multiple: dec!(2),
+        threshold: dec!("0.8"),
+    });
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_governance_parameters",
+        manifest_args!(params.clone()),
+    );
+    expect_error(receipt, GovernanceError::InvalidAdaptiveApprovalThreshold);
+
+    params.adaptive_approval_threshold = Some(AdaptiveApprovalThreshold {
+        quorum_multiple: dec!(2),
+        threshold: dec!("0.6"),
+    });
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_governance_parameters",
+        manifest_args!(params),
+    )
+    .expect_commit_success();
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+
+    // Two thirds in favor with 30000 cast: below 70%, above the 60% of high turnout
+    for option in [0, 0, 1] {
+        let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+        vote_on_proposal(
+            &mut ledger,
+            governance_component,
+            (voter_pk, voter_account),
+            proposal_id,
+            vec![ProposalVoteOptionId(option)],
+        )
+        .expect_commit_success();
+    }
+
+    advance_time(&mut ledger, 15 * 24 * 60 * 60);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_proposal_tally", manifest_args!(proposal_id))
+        .call_method(governance_component, "finalize_proposal", manifest_args!(proposal_id))
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let commit = receipt.expect_commit_success();
+    let tally: ProposalTally = commit.output(1);
+    assert_eq!(tally.approval_threshold, dec!("0.6"));
+    assert!(tally.threshold_met);
+    let status: ProposalStatus = commit.output(2);
+    assert_eq!(status, ProposalStatus::Passed);
+}
+
+#[test]
+fn test_rollback_parameter_change_execution() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    let mut params = create_governance_parameters();
+    params.rollback_window_days = 3;
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_governance_parameters",
+        manifest_args!(params.clone()),
+    )
+    .expect_commit_success();
+
+    // A text proposal has nothing to roll back
+    let text_proposal_id = create_proposal(&mut ledger, governance_component, owner);
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "rollback_execution",
+        manifest_args!(text_proposal_id),
+    );
+    expect_error(receipt, GovernanceError::NotRollbackable);
+
+    let mut parameters = params;
+    parameters.archive_retention_days = 99;
+    let mut draft = create_temp_check_draft();
+    draft.kind = ProposalKind::ParameterChange { parameters };
+    let temperature_check_id = create_temperature_check(&mut ledger, governance_component, draft);
+    let proposal_id: u64 = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "force_elevate",
+        manifest_args!(temperature_check_id),
+    )
+    .expect_commit_success()
+    .output(2);
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+
+    advance_time(&mut ledger, 15 * 24 * 60 * 60);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "finalize_proposal", manifest_args!(proposal_id))
+        .build();
+    ledger.execute_manifest(manifest, vec![]).expect_commit_success();
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "execute_proposal",
+        manifest_args!(proposal_id, None::<u16>),
+    )
+    .expect_commit_success();
+
+    // The owner holds the security role by default
+    let rollback = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>| {
+        call_as_owner(
+            ledger,
+            governance_component,
+            owner,
+            "rollback_execution",
+            manifest_args!(proposal_id),
+        )
+    };
+    rollback(&mut ledger).expect_commit_success();
+    expect_error(rollback(&mut ledger), GovernanceError::AlreadyRolledBack);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_governance_parameters", manifest_args!())
+        .call_method(governance_component, "get_proposal", manifest_args!(proposal_id))
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let commit = receipt.expect_commit_success();
+    let current: GovernanceParameters = commit.output(1);
+    assert_eq!(current.archive_retention_days, 30);
+    let proposal: ProposalView = commit.output(2);
+    assert!(proposal.rolled_back_at.is_some());
+}
+
+#[test]
+fn test_rollback_keeps_later_parameter_changes() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    let mut base = create_governance_parameters();
+    base.rollback_window_days = 3;
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_governance_parameters",
+        manifest_args!(base.clone()),
+    )
+    .expect_commit_success();
+
+    // The second change builds on the first one
+    let mut first = base.clone();
+    first.archive_retention_days = 99;
+    let mut second = first.clone();
+    second.max_description_length = 1234;
+    let proposal_ids: Vec<u64> = [first.clone(), second.clone()]
+        .into_iter()
+        .map(|parameters| {
+            let mut draft = create_temp_check_draft();
+            draft.kind = ProposalKind::ParameterChange { parameters };
+            let temperature_check_id =
+                create_temperature_check(&mut ledger, governance_component, draft);
+            let proposal_id: u64 = call_as_owner(
+                &mut ledger,
+                governance_component,
+                owner,
+                "force_elevate",
+                manifest_args!(temperature_check_id),
+            )
+            .expect_commit_success()
+            .output(2);
+            vote_on_proposal(
+                &mut ledger,
+                governance_component,
+                (voter_pk, voter_account),
+                proposal_id,
+                vec![ProposalVoteOptionId(0)],
+            )
+            .expect_commit_success();
+            proposal_id
+        })
+        .collect();
+
+    advance_time(&mut ledger, 15 * 24 * 60 * 60);
+    for proposal_id in proposal_ids.iter() {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(governance_component, "finalize_proposal", manifest_args!(*proposal_id))
+            .build();
+        ledger.execute_manifest(manifest, vec![]).expect_commit_success();
+        call_as_owner(
+            &mut ledger,
+            governance_component,
+            owner,
+            "execute_proposal",
+            manifest_args!(*proposal_id, None::<u16>),
+        )
+        .expect_commit_success();
+    }
+
+    let rollback = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+                    proposal_id: u64| {
+        call_as_owner(
+            ledger,
+            governance_component,
+            owner,
+            "rollback_execution",
+            manifest_args!(proposal_id),
+        )
+    };
+    let current_parameters = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(governance_component, "get_governance_parameters", manifest_args!())
+            .build();
+        let parameters: GovernanceParameters = ledger
+            .execute_manifest(manifest, vec![])
+            .expect_commit_success()
+            .output(1);
+        parameters
+    };
+
+    // Rolling back the first change would discard the second one
+    expect_error(
+        rollback(&mut ledger, proposal_ids[0]),
+        GovernanceError::ParametersChangedSinceExecution,
+    );
+    assert_eq!(current_parameters(&mut ledger), second);
+
+    // Rolled back in reverse order, each change restores what it replaced
+    rollback(&mut ledger, proposal_ids[1]).expect_commit_success();
+    assert_eq!(current_parameters(&mut ledger), first);
+    rollback(&mut ledger, proposal_ids[0]).expect_commit_success();
+    assert_eq!(current_parameters(&mut ledger), base);
+}
+
+#[test]
+fn test_result_attestation() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+
+    let proposal_id = create_proposal(&mut ledger, governance_component, owner);
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_result_attestation", manifest_args!(proposal_id))
+        .build();
+    expect_error(
+        ledger.execute_manifest(manifest.clone(), vec![]),
+        GovernanceError::ProposalNotFinalized,
+    );
+
+    advance_time(&mut ledger, 15 * 24 * 60 * 60);
+    let finalize = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "finalize_proposal", manifest_args!(proposal_id))
+        .build();
+    ledger.execute_manifest(finalize, vec![]).expect_commit_success();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let payload: Vec<u8> = receipt.expect_commit_success().output(1);
+    let attestation: ResultAttestation = scrypto_decode(&payload).unwrap();
+    assert_eq!(attestation.version, RESULT_ATTESTATION_VERSION);
+    assert_eq!(attestation.governance, governance_component);
+    assert_eq!(attestation.proposal_id, proposal_id);
+    assert_eq!(attestation.status, ProposalStatus::Passed);
+    assert_eq!(attestation.option_weights[0], dec!(10000));
+    assert_eq!(attestation.total_weight_cast, dec!(10000));
+    assert_eq!(attestation.winning_options, vec![ProposalVoteOptionId(0)]);
+}
+
+#[test]
+fn test_incoming_power_checkpoints_per_epoch() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let delegation_component = instantiate_vote_delegation(&mut ledger, package_address, owner.0);
+    let (first_pk, _first_sk, first_account) = ledger.new_allocated_account();
+    let (second_pk, _second_sk, second_account) = ledger.new_allocated_account();
+    let (_delegatee_pk, _delegatee_sk, delegatee_account) = ledger.new_allocated_account();
+    let valid_until = Instant::new(i64::MAX / 2);
+
+    let call = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+                signer: Secp256k1PublicKey,
+                method: &str,
+                args: ManifestArgs| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(delegation_component, method, args)
+            .build();
+        ledger
+            .execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&signer)])
+            .expect_commit_success();
+    };
+
+    let first_epoch = ledger.get_current_epoch();
+    call(
+        &mut ledger,
+        first_pk,
+        "make_delegation",
+        manifest_args!(first_account, delegatee_account, dec!("0.5"), valid_until),
+    );
+
+    // In the next epoch one delegator arrives and the other leaves
+    let second_epoch = Epoch::of(first_epoch.number() + 1);
+    ledger.set_current_epoch(second_epoch);
+    call(
+        &mut ledger,
+        second_pk,
+        "make_delegation",
+        manifest_args!(second_account, delegatee_account, dec!("0.3"), valid_until),
+    );
+    call(
+        &mut ledger,
+        first_pk,
+        "remove_delegation",
+        manifest_args!(first_account, delegatee_account),
+    );
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "get_incoming_power_at_epoch",
+            manifest_args!(delegatee_account, first_epoch),
+        )
+        .call_method(
+            delegation_component,
+            "get_incoming_power_at_epoch",
+            manifest_args!(delegatee_account, second_epoch),
+        )
+        .call_method(
+            delegation_component,
+            "get_incoming_power_at_epoch",
+            manifest_args!(first_account, second_epoch),
+        )
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let commit = receipt.expect_commit_success();
+    assert_eq!(commit.output::<Option<Decimal>>(1), Some(dec!("0.5")));
+    assert_eq!(commit.output::<Option<Decimal>>(2), Some(dec!("0.3")));
+    assert_eq!(commit.output::<Option<Decimal>>(3), Some(Decimal::ZERO));
+
+    // Once older checkpoints are dropped, the first epoch's total is unknown
+    for offset in 2..=MAX_INCOMING_POWER_CHECKPOINTS as u64 + 1 {
+        ledger.set_current_epoch(Epoch::of(first_epoch.number() + offset));
+        let fraction = if offset % 2 == 0 { dec!("0.2") } else { dec!("0.3") };
+        call(
+            &mut ledger,
+            second_pk,
+            "update_delegation_fraction",
+            manifest_args!(second_account, delegatee_account, fraction),
+        );
+    }
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            delegation_component,
+            "get_incoming_power_at_epoch",
+            manifest_args!(delegatee_account, first_epoch),
+        )
+        .build();
+    let incoming: Option<Decimal> = ledger
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success()
+        .output(1);
+    assert_eq!(incoming, None);
+}
+
+#[test]
+fn test_delegations_before_retained_history() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let delegation_component = instantiate_vote_delegation(&mut ledger, package_address, owner.0);
+    let (delegator_pk, _delegator_sk, delegator_account) = ledger.new_allocated_account();
+    let (_delegatee_pk, _delegatee_sk, delegatee_account) = ledger.new_allocated_account();
+    let valid_until = Instant::new(i64::MAX / 2);
+
+    let call = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+                method: &str,
+                args: ManifestArgs| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(delegation_component, method, args)
+            .build();
+        ledger
+            .execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&delegator_pk)])
+            .expect_commit_success();
+    };
+    let delegations_at = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+                          at: Instant| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                delegation_component,
+                "get_delegations_at",
+                manifest_args!(delegator_account, at),
+            )
+            .build();
+        ledger.execute_manifest(manifest, vec![])
+    };
+
+    let before = Instant::new(ledger.get_current_proposer_timestamp_ms() / 1000);
+    advance_time(&mut ledger, 1);
+    let snapshot = Instant::new(ledger.get_current_proposer_timestamp_ms() / 1000);
+    call(
+        &mut ledger,
+        "make_delegation",
+        manifest_args!(delegator_account, delegatee_account, dec!("0.5"), valid_until),
+    );
+
+    // Before the first delegation there were none
+    let delegations: Vec<Delegation> = delegations_at(&mut ledger, before)
+        .expect_commit_success()
+        .output(1);
+    assert!(delegations.is_empty());
+
+    // Changing the delegation often enough drops the checkpoint covering the snapshot; looking
+    // it up then fails instead of reporting no delegations
+    for i in 0..MAX_DELEGATION_CHECKPOINTS {
+        advance_time(&mut ledger, 1);
+        let fraction = if i % 2 == 0 { dec!("0.4") } else { dec!("0.5") };
+        call(
+            &mut ledger,
+            "update_delegation_fraction",
+            manifest_args!(delegator_account, delegatee_account, fraction),
+        );
+    }
+    expect_error(
+        delegations_at(&mut ledger, snapshot),
+        DelegationError::InstantBeforeRetainedHistory,
+    );
+
+    // Instants covered by a kept checkpoint still resolve
+    let now = Instant::new(ledger.get_current_proposer_timestamp_ms() / 1000);
+    let delegations: Vec<Delegation> = delegations_at(&mut ledger, now)
+        .expect_commit_success()
+        .output(1);
+    assert_eq!(delegations.len(), 1);
+}
+
+#[test]
+fn test_abstain_only_quorum_weight() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+
+    let mut draft = create_temp_check_draft();
+    draft.abstain_option = Some(ProposalVoteOptionId(2));
+    expect_error(
+        submit_temperature_check(&mut ledger, governance_component, draft.clone()),
+        GovernanceError::InvalidAbstainOption,
+    );
+
+    // Abstain-only votes count half towards a quorum of 6000
+    let mut params = create_governance_parameters();
+    params.proposal_quorum = dec!(6000);
+    params.abstain_quorum_weight = AbstainQuorumWeight::Half;
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "update_governance_parameters",
+        manifest_args!(params),
+    )
+    .expect_commit_success();
+
+    draft.abstain_option = Some(ProposalVoteOptionId(1));
+    let temperature_check_id = create_temperature_check(&mut ledger, governance_component, draft);
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "force_elevate",
+        manifest_args!(temperature_check_id),
+    );
+    let proposal_id: u64 = receipt.expect_commit_success().output(2);
+
+    let get_tally = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(governance_component, "get_proposal_tally", manifest_args!(proposal_id))
+            .build();
+        let tally: ProposalTally = ledger
+            .execute_manifest(manifest, vec![])
+            .expect_commit_success()
+            .output(1);
+        tally
+    };
+
+    // 10000 abstaining only counts as 5000, which would meet the quorum in full
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(1)],
+    )
+    .expect_commit_success();
+    let tally = get_tally(&mut ledger);
+    assert_eq!(tally.quorum_weight, dec!(5000));
+    assert!(!tally.quorum_met);
+
+    // Other votes count in full
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+    vote_on_proposal(
+        &mut ledger,
+        governance_component,
+        (voter_pk, voter_account),
+        proposal_id,
+        vec![ProposalVoteOptionId(0)],
+    )
+    .expect_commit_success();
+    let tally = get_tally(&mut ledger);
+    assert_eq!(tally.quorum_weight, dec!(15000));
+    assert!(tally.quorum_met);
+}
+
+#[test]
+fn test_ranking_borda_count() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+
+    // Three competing RFCs
+    for _ in 0..3 {
+        create_temperature_check(&mut ledger, governance_component, create_temp_check_draft());
+    }
+
+    let mut draft = create_multi_choice_temp_check_draft();
+    draft.voting_mode = VotingMode::PointsAllocation;
+    for candidates in [vec![0, 1, 1], vec![0, 1, 7]] {
+        draft.kind = ProposalKind::Ranking {
+            temperature_check_ids: candidates,
+        };
+        expect_error(
+            submit_temperature_check(&mut ledger, governance_component, draft.clone()),
+            GovernanceError::InvalidRankingCandidates,
+        );
+    }
+    draft.kind = ProposalKind::Ranking {
+        temperature_check_ids: vec![0, 1, 2],
+    };
+    let temperature_check_id = create_temperature_check(&mut ledger, governance_component, draft);
+    let proposal_id: u64 = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "force_elevate",
+        manifest_args!(temperature_check_id),
+    )
+    .expect_commit_success()
+    .output(2);
+
+    let vote_on_ranking = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+                           (voter_pk, voter_account): (Secp256k1PublicKey, ComponentAddress),
+                           proposal_id: u64,
+                           ranking: Vec<u64>| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                governance_component,
+                "vote_on_ranking",
+                manifest_args!(voter_account, proposal_id, ranking),
+            )
+            .build();
+        ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&voter_pk)])
+    };
+
+    // Rankings must order every temperature check of a ranking proposal once
+    let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+    expect_error(
+        vote_on_ranking(&mut ledger, (voter_pk, voter_account), proposal_id, vec![0, 1, 1]),
+        GovernanceError::InvalidRanking,
+    );
+    let other_proposal_id = create_proposal(&mut ledger, governance_component, owner);
+    expect_error(
+        vote_on_ranking(&mut ledger, (voter_pk, voter_account), other_proposal_id, vec![0, 1, 2]),
+        GovernanceError::NotARanking,
+    );
+
+    // Borda points 2, 1 and 0 per ballot of 10000
+    for ranking in [vec![2, 0, 1], vec![0, 2, 1], vec![0, 1, 2]] {
+        let (voter_pk, _voter_sk, voter_account) = ledger.new_allocated_account();
+        vote_on_ranking(&mut ledger, (voter_pk, voter_account), proposal_id, ranking)
+            .expect_commit_success();
+    }
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_ranking", manifest_args!(proposal_id))
+        .build();
+    let ranking: Vec<RankedTemperatureCheck> = ledger
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success()
+        .output(1);
+    let order: Vec<u64> = ranking.iter().map(|r| r.temperature_check_id).collect();
+    assert_eq!(order, vec![0, 2, 1]);
+    assert!((ranking[0].borda_score - dec!(50000)).checked_abs().unwrap() < dec!("0.0001"));
+}
+
+#[test]
+fn test_group_bloc_voting() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let package_address = ledger.compile_and_publish(this_package!());
+    let owner = create_owner_badge_with_account(&mut ledger);
+    let governance_component = instantiate_governance(&mut ledger, package_address, owner.0);
+
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "register_voting_group",
+        manifest_args!("Europe".to_string(), Decimal::ZERO),
+    );
+    expect_error(receipt, GovernanceError::InvalidVotingGroup);
+
+    // Two chapters with blocs of 4000 and 3000, with three and two members
+    let mut members = Vec::new();
+    for (name, bloc_weight, member_count) in [("Europe", dec!(4000), 3), ("Asia", dec!(3000), 2)] {
+        let group_id: u64 = call_as_owner(
+            &mut ledger,
+            governance_component,
+            owner,
+            "register_voting_group",
+            manifest_args!(name.to_string(), bloc_weight),
+        )
+        .expect_commit_success()
+        .output(2);
+        for _ in 0..member_count {
+            let (member_pk, _member_sk, member_account) = ledger.new_allocated_account();
+            call_as_owner(
+                &mut ledger,
+                governance_component,
+                owner,
+                "add_voting_group_member",
+                manifest_args!(group_id, member_account),
+            )
+            .expect_commit_success();
+            members.push((member_pk, member_account));
+        }
+    }
+    let receipt = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "add_voting_group_member",
+        manifest_args!(1u64, members[0].1),
+    );
+    expect_error(receipt, GovernanceError::AlreadyInVotingGroup);
+
+    let mut draft = create_temp_check_draft();
+    draft.voting_mode = VotingMode::Groups;
+    let temperature_check_id = create_temperature_check(&mut ledger, governance_component, draft);
+    let proposal_id: u64 = call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "force_elevate",
+        manifest_args!(temperature_check_id),
+    )
+    .expect_commit_success()
+    .output(2);
+
+    let vote_in_group = |ledger: &mut LedgerSimulator<NoExtension, InMemorySubstateDatabase>,
+                         (voter_pk, voter_account): (Secp256k1PublicKey, ComponentAddress),
+                         option: u32| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                governance_component,
+                "vote_in_group",
+                manifest_args!(voter_account, proposal_id, vec![ProposalVoteOptionId(option)]),
+            )
+            .build();
+        ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&voter_pk)])
+    };
+
+    // Accounts are not weighed individually, and only group members vote
+    expect_error(
+        vote_on_proposal(
+            &mut ledger,
+            governance_component,
+            members[0],
+            proposal_id,
+            vec![ProposalVoteOptionId(0)],
+        ),
+        GovernanceError::WrongVotingMode,
+    );
+    let (outsider_pk, _outsider_sk, outsider_account) = ledger.new_allocated_account();
+    expect_error(
+        vote_in_group(&mut ledger, (outsider_pk, outsider_account), 0),
+        GovernanceError::NotInVotingGroup,
+    );
+
+    // Members added while the proposal is open don't vote on it
+    advance_time(&mut ledger, 60);
+    call_as_owner(
+        &mut ledger,
+        governance_component,
+        owner,
+        "add_voting_group_member",
+        manifest_args!(1u64, outsider_account),
+    )
+    .expect_commit_success();
+    expect_error(
+        vote_in_group(&mut ledger, (outsider_pk, outsider_account), 0),
+        GovernanceError::JoinedVotingGroupAfterSnapshot,
+    );
+
+    // Europe splits 2 to 1 for option 0, Asia is unanimous for option 1
+    for (member, option) in members.iter().zip([0, 1, 0, 1, 1]) {
+        vote_in_group(&mut ledger, *member, option).expect_commit_success();
+    }
+    expect_error(
+        vote_in_group(&mut ledger, members[0], 1),
+        GovernanceError::AlreadyVotedOnProposal,
+    );
+
+    advance_time(&mut ledger, 15 * 24 * 60 * 60);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(governance_component, "get_group_tally", manifest_args!(proposal_id, 0u64))
+        .call_method(governance_component, "get_proposal_tally", manifest_args!(proposal_id))
+        .call_method(governance_component, "finalize_proposal", manifest_args!(proposal_id))
+        .build();
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let commit = receipt.expect_commit_success();
+    let group_tally: Option<GroupTally> = commit.output(1);
+    let group_tally = group_tally.unwrap();
+    assert_eq!(group_tally.option_votes, vec![2, 1]);
+    assert_eq!(group_tally.majority, Some(ProposalVoteOptionId(0)));
+    let tally: ProposalTally = commit.output(2);
+    assert_eq!(tally.options[0].weight, dec!(4000));
+    assert_eq!(tally.options[1].weight, dec!(3000));
+    // The quorum and eligible weight are in bloc weight
+    assert!(tally.quorum_met);
+    assert_eq!(tally.turnout.eligible_weight, dec!(7000));
+    let status: ProposalStatus = commit.output(3);
+    assert_eq!(status, ProposalStatus::Passed);
+}